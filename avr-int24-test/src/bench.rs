@@ -0,0 +1,104 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::pac::TC1;
+use avr_int24::{Int24, unit_tests::TestOps};
+use core::hint::black_box;
+
+/// Operation under benchmark.
+type Op = fn(Int24, Int24) -> Int24;
+
+/// The benchmarked operations.
+const OPS: [(&str, Op); 24] = [
+    ("add", |a, b| a + b),
+    ("sub", |a, b| a - b),
+    ("mul", |a, b| a * b),
+    ("mul_shr(8)", |a, b| a.mul_shr(b, 8)),
+    ("mul_shr(23)", |a, b| a.mul_shr(b, 23)),
+    ("div", |a, b| a / b),
+    ("shl8div", |a, b| a.shl8div(b)),
+    ("shl_div(16)", |a, b| a.shl_div(b, 16)),
+    ("mul_i16", |a, b| a.mul_i16(b.cast_to_i16())),
+    ("mul_u8", |a, b| a.mul_u8(b.cast_to_i8() as u8)),
+    ("div_u8", |a, b| a.div_u8(b.cast_to_i8() as u8)),
+    ("neg", |a, _| -a),
+    ("abs", |a, _| a.abs()),
+    ("isqrt", |a, _| a.isqrt()),
+    ("shl(4)", |a, _| a.shl(4)),
+    ("shr(4)", |a, _| a.shr(4)),
+    ("shr8", |a, _| a.shr8()),
+    ("cmp", |a, b| Int24::from_i8(a.cmp(b) as i8)),
+    ("ct_add", |a, b| a.ct_add(b)),
+    ("ct_sub", |a, b| a.ct_sub(b)),
+    ("ct_mul", |a, b| a.ct_mul(b)),
+    ("ct_div", |a, b| a.ct_div(b)),
+    ("ct_neg", |a, _| a.ct_neg()),
+    ("ct_abs", |a, _| a.ct_abs()),
+];
+
+/// The operand classes `(name, a, b)`.
+const CLASSES: [(&str, i32, i32); 6] = [
+    ("small", 100, 7),
+    ("large", 0x12_3456, 0x65),
+    ("pow2", 0x12_3456, 0x40),
+    ("negative", -0x12_3456, -0x65),
+    ("saturating", 0x7F_FFF0, 0x7F_FFF0),
+    ("zero", 0x12_3456, 0),
+];
+
+/// Cycle counter based on the free running 16 bit Timer1 without prescaler.
+struct CycleCounter<'a> {
+    tc1: &'a TC1,
+}
+
+impl<'a> CycleCounter<'a> {
+    fn new(tc1: &'a TC1) -> Self {
+        // Disable the overflow interrupt of the test time measurement,
+        // so that no interrupt disturbs the measurement.
+        tc1.timsk1.reset();
+        tc1.tccr1a.reset();
+        tc1.tccr1b.write(|w| w.cs1().direct());
+        Self { tc1 }
+    }
+
+    /// Get the number of CPU cycles of one call to `op`.
+    ///
+    /// This includes the call overhead.
+    #[inline(never)]
+    fn measure(&self, op: Op, a: Int24, b: Int24) -> u16 {
+        let (op, a, b) = black_box((op, a, b));
+        let start = self.tc1.tcnt1.read().bits();
+        let r = op(a, b);
+        let end = self.tc1.tcnt1.read().bits();
+        black_box(r);
+        end.wrapping_sub(start)
+    }
+}
+
+/// Measure the cycle counts of all operations for all operand classes
+/// and report them through `t`.
+///
+/// The overhead of calling an operation is measured with an operation
+/// that returns its operand and it is subtracted from the results.
+pub fn run_benchmarks(t: &impl TestOps, tc1: &TC1) {
+    let cc = CycleCounter::new(tc1);
+    t.print("\n\nBegin benchmarks\n");
+    for (class, a, b) in CLASSES {
+        let (a, b) = (Int24::from_i32(a), Int24::from_i32(b));
+        let overhead = cc.measure(|a, _| a, a, b);
+        for (name, op) in OPS {
+            let cycles = cc.measure(op, a, b).saturating_sub(overhead);
+            t.print("bench ");
+            t.print(name);
+            t.print(" ");
+            t.print(class);
+            t.print(": ");
+            t.print_num(cycles.into());
+            t.print(" cycles\n");
+        }
+    }
+    t.print("Benchmarks done\n");
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,36 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use avr_int24::{clobber, unit_tests::TestOps};
+
+/// Run the register clobber self-test of the assembly routines
+/// and report the results through `t`.
+///
+/// Returns `true`, if no routine modified anything undeclared.
+pub fn run_clobber_test(t: &impl TestOps) -> bool {
+    t.print("\n\nBegin clobber test\n");
+    let ok = clobber::check_all(|name, c| {
+        t.print("clobber ");
+        t.print(name);
+        if c.is_empty() {
+            t.print(": Ok\n");
+        } else {
+            t.print(": FAILED regs: ");
+            t.print_num(c.regs);
+            t.print(" live: ");
+            t.print_num(c.live.into());
+            if c.stack {
+                t.print(" stack");
+            }
+            if c.irq_flag {
+                t.print(" irq_flag");
+            }
+            t.print("\n");
+        }
+    });
+    t.print("Clobber test done\n");
+    ok
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,132 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Instances of the operations for measuring their code size.
+//!
+//! Each operation is instantiated exactly once in its own function `codesize_<name>`
+//! in its own section `.text.codesize.<name>`.
+//! The operands are function arguments, so the operations can't be constant folded.
+//! `codesize.py` reads the sizes of the functions and of the functions they call from the ELF file.
+
+use avr_int24::{
+    Angle24, Complex24, DivisorInt24, Error, Fix24, Frac24, I8F16, I16F8, Int24, Int48, Lut24,
+    Nco24, Ratio24, Vec3, XorShift24,
+};
+use core::hint::black_box;
+
+/// Generate the measured function of each operation
+/// and the function [keep], which keeps all of them in the program.
+macro_rules! codesize {
+    ($($name:ident($($arg:ident: $ty:ty),*) -> $ret:ty => $body:expr;)*) => {
+        $(
+            #[unsafe(export_name = concat!("codesize_", stringify!($name)))]
+            #[unsafe(link_section = concat!(".text.codesize.", stringify!($name)))]
+            #[inline(never)]
+            fn $name($($arg: $ty),*) -> $ret {
+                $body
+            }
+        )*
+
+        /// Keep all measured operations in the program.
+        ///
+        /// This doesn't call the operations.
+        pub fn keep() {
+            $(
+                black_box($name as fn($($ty),*) -> $ret);
+            )*
+        }
+    };
+}
+
+codesize! {
+    // Core operations
+    add(a: Int24, b: Int24) -> Int24 => a + b;
+    sub(a: Int24, b: Int24) -> Int24 => a - b;
+    mul(a: Int24, b: Int24) -> Int24 => a * b;
+    mul_shr(a: Int24, b: Int24, c: u8) -> Int24 => a.mul_shr(b, c);
+    mul_i16(a: Int24, b: i16) -> Int24 => a.mul_i16(b);
+    mul_u8(a: Int24, b: u8) -> Int24 => a.mul_u8(b);
+    div(a: Int24, b: Int24) -> Int24 => a / b;
+    div_u8(a: Int24, b: u8) -> Int24 => a.div_u8(b);
+    checked_div(a: Int24, b: Int24) -> Option<Int24> => a.checked_div(b);
+    shl8div(a: Int24, b: Int24) -> Int24 => a.shl8div(b);
+    shl_div(a: Int24, b: Int24, c: u8) -> Int24 => a.shl_div(b, c);
+    neg(a: Int24) -> Int24 => -a;
+    abs(a: Int24) -> Int24 => a.abs();
+    isqrt(a: Int24) -> Int24 => a.isqrt();
+    shl(a: Int24, c: u8) -> Int24 => a.shl(c);
+    shr(a: Int24, c: u8) -> Int24 => a.shr(c);
+    shr8(a: Int24) -> Int24 => a.shr8();
+    cmp(a: Int24, b: Int24) -> core::cmp::Ordering => a.cmp(b);
+    wrapping_mul(a: Int24, b: Int24) -> Int24 => a.wrapping_mul(b);
+    saturating_add_unsigned(a: Int24, b: u32) -> Int24 => a.saturating_add_unsigned(b);
+    add_in_place(a: &mut Int24, b: Int24) -> () => a.add_in_place(b);
+
+    // Constant time operations
+    ct_add(a: Int24, b: Int24) -> Int24 => a.ct_add(b);
+    ct_sub(a: Int24, b: Int24) -> Int24 => a.ct_sub(b);
+    ct_mul(a: Int24, b: Int24) -> Int24 => a.ct_mul(b);
+    ct_div(a: Int24, b: Int24) -> Int24 => a.ct_div(b);
+    ct_neg(a: Int24) -> Int24 => a.ct_neg();
+    ct_abs(a: Int24) -> Int24 => a.ct_abs();
+    self_test() -> Result<(), avr_int24::SelfTestFailure> => Int24::self_test();
+
+    // Conversions
+    from_i32(v: i32) -> Int24 => Int24::from_i32(v);
+    to_i16(a: Int24) -> i16 => a.to_i16();
+    to_bcd(a: Int24) -> (bool, u32) => a.to_bcd();
+    to_gray(a: Int24) -> Int24 => a.to_gray();
+    next_power_of_two(a: Int24) -> Int24 => a.next_power_of_two();
+    mul_q15(a: Int24, c: i16) -> Int24 => a.mul_q15(c);
+    apply_gain(a: Int24, g: i16) -> Int24 => a.apply_gain(g);
+    to_db_q8_8(a: Int24) -> i16 => a.to_db_q8_8();
+    from_db_q8_8(g: i16) -> Int24 => Int24::from_db_q8_8(g);
+
+    // Scaling
+    lerp_q16(a: Int24, b: Int24, t: u16) -> Int24 => a.lerp_q16(b, t);
+    map_range(a: Int24, b: Int24, c: Int24, d: Int24, e: Int24) -> Int24 => a.map_range(b, c, d, e);
+    to_duty(a: Int24, top: u16) -> u16 => a.to_duty(top);
+    from_duty(d: u16, top: u16) -> Int24 => Int24::from_duty(d, top);
+    scale_ppm(a: Int24, p: i32) -> Int24 => a.scale_ppm(p);
+    next_multiple_of(a: Int24, b: Int24) -> Int24 => a.next_multiple_of(b);
+    deadband(a: Int24, b: Int24) -> Int24 => a.deadband(b);
+    elapsed_since(a: Int24, b: Int24) -> u32 => a.elapsed_since(b);
+    bisect(a: Int24, b: Int24, t: i32) -> Result<Int24, Int24> => {
+        avr_int24::bisect(a, b, |x| x.to_i32().cmp(&t))
+    };
+
+    // Wide integers and fractions
+    int48_mul_int24(a: Int24, b: Int24) -> Int48 => Int48::mul_int24(a, b);
+    int48_div(a: Int48, b: Int48) -> Int48 => a / b;
+    ratio_mul(a: Ratio24, b: Ratio24) -> Ratio24 => a * b;
+    ratio_mul_int(a: Ratio24, b: Int24) -> Int24 => a.mul_int(b);
+
+    // Fixed-point
+    i16f8_mul(a: I16F8, b: I16F8) -> I16F8 => a * b;
+    i8f16_div(a: I8F16, b: I8F16) -> I8F16 => a / b;
+    frac24_mul(a: Frac24, b: Frac24) -> Frac24 => a * b;
+    fix24_rescale(a: Fix24<12>) -> Fix24<20> => a.rescale::<20>();
+    divisor_new(b: Int24) -> DivisorInt24 => DivisorInt24::new(b);
+    divisor_divide(d: &DivisorInt24, a: Int24) -> Int24 => d.divide(a);
+    recip_q23(a: Int24) -> Frac24 => a.recip_q23();
+
+    // Trigonometry and geometry
+    sin_cos(a: Int24) -> (Frac24, Frac24) => Frac24::sin_cos(a);
+    atan2(a: Int24, b: Int24) -> Int24 => a.atan2(b);
+    angle_to_degrees(a: Angle24) -> I16F8 => a.to_degrees();
+    nco_sample_sin(nco: &mut Nco24) -> Frac24 => nco.sample_sin();
+    complex_mul_shr(a: Complex24, b: Complex24, c: u8) -> Complex24 => a.mul_shr(b, c);
+    complex_magnitude(a: Complex24) -> Int24 => a.magnitude_approx();
+    vec3_cross(a: Vec3, b: Vec3) -> Vec3 => a.cross(b);
+
+    // Filters, tables and formatting
+    next_int24(rng: &mut XorShift24) -> Int24 => rng.next_int24();
+    lut_lookup(lut: &Lut24<'static>, x: Int24) -> Int24 => lut.lookup(x);
+    format_decimal(a: Int24, d: u8, buf: &mut [u8]) -> usize => {
+        a.format_decimal(d, buf).map_or(0, |s| s.len())
+    };
+    parse_decimal(s: &str, d: u8) -> Result<Int24, Error> => Int24::parse_decimal(s, d);
+}
+
+// vim: ts=4 sw=4 expandtab
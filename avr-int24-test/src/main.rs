@@ -7,20 +7,89 @@
 #![feature(abi_avr_interrupt)]
 #![feature(asm_experimental_arch)]
 
+#[cfg(feature = "bench")]
+mod bench;
+#[cfg(feature = "clobber-test")]
+mod clobber;
+#[cfg(feature = "codesize")]
+mod codesize;
+mod timer;
 mod uart;
 
-use crate::uart::Uart;
+use crate::{timer::Timer, uart::Uart};
+#[cfg(feature = "atmega32u4")]
+use avr_device::atmega32u4 as pac;
+#[cfg(feature = "atmega328p")]
 use avr_device::atmega328p as pac;
-use avr_int24::unit_tests::{TestOps, run_tests};
+#[cfg(feature = "atmega2560")]
+use avr_device::atmega2560 as pac;
+use avr_int24::unit_tests::{FailPolicy, TestOps, run_tests};
+use core::cell::RefCell;
 
-struct TestRunner<'a> {
-    uart: &'a Uart,
+/// Parse the decimal build time environment variable `value` or return `default`, if it is not set.
+const fn env_u8(value: Option<&str>, default: u8) -> u8 {
+    let Some(value) = value else {
+        return default;
+    };
+    let value = value.as_bytes();
+    let mut v: u8 = 0;
+    let mut i = 0;
+    while i < value.len() {
+        assert!(value[i].is_ascii_digit(), "Invalid number");
+        v = v * 10 + (value[i] - b'0');
+        i += 1;
+    }
+    v
+}
+
+/// Build only the selected tests, e.g. `"mul_small"` or `"conv_*"`.
+/// An empty filter selects all tests.
+const TEST_FILTER: &str = match option_env!("TEST_FILTER") {
+    Some(filter) => filter,
+    None => "",
+};
+
+/// Number of parts the tests are split into.
+/// The whole test suite doesn't fit into the program memory.
+const TEST_PARTS: u8 = env_u8(option_env!("TEST_PARTS"), 1);
+
+/// The part of the tests to build.
+const TEST_PART: u8 = env_u8(option_env!("TEST_PART"), 0);
+const _: () = assert!(
+    TEST_PART < TEST_PARTS,
+    "TEST_PART must be less than TEST_PARTS"
+);
+
+/// Test runner that prints the results to any [embedded_io::Write] writer,
+/// for example a HAL UART or a USB-CDC device.
+struct TestRunner<W> {
+    writer: RefCell<W>,
+    timer: Timer,
+}
+
+impl<W: embedded_io::Write> TestRunner<W> {
+    fn new(writer: W, timer: Timer) -> Self {
+        Self {
+            writer: RefCell::new(writer),
+            timer,
+        }
+    }
+
+    /// Wait until all output has been transmitted.
+    fn flush(&self) {
+        let _ = self.writer.borrow_mut().flush();
+    }
 }
 
-impl<'a> TestOps for TestRunner<'a> {
+impl<W: embedded_io::Write> TestOps for TestRunner<W> {
+    const FILTER: &'static str = TEST_FILTER;
+    const PARTS: u8 = TEST_PARTS;
+    const PART: u8 = TEST_PART;
+
     #[inline(never)]
     fn print(&self, text: &str) {
-        self.uart.tx_str(text);
+        // Write errors can't be reported anywhere.
+        let _ = self.writer.borrow_mut().write_all(text.as_bytes());
     }
 
     #[inline(never)]
@@ -44,20 +113,56 @@ impl<'a> TestOps for TestRunner<'a> {
             self.print(": Ok\n");
         } else {
             self.print(": FAILED\n");
-            panic!();
         }
     }
+
+    fn fail_policy(&self) -> FailPolicy {
+        // Report all failures over the UART.
+        FailPolicy::Continue
+    }
+
+    fn time_start(&self) {
+        self.timer.start();
+    }
+
+    /// Get the duration of the test, in units of 1024 CPU cycles.
+    fn time_end(&self) -> Option<u32> {
+        Some(self.timer.elapsed())
+    }
 }
 
 #[avr_device::entry]
 fn main() -> ! {
     let dp = pac::Peripherals::take().unwrap();
 
+    #[cfg(not(feature = "atmega32u4"))]
     let uart = Uart::new(dp.USART0);
-    let test = TestRunner { uart: &uart };
+    #[cfg(feature = "atmega32u4")]
+    let uart = Uart::new(dp.USART1);
+    let test = TestRunner::new(uart, Timer::new(dp.TC1));
+
+    // The timer needs the interrupts.
+    // SAFETY: All data shared with interrupts is protected by critical sections.
+    unsafe { avr_device::interrupt::enable() };
+
+    let ok = run_tests(&test).ok();
+    #[cfg(feature = "clobber-test")]
+    let ok = clobber::run_clobber_test(&test) && ok;
+    if ok {
+        test.print("TESTS PASSED\n");
+    } else {
+        test.print("TESTS FAILED\n");
+    }
+
+    #[cfg(feature = "bench")]
+    bench::run_benchmarks(&test, test.timer.tc1());
+
+    #[cfg(feature = "codesize")]
+    codesize::keep();
 
-    run_tests(&test);
+    test.flush();
 
+    // Sleeping with disabled interrupts also terminates the simavr simulator.
     loop {
         avr_device::interrupt::disable();
         avr_device::asm::sleep();
@@ -0,0 +1,69 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::pac::TC1;
+use avr_device::interrupt::{self, Mutex};
+use core::cell::Cell;
+
+/// Number of Timer1 overflows since the start of the measurement.
+static OVERFLOWS: Mutex<Cell<u16>> = Mutex::new(Cell::new(0));
+
+#[cfg_attr(feature = "atmega328p", avr_device::interrupt(atmega328p))]
+#[cfg_attr(feature = "atmega2560", avr_device::interrupt(atmega2560))]
+#[cfg_attr(feature = "atmega32u4", avr_device::interrupt(atmega32u4))]
+fn TIMER1_OVF() {
+    interrupt::free(|cs| {
+        let overflows = OVERFLOWS.borrow(cs);
+        overflows.set(overflows.get().saturating_add(1));
+    });
+}
+
+/// Coarse time measurement with Timer1 and a prescaler of 1024.
+///
+/// The overflows of the 16 bit timer are counted in the overflow interrupt.
+/// Therefore, the interrupts must be enabled during the measurement.
+pub struct Timer {
+    tc1: TC1,
+}
+
+impl Timer {
+    pub fn new(tc1: TC1) -> Self {
+        tc1.tccr1a.reset();
+        tc1.tccr1b.write(|w| w.cs1().prescale_1024());
+        tc1.timsk1.write(|w| w.toie1().set_bit());
+        Self { tc1 }
+    }
+
+    /// Get the timer peripheral.
+    #[cfg(feature = "bench")]
+    pub fn tc1(&self) -> &TC1 {
+        &self.tc1
+    }
+
+    /// Restart the measurement.
+    pub fn start(&self) {
+        interrupt::free(|cs| {
+            self.tc1.tcnt1.write(|w| w.bits(0));
+            // Clear a pending overflow interrupt.
+            self.tc1.tifr1.write(|w| w.tov1().set_bit());
+            OVERFLOWS.borrow(cs).set(0);
+        });
+    }
+
+    /// Get the time since the start of the measurement, in units of 1024 CPU cycles.
+    pub fn elapsed(&self) -> u32 {
+        interrupt::free(|cs| {
+            let mut overflows = OVERFLOWS.borrow(cs).get();
+            let ticks = self.tc1.tcnt1.read().bits();
+            // The overflow interrupt can't run in the critical section.
+            // Count a pending overflow, if it happened before reading the counter.
+            if self.tc1.tifr1.read().tov1().bit() && ticks < 0x8000 {
+                overflows = overflows.saturating_add(1);
+            }
+            ((overflows as u32) << 16) | ticks as u32
+        })
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -2,53 +2,113 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
-use crate::pac::USART0;
+/// Implement the [Uart] for the USART peripheral `$usart`.
+///
+/// The register and bit names of the USARTs only differ in the USART number.
+#[rustfmt::skip]
+macro_rules! impl_uart {
+    (
+        $usart:ident,
+        $udr:ident, $ubrr:ident, $ucsra:ident, $ucsrb:ident, $ucsrc:ident,
+        $u2x:ident, $udre:ident, $txc:ident, $umsel:ident, $ucsz:ident, $usbs:ident, $upm:ident,
+        $txen:ident, $rxen:ident
+    ) => {
+        pub struct Uart {
+            dp: crate::pac::$usart,
+            /// A byte has been written since the last flush.
+            tx_pending: bool,
+        }
 
-pub struct Uart {
-    dp: USART0,
-}
+        impl Uart {
+            pub fn new(dp: crate::pac::$usart) -> Self {
+                dp.$udr.write(|w| {
+                    w.bits(0)
+                });
+                dp.$ubrr.write(|w| {
+                    w.bits(25) // 19200 baud
+                });
+                dp.$ucsra.write(|w| {
+                    w.$u2x().clear_bit()
+                });
+                dp.$ucsrc.write(|w| {
+                    w.$umsel().usart_async()
+                     .$ucsz().chr8()
+                     .$usbs().stop1()
+                     .$upm().disabled()
+                });
+                dp.$ucsrb.write(|w| {
+                    w.$txen().set_bit()
+                     .$rxen().clear_bit()
+                });
 
-impl Uart {
-    #[rustfmt::skip]
-    pub fn new(dp: USART0) -> Self {
-        dp.udr0.write(|w| {
-            w.bits(0)
-        });
-        dp.ubrr0.write(|w| {
-            w.bits(25) // 19200 baud
-        });
-        dp.ucsr0a.write(|w| {
-            w.u2x0().clear_bit()
-        });
-        dp.ucsr0c.write(|w| {
-            w.umsel0().usart_async()
-             .ucsz0().chr8()
-             .usbs0().stop1()
-             .upm0().disabled()
-        });
-        dp.ucsr0b.write(|w| {
-            w.txen0().set_bit()
-             .rxen0().clear_bit()
-        });
-
-        Self { dp }
-    }
+                Self { dp, tx_pending: false }
+            }
+        }
 
-    pub fn tx_byte(&self, data: u8) {
-        while !self.dp.ucsr0a.read().udre0().bit() {
-            // wait for previous tx to finish.
+        impl embedded_hal_nb::serial::Write<u8> for Uart {
+            fn write(&mut self, data: u8) -> nb::Result<(), Self::Error> {
+                if !self.dp.$ucsra.read().$udre().bit() {
+                    // The previous tx is not finished, yet.
+                    return Err(nb::Error::WouldBlock);
+                }
+                // Clear the tx complete flag.
+                // This also writes the u2x and mpcm bits with their configured value 0.
+                self.dp.$ucsra.write(|w| {
+                    w.$txc().set_bit()
+                });
+                self.dp.$udr.write(|w| w.bits(data));
+                self.tx_pending = true;
+                Ok(())
+            }
+
+            fn flush(&mut self) -> nb::Result<(), Self::Error> {
+                if self.tx_pending && !self.dp.$ucsra.read().$txc().bit() {
+                    return Err(nb::Error::WouldBlock);
+                }
+                self.tx_pending = false;
+                Ok(())
+            }
         }
-        self.dp.udr0.write(|w| w.bits(data));
-    }
+    };
+}
+
+#[cfg(any(feature = "atmega328p", feature = "atmega2560"))]
+#[rustfmt::skip]
+impl_uart!(
+    USART0,
+    udr0, ubrr0, ucsr0a, ucsr0b, ucsr0c,
+    u2x0, udre0, txc0, umsel0, ucsz0, usbs0, upm0,
+    txen0, rxen0
+);
+
+// The ATmega32U4 doesn't have USART0.
+#[cfg(feature = "atmega32u4")]
+#[rustfmt::skip]
+impl_uart!(
+    USART1,
+    udr1, ubrr1, ucsr1a, ucsr1b, ucsr1c,
+    u2x1, udre1, txc1, umsel1, ucsz1, usbs1, upm1,
+    txen1, rxen1
+);
+
+impl embedded_hal_nb::serial::ErrorType for Uart {
+    type Error = core::convert::Infallible;
+}
+
+impl embedded_io::ErrorType for Uart {
+    type Error = core::convert::Infallible;
+}
 
-    pub fn tx_slice(&self, data: &[u8]) {
-        for d in data {
-            self.tx_byte(*d);
+impl embedded_io::Write for Uart {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        for d in buf {
+            nb::block!(embedded_hal_nb::serial::Write::write(self, *d))?;
         }
+        Ok(buf.len())
     }
 
-    pub fn tx_str(&self, s: &str) {
-        self.tx_slice(s.as_bytes());
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        nb::block!(embedded_hal_nb::serial::Write::flush(self))
     }
 }
 
@@ -0,0 +1,65 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Benchmarks of the generic (non-AVR) backend.
+//!
+//! Run them with `cargo bench`.
+
+use avr_int24::Int24;
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+/// Operand classes `(name, a, b)`.
+const CLASSES: [(&str, i32, i32); 4] = [
+    ("small", 100, 7),
+    ("large", 0x12_3456, 0x65),
+    ("negative", -0x12_3456, -0x65),
+    ("saturating", 0x7F_FFF0, 0x7F_FFF0),
+];
+
+/// Benchmark the binary operation `f` for all operand classes.
+fn bench2(c: &mut Criterion, name: &str, f: impl Fn(Int24, Int24) -> Int24) {
+    let mut group = c.benchmark_group(name);
+    for (class, a, b) in CLASSES {
+        let (a, b) = (Int24::from_i32(a), Int24::from_i32(b));
+        group.bench_function(class, |bench| {
+            bench.iter(|| f(black_box(a), black_box(b)));
+        });
+    }
+    group.finish();
+}
+
+fn arith(c: &mut Criterion) {
+    bench2(c, "add", |a, b| a + b);
+    bench2(c, "sub", |a, b| a - b);
+    bench2(c, "mul", |a, b| a * b);
+    bench2(c, "mul_shr", |a, b| a.mul_shr(b, 8));
+    bench2(c, "div", |a, b| a / b);
+    bench2(c, "shl8div", |a, b| a.shl8div(b));
+    bench2(c, "shl_div", |a, b| a.shl_div(b, 16));
+    bench2(c, "mul_i16", |a, b| a.mul_i16(b.cast_to_i16()));
+    bench2(c, "mul_u8", |a, b| a.mul_u8(b.cast_to_i8() as u8));
+    bench2(c, "div_u8", |a, b| a.div_u8(b.cast_to_i8() as u8));
+}
+
+fn unary(c: &mut Criterion) {
+    bench2(c, "neg", |a, _| -a);
+    bench2(c, "abs", |a, _| a.abs());
+    bench2(c, "isqrt", |a, _| a.isqrt());
+    bench2(c, "shl", |a, _| a.shl(4));
+    bench2(c, "shr", |a, _| a.shr(4));
+    bench2(c, "cmp", |a, b| Int24::from_i8(a.cmp(b) as i8));
+}
+
+fn slices(c: &mut Criterion) {
+    let data: Vec<Int24> = (0..1024).map(|i| Int24::from_i32(i * 0x1234)).collect();
+    c.bench_function("sum_1024", |bench| {
+        bench.iter(|| black_box(&data).iter().copied().sum::<Int24>());
+    });
+}
+
+criterion_group!(benches, arith, unary, slices);
+criterion_main!(benches);
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,90 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Compare all operations of [Int24] with an `i64` oracle.
+//!
+//! The input is split into records of 7 bytes:
+//! The little endian operands `a` and `b` (3 bytes each) and the shift count (1 byte).
+//!
+//! libFuzzer runs on the host. Therefore, this fuzzes the generic backend
+//! and the saturation and corner case handling around the assembly routines,
+//! but not the AVR assembly itself.
+
+#![no_main]
+
+use avr_int24::Int24;
+use libfuzzer_sys::fuzz_target;
+
+const MIN: i64 = -0x80_0000;
+const MAX: i64 = 0x7F_FFFF;
+
+/// Saturate `v` to the 24 bit range.
+fn sat(v: i64) -> i32 {
+    v.clamp(MIN, MAX) as i32
+}
+
+/// Saturated division with the division by zero semantics of [Int24].
+fn div(a: i64, b: i64) -> i32 {
+    if b == 0 {
+        if a < 0 { MIN as i32 } else { MAX as i32 }
+    } else {
+        sat(a / b)
+    }
+}
+
+/// Sign extend the lower 24 bits of `v`.
+fn wrap(v: i64) -> i32 {
+    ((v as i32) << 8) >> 8
+}
+
+fn check(a: Int24, b: Int24, count: u8) {
+    let (x, y) = (a.to_i32() as i64, b.to_i32() as i64);
+    let r = |v: Int24| v.to_i32();
+
+    assert_eq!(r(a + b), sat(x + y), "add {x} {y}");
+    assert_eq!(r(a - b), sat(x - y), "sub {x} {y}");
+    assert_eq!(r(a * b), sat(x * y), "mul {x} {y}");
+    let c = count % 48;
+    assert_eq!(r(a.mul_shr(b, c)), sat((x * y) >> c), "mul_shr {x} {y} {c}");
+    assert_eq!(r(a / b), div(x, y), "div {x} {y}");
+    assert_eq!(r(a.shl8div(b)), div(x << 8, y), "shl8div {x} {y}");
+    let c = count.min(24);
+    assert_eq!(
+        r(a.shl_div(b, count)),
+        div(x << c, y),
+        "shl_div {x} {y} {count}"
+    );
+    assert_eq!(r(-a), sat(-x), "neg {x}");
+    assert_eq!(r(a.abs()), sat(x.abs()), "abs {x}");
+    assert_eq!(r(a.isqrt()), x.max(0).isqrt() as i32, "isqrt {x}");
+    let c = count % 24;
+    assert_eq!(r(a.shl(c)), wrap(x << c), "shl {x} {c}");
+    assert_eq!(r(a.shr(c)), (x >> c) as i32, "shr {x} {c}");
+    assert_eq!(r(a.shr8()), (x >> 8) as i32, "shr8 {x}");
+    assert_eq!(r(a.shr16()), (x >> 16) as i32, "shr16 {x}");
+    assert_eq!(a.cmp(b), x.cmp(&y), "cmp {x} {y}");
+
+    let s = b.cast_to_i16();
+    assert_eq!(r(a.mul_i16(s)), sat(x * s as i64), "mul_i16 {x} {s}");
+    assert_eq!(
+        r(a.mul_u8(count)),
+        sat(x * count as i64),
+        "mul_u8 {x} {count}"
+    );
+    assert_eq!(
+        r(a.div_u8(count)),
+        div(x, count as i64),
+        "div_u8 {x} {count}"
+    );
+}
+
+fuzz_target!(|data: &[u8]| {
+    for rec in data.chunks_exact(7) {
+        let a = Int24::from_le_bytes([rec[0], rec[1], rec[2]]);
+        let b = Int24::from_le_bytes([rec[3], rec[4], rec[5]]);
+        check(a, b, rec[6]);
+    }
+});
+
+// vim: ts=4 sw=4 expandtab
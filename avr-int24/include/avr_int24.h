@@ -0,0 +1,39 @@
+/* -*- coding: utf-8 -*-
+ * SPDX-License-Identifier: Apache-2.0 OR MIT
+ * Copyright (C) 2025 Michael Büsch <m@bues.ch>
+ *
+ * C declarations of the avr-int24 exports.
+ * The exports are only available with the `ffi` crate feature.
+ *
+ * All values are passed as int32_t.
+ * Arguments outside of the 24 bit range are saturated before the operation.
+ * Results are always within the 24 bit range.
+ */
+
+#ifndef AVR_INT24_H_
+#define AVR_INT24_H_
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+int32_t int24_sat(int32_t a);
+int16_t int24_to_i16(int32_t a);
+int32_t int24_add(int32_t a, int32_t b);
+int32_t int24_sub(int32_t a, int32_t b);
+int32_t int24_mul(int32_t a, int32_t b);
+int32_t int24_div(int32_t a, int32_t b);
+int32_t int24_neg(int32_t a);
+int32_t int24_abs(int32_t a);
+int32_t int24_shl(int32_t a, uint8_t count);
+int32_t int24_shr(int32_t a, uint8_t count);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AVR_INT24_H_ */
+
+/* vim: ts=4 sw=4 expandtab */
@@ -0,0 +1,222 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Helpers for decoding the data of 24 bit ADCs.
+
+use crate::{Error, Int24};
+
+/// Number of data bits clocked out of a 24 bit ADC.
+pub const DATA_BITS: u8 = 24;
+
+/// Channel and gain selection of the HX711.
+///
+/// The HX711 selects the channel and gain for the next conversion
+/// by the number of clock pulses that follow the 24 data bits.
+///
+/// ```
+/// use avr_int24::adc::Hx711Gain;
+///
+/// assert_eq!(Hx711Gain::A128.pulses(), 25);
+/// assert_eq!(Hx711Gain::A64.extra_pulses(), 3);
+/// assert_eq!(Hx711Gain::from_pulses(26), Some(Hx711Gain::B32));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum Hx711Gain {
+    /// Channel A with a gain of 128.
+    #[default]
+    A128,
+    /// Channel B with a gain of 32.
+    B32,
+    /// Channel A with a gain of 64.
+    A64,
+}
+
+impl Hx711Gain {
+    /// Get the number of clock pulses after the data bits.
+    pub const fn extra_pulses(self) -> u8 {
+        match self {
+            Hx711Gain::A128 => 1,
+            Hx711Gain::B32 => 2,
+            Hx711Gain::A64 => 3,
+        }
+    }
+
+    /// Get the total number of clock pulses of one readout, including the data bits.
+    pub const fn pulses(self) -> u8 {
+        DATA_BITS + self.extra_pulses()
+    }
+
+    /// Get the selection for the total number of clock pulses of one readout.
+    ///
+    /// Returns `None`, if `pulses` doesn't select a channel.
+    pub const fn from_pulses(pulses: u8) -> Option<Self> {
+        match pulses {
+            25 => Some(Hx711Gain::A128),
+            26 => Some(Hx711Gain::B32),
+            27 => Some(Hx711Gain::A64),
+            _ => None,
+        }
+    }
+}
+
+/// Total number of clock pulses of one ADS1232 readout.
+///
+/// The 25th pulse forces the DRDY/DOUT line high until the next conversion is ready.
+pub const ADS1232_PULSES: u8 = DATA_BITS + 1;
+
+/// Total number of clock pulses of one ADS1232 readout that starts an offset calibration.
+pub const ADS1232_CALIBRATION_PULSES: u8 = DATA_BITS + 2;
+
+/// Layout of one sample frame of a precision ADC or DAC on the SPI bus.
+///
+/// The sample data is always 24 bit big endian two's complement.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum FrameFormat {
+    /// 3 data bytes (e.g. ADS1256).
+    #[default]
+    Data24,
+    /// 3 data bytes followed by one padding or status byte (e.g. ADS131 in 32 bit word mode).
+    Data24Pad8,
+    /// One status byte followed by 3 data bytes (e.g. MCP3561 with the status byte).
+    Status8Data24,
+}
+
+impl FrameFormat {
+    /// Get the length of one frame in bytes.
+    pub const fn frame_len(self) -> usize {
+        match self {
+            FrameFormat::Data24 => 3,
+            FrameFormat::Data24Pad8 | FrameFormat::Status8Data24 => 4,
+        }
+    }
+
+    /// Get the offset of the data bytes and of the status byte in a frame.
+    const fn offsets(self) -> (usize, Option<usize>) {
+        match self {
+            FrameFormat::Data24 => (0, None),
+            FrameFormat::Data24Pad8 => (0, Some(3)),
+            FrameFormat::Status8Data24 => (1, Some(0)),
+        }
+    }
+
+    /// Decode one frame of exactly the frame length.
+    #[inline(always)]
+    fn decode(self, frame: &[u8]) -> (Int24, u8) {
+        let (data, status) = self.offsets();
+        let v = Int24::from_be_bytes([frame[data], frame[data + 1], frame[data + 2]]);
+        (v, status.map_or(0, |i| frame[i]))
+    }
+}
+
+/// Decode one frame at the start of `frame`.
+///
+/// Returns the sample and the status byte.
+/// The status byte is 0 for formats without status byte.
+/// Returns [Error::SliceTooShort], if `frame` is shorter than one frame.
+///
+/// ```
+/// use avr_int24::{Int24, adc::{self, FrameFormat}};
+///
+/// let spi = [0x81, 0xFF, 0xFF, 0xFE];
+/// let (v, status) = adc::decode_frame(&spi, FrameFormat::Status8Data24).unwrap();
+/// assert_eq!(v, Int24::from_i16(-2));
+/// assert_eq!(status, 0x81);
+/// ```
+pub fn decode_frame(frame: &[u8], format: FrameFormat) -> Result<(Int24, u8), Error> {
+    match frame.get(..format.frame_len()) {
+        Some(frame) => Ok(format.decode(frame)),
+        None => Err(Error::SliceTooShort {
+            needed: format.frame_len(),
+            len: frame.len(),
+        }),
+    }
+}
+
+/// Decode all complete frames in `buf`.
+///
+/// Yields the samples and the status bytes as by [decode_frame].
+/// Trailing bytes that don't form a complete frame are ignored.
+///
+/// ```
+/// use avr_int24::{Int24, adc::{self, FrameFormat}};
+///
+/// let spi = [0x00, 0x01, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00];
+/// let mut it = adc::decode_frames(&spi, FrameFormat::Data24Pad8).map(|(v, _)| v);
+/// assert_eq!(it.next(), Some(Int24::from_i16(0x100)));
+/// assert_eq!(it.next(), Some(Int24::MIN));
+/// assert_eq!(it.next(), None);
+/// ```
+pub fn decode_frames(
+    buf: &[u8],
+    format: FrameFormat,
+) -> impl DoubleEndedIterator<Item = (Int24, u8)> + ExactSizeIterator + '_ {
+    buf.chunks_exact(format.frame_len())
+        .map(move |frame| format.decode(frame))
+}
+
+/// Encode `value` and `status` into one frame at the start of `frame`.
+///
+/// The padding byte of [FrameFormat::Data24Pad8] is set to `status`.
+/// `status` is ignored for formats without status byte.
+///
+/// Returns [Error::SliceTooShort] and writes nothing, if `frame` is shorter than one frame.
+///
+/// ```
+/// use avr_int24::{Int24, adc::{self, FrameFormat}};
+///
+/// let mut spi = [0; 3];
+/// adc::encode_frame(&mut spi, FrameFormat::Data24, Int24::from_i16(-2), 0).unwrap();
+/// assert_eq!(spi, [0xFF, 0xFF, 0xFE]);
+/// ```
+pub fn encode_frame(
+    frame: &mut [u8],
+    format: FrameFormat,
+    value: Int24,
+    status: u8,
+) -> Result<(), Error> {
+    let len = frame.len();
+    let Some(frame) = frame.get_mut(..format.frame_len()) else {
+        return Err(Error::SliceTooShort {
+            needed: format.frame_len(),
+            len,
+        });
+    };
+    let (data, status_pos) = format.offsets();
+    frame[data..data + 3].copy_from_slice(&value.to_be_bytes());
+    if let Some(i) = status_pos {
+        frame[i] = status;
+    }
+    Ok(())
+}
+
+impl Int24 {
+    /// Construct a new [Int24] from the 24 bits clocked out of an HX711.
+    ///
+    /// The bits are shifted in MSB first, so that the last received bit is bit 0 of `bits`.
+    /// The data is two's complement and is sign extended from bit 23.
+    /// The bits above bit 23 are ignored.
+    ///
+    /// The HX711 clamps the conversion result to [Int24::MIN] and [Int24::MAX],
+    /// if the input is out of range.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_hx711_bits(0x00_0010), Int24::from_i16(16));
+    /// assert_eq!(Int24::from_hx711_bits(0xFF_FFF0), Int24::from_i16(-16));
+    /// assert_eq!(Int24::from_hx711_bits(0x80_0000), Int24::MIN);
+    /// ```
+    pub const fn from_hx711_bits(bits: u32) -> Self {
+        Self::from_bits(bits)
+    }
+
+    /// Construct a new [Int24] from the 24 bits clocked out of an ADS1232 or ADS1234.
+    ///
+    /// The data format is identical to [Int24::from_hx711_bits].
+    pub const fn from_ads1232_bits(bits: u32) -> Self {
+        Self::from_hx711_bits(bits)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,162 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Frac24, I16F8, Int24, Int48};
+
+/// Angle as a fraction of a full turn.
+///
+/// The full 24 bit range corresponds to one turn,
+/// so the resolution is `2^-24` turns (about 0.00002 degrees).
+/// All arithmetic operations wrap around modulo one turn instead of saturating.
+/// This is the natural representation for headings and commutation angles.
+///
+/// The raw bits are interpreted as signed, which gives the range -180..180 degrees.
+/// Therefore, the difference of two angles is the signed shortest rotation between them.
+///
+/// ```
+/// use avr_int24::Angle24;
+///
+/// let a = Angle24::from_degrees(170);
+/// let b = a + Angle24::from_degrees(20);
+/// assert_eq!(b, Angle24::from_degrees(-170)); // wrapped around
+/// assert_eq!((a - b).to_degrees().to_int(), -20);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[repr(transparent)]
+pub struct Angle24(Int24);
+
+impl Angle24 {
+    /// Zero degrees.
+    pub const ZERO: Self = Self(Int24::zero());
+
+    /// A quarter turn: 90 degrees.
+    pub const QUARTER: Self = Self(Int24::from_i32(0x40_0000));
+
+    /// Half a turn: -180 degrees, which is the same as 180 degrees.
+    pub const HALF: Self = Self(Int24::MIN);
+
+    /// Construct a new [Angle24] from the raw bits in units of `2^-24` turns.
+    pub const fn from_bits(bits: Int24) -> Self {
+        Self(bits)
+    }
+
+    /// Get the raw bits in units of `2^-24` turns.
+    pub const fn to_bits(self) -> Int24 {
+        self.0
+    }
+
+    /// Construct a new [Angle24] from integer degrees.
+    ///
+    /// The angle is rounded to the nearest representable value.
+    /// Angles outside of -180..180 degrees wrap around.
+    ///
+    /// This uses 64 bit arithmetic and is meant for `const` context.
+    pub const fn from_degrees(deg: i16) -> Self {
+        let bits = (deg as i64 * 0x100_0000 + 180).div_euclid(360);
+        Self(Int24::from_bits(bits as u32))
+    }
+
+    /// Convert this angle to degrees in the range -180..180.
+    ///
+    /// The result is rounded to the nearest value.
+    pub fn to_degrees(self) -> I16F8 {
+        let deg = Int48::mul_int24(self.0, Int24::from_i16(360)) + Int48::from_i32(1 << 15);
+        I16F8::from_bits(deg.to_int24_shr(16))
+    }
+
+    /// Calculate the sine and cosine of this angle.
+    ///
+    /// See [Frac24::sin_cos].
+    pub fn sin_cos(self) -> (Frac24, Frac24) {
+        Frac24::sin_cos(self.0)
+    }
+
+    /// Calculate the sine of this angle.
+    ///
+    /// See [Frac24::sin_cos].
+    pub fn sin(self) -> Frac24 {
+        Frac24::sin(self.0)
+    }
+
+    /// Calculate the cosine of this angle.
+    ///
+    /// See [Frac24::sin_cos].
+    pub fn cos(self) -> Frac24 {
+        Frac24::cos(self.0)
+    }
+
+    /// Get the angle of the vector (`x`, `y`).
+    ///
+    /// See [Int24::atan2].
+    pub fn atan2(y: Int24, x: Int24) -> Self {
+        Self(y.atan2(x))
+    }
+
+    /// Get the absolute difference to `other` along the shortest rotation.
+    ///
+    /// The difference of half a turn is returned as [Int24::MAX] units.
+    pub fn abs_diff(self, other: Self) -> Int24 {
+        (self - other).0.abs()
+    }
+}
+
+impl From<Int24> for Angle24 {
+    fn from(v: Int24) -> Self {
+        Self(v)
+    }
+}
+
+impl From<Angle24> for Int24 {
+    fn from(v: Angle24) -> Self {
+        v.0
+    }
+}
+
+impl core::ops::Add for Angle24 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+}
+
+impl core::ops::AddAssign for Angle24 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub for Angle24 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl core::ops::SubAssign for Angle24 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Neg for Angle24 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+/// Wrapping multiplication by an integer factor, e.g. for the electrical angle of a motor
+/// with multiple pole pairs.
+impl core::ops::Mul<i16> for Angle24 {
+    type Output = Self;
+
+    fn mul(self, other: i16) -> Self {
+        Self(self.0.wrapping_mul(Int24::from_i16(other)))
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
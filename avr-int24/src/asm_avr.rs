@@ -129,6 +129,539 @@ pub fn asm_mulsat24(a: Int24Raw, mut b: Int24Raw) -> Int24Raw {
     b
 }
 
+/// Multiply `a` by `b` and return the low 24 bits of the product.
+///
+/// The low bits of the product don't depend on the signs of the operands.
+/// Therefore, this is a plain unsigned multiplication loop without any saturation.
+#[inline(always)]
+pub fn asm_mul24_lo(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    let mut p: Int24Raw = (0, 0, 0);
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            "   clr {p0}",              // clear product
+            "   clr {p1}",
+            "   clr {p2}",
+            "   ldi {t}, 24",           // loop counter
+
+            "1: lsr {b2}",              // next multiplier bit
+            "   ror {b1}",
+            "   ror {b0}",
+            "   brcc 2f",
+            "   add {p0}, {a0}",
+            "   adc {p1}, {a1}",
+            "   adc {p2}, {a2}",
+
+            "2: lsl {a0}",              // next multiplicand weight
+            "   rol {a1}",
+            "   rol {a2}",
+
+            "   dec {t}",
+            "   brne 1b",               // loop counter != 0?
+
+            a0 = inout(reg) a.0 => _,   // multiplicand
+            a1 = inout(reg) a.1 => _,
+            a2 = inout(reg) a.2 => _,
+
+            b0 = inout(reg) b.0 => _,   // multiplier
+            b1 = inout(reg) b.1 => _,
+            b2 = inout(reg) b.2 => _,
+
+            p0 = out(reg) p.0,          // product
+            p1 = out(reg) p.1,
+            p2 = out(reg) p.2,
+
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    p
+}
+
+#[inline(never)]
+pub fn asm_mul24_wide(mut a: Int24Raw, mut b: Int24Raw) -> (Int24Raw, Int24Raw) {
+    // The multiplication loop can't handle a multiplicand of MIN.
+    if a == (0x00, 0x00, 0x80) {
+        if b == (0x00, 0x00, 0x80) {
+            return ((0x00, 0x00, 0x00), (0x00, 0x00, 0x40));
+        }
+        core::mem::swap(&mut a, &mut b);
+    }
+    let mut hi: Int24Raw = (0, 0, 0);
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            "   ldi {t}, 24",           // loop counter
+            "   sub {p3}, {p3}",        // clear upper product and carry
+            "   sub {p4}, {p4}",
+            "   sub {p5}, {p5}",
+
+            "1: brcc 2f",
+            "   add {p3}, {a0}",
+            "   adc {p4}, {a1}",
+            "   adc {p5}, {a2}",
+
+            "2: sbrs {b0}, 0",
+            "   rjmp 3f",
+            "   sub {p3}, {a0}",
+            "   sbc {p4}, {a1}",
+            "   sbc {p5}, {a2}",
+
+            "3: asr {p5}",
+            "   ror {p4}",
+            "   ror {p3}",
+            "   ror {b2}",
+            "   ror {b1}",
+            "   ror {b0}",
+
+            "   dec {t}",
+            "   brne 1b",               // loop counter != 0?
+
+            a0 = in(reg) a.0,           // multiplicand
+            a1 = in(reg) a.1,
+            a2 = in(reg) a.2,
+
+            b0 = inout(reg) b.0,        // multiplier and product low
+            b1 = inout(reg) b.1,
+            b2 = inout(reg) b.2,
+            p3 = out(reg) hi.0,         // product high
+            p4 = out(reg) hi.1,
+            p5 = out(reg) hi.2,
+
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    (b, hi)
+}
+
+/// Multiply `a` by the 16 bit `b` and shift the 40 bit product right by 8 bits.
+///
+/// The multiplication loop only needs 16 iterations.
+#[inline(never)]
+pub fn asm_mul24x16_shr8(a: Int24Raw, b: i16) -> i32 {
+    let b = b.to_le_bytes();
+    let mut p: [u8; 4] = [0; 4];
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            // The multiplicand and the upper product are sign extended to 32 bits,
+            // so that a multiplicand of MIN doesn't overflow.
+            "   mov {a3}, {a2}",        // multiplicand sign extension
+            "   lsl {a3}",
+            "   sbc {a3}, {a3}",
+
+            "   ldi {t}, 16",           // loop counter
+            "   sub {p3}, {p3}",        // clear upper product and carry
+            "   sub {p4}, {p4}",
+            "   sub {p5}, {p5}",
+            "   sub {p6}, {p6}",
+
+            "1: brcc 2f",
+            "   add {p3}, {a0}",
+            "   adc {p4}, {a1}",
+            "   adc {p5}, {a2}",
+            "   adc {p6}, {a3}",
+
+            "2: sbrs {b0}, 0",
+            "   rjmp 3f",
+            "   sub {p3}, {a0}",
+            "   sbc {p4}, {a1}",
+            "   sbc {p5}, {a2}",
+            "   sbc {p6}, {a3}",
+
+            "3: asr {p6}",
+            "   ror {p5}",
+            "   ror {p4}",
+            "   ror {p3}",
+            "   ror {b1}",
+            "   ror {b0}",
+
+            "   dec {t}",
+            "   brne 1b",               // loop counter != 0?
+
+            a0 = in(reg) a.0,           // multiplicand
+            a1 = in(reg) a.1,
+            a2 = in(reg) a.2,
+            a3 = out(reg) _,
+
+            b0 = inout(reg) b[0] => _,  // multiplier and product low
+            b1 = inout(reg) b[1] => p[0],
+            p3 = out(reg) p[1],         // product high
+            p4 = out(reg) p[2],
+            p5 = out(reg) p[3],
+            p6 = out(reg) _,
+
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    i32::from_le_bytes(p)
+}
+
+/// Multiply `a` by the 16 bit `b` to the full 40 bit product.
+///
+/// The multiplication loop only needs 16 iterations.
+#[inline(never)]
+pub fn asm_mul24x16(a: Int24Raw, b: i16) -> [u8; 5] {
+    let b = b.to_le_bytes();
+    let mut p: [u8; 5] = [0; 5];
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            // The multiplicand and the upper product are sign extended to 32 bits,
+            // so that a multiplicand of MIN doesn't overflow.
+            "   mov {a3}, {a2}",        // multiplicand sign extension
+            "   lsl {a3}",
+            "   sbc {a3}, {a3}",
+
+            "   ldi {t}, 16",           // loop counter
+            "   sub {p3}, {p3}",        // clear upper product and carry
+            "   sub {p4}, {p4}",
+            "   sub {p5}, {p5}",
+            "   sub {p6}, {p6}",
+
+            "1: brcc 2f",
+            "   add {p3}, {a0}",
+            "   adc {p4}, {a1}",
+            "   adc {p5}, {a2}",
+            "   adc {p6}, {a3}",
+
+            "2: sbrs {b0}, 0",
+            "   rjmp 3f",
+            "   sub {p3}, {a0}",
+            "   sbc {p4}, {a1}",
+            "   sbc {p5}, {a2}",
+            "   sbc {p6}, {a3}",
+
+            "3: asr {p6}",
+            "   ror {p5}",
+            "   ror {p4}",
+            "   ror {p3}",
+            "   ror {b1}",
+            "   ror {b0}",
+
+            "   dec {t}",
+            "   brne 1b",               // loop counter != 0?
+
+            a0 = in(reg) a.0,           // multiplicand
+            a1 = in(reg) a.1,
+            a2 = in(reg) a.2,
+            a3 = out(reg) _,
+
+            b0 = inout(reg) b[0] => p[0], // multiplier and product low
+            b1 = inout(reg) b[1] => p[1],
+            p3 = out(reg) p[2],         // product high
+            p4 = out(reg) p[3],
+            p5 = out(reg) p[4],
+            p6 = out(reg) _,
+
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    p
+}
+
+/// Multiply `a` by the unsigned 8 bit `b` to the full 32 bit product.
+///
+/// The multiplication loop only needs 8 iterations.
+#[inline(never)]
+pub fn asm_mul24x8u(a: Int24Raw, b: u8) -> [u8; 4] {
+    let mut p: [u8; 4] = [0; 4];
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            "   mov {a3}, {a2}",        // multiplicand sign extension
+            "   lsl {a3}",
+            "   sbc {a3}, {a3}",
+
+            "   ldi {t}, 8",            // loop counter
+            "   clr {p1}",              // clear upper product
+            "   clr {p2}",
+            "   clr {p3}",
+            "   clr {p4}",
+            "   lsr {b}",               // first multiplier bit
+
+            "1: brcc 2f",
+            "   add {p1}, {a0}",
+            "   adc {p2}, {a1}",
+            "   adc {p3}, {a2}",
+            "   adc {p4}, {a3}",
+
+            "2: asr {p4}",
+            "   ror {p3}",
+            "   ror {p2}",
+            "   ror {p1}",
+            "   ror {b}",               // product low and next multiplier bit
+
+            "   dec {t}",
+            "   brne 1b",               // loop counter != 0?
+
+            a0 = in(reg) a.0,           // multiplicand
+            a1 = in(reg) a.1,
+            a2 = in(reg) a.2,
+            a3 = out(reg) _,
+
+            b = inout(reg) b => p[0],   // multiplier and product low
+            p1 = out(reg) p[1],         // product high
+            p2 = out(reg) p[2],
+            p3 = out(reg) p[3],
+            p4 = out(reg) _,
+
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    p
+}
+
+/// Divide `a` by the unsigned 8 bit `b` and round towards zero.
+///
+/// `b` must not be zero.
+/// The division loop only needs an 8 bit remainder.
+#[inline(never)]
+pub fn asm_div24x8u(a: Int24Raw, b: u8) -> Int24Raw {
+    let mut q = a;
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            // get the absolute value of the dividend
+            "   mov {s}, {q2}",         // dividend sign
+            "   sbrs {s}, 7",
+            "   rjmp 1f",
+            "   com {q2}",
+            "   com {q1}",
+            "   neg {q0}",
+            "   sbci {q1}, 0xFF",
+            "   sbci {q2}, 0xFF",
+
+            // unsigned division
+            "1: ldi {t}, 24",           // loop counter
+            "   clr {r}",               // clear remainder
+            "2: lsl {q0}",              // shift the next dividend bit into the remainder
+            "   rol {q1}",
+            "   rol {q2}",
+            "   rol {r}",
+            "   brcs 3f",               // remainder bigger than 8 bits?
+            "   cp {r}, {d}",
+            "   brcs 4f",               // remainder < divisor?
+            "3: sub {r}, {d}",
+            "   inc {q0}",              // set quotient bit
+            "4: dec {t}",
+            "   brne 2b",               // loop counter != 0?
+
+            // restore the sign
+            "   sbrs {s}, 7",
+            "   rjmp 5f",
+            "   com {q2}",
+            "   com {q1}",
+            "   neg {q0}",
+            "   sbci {q1}, 0xFF",
+            "   sbci {q2}, 0xFF",
+            "5:",
+
+            q0 = inout(reg) q.0,        // dividend and quotient
+            q1 = inout(reg_upper) q.1,
+            q2 = inout(reg_upper) q.2,
+
+            d = in(reg) b,              // divisor
+            r = out(reg) _,             // remainder
+            s = out(reg) _,             // sign
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    q
+}
+
+// The assembly loops read [Int24Raw] slices as plain little endian byte arrays.
+const _: () = assert!(
+    core::mem::size_of::<Int24Raw>() == 3
+        && core::mem::offset_of!(Int24Raw, 0) == 0
+        && core::mem::offset_of!(Int24Raw, 1) == 1
+        && core::mem::offset_of!(Int24Raw, 2) == 2
+);
+
+/// Sum up all elements of `data` onto `acc`.
+///
+/// The accumulator has 48 bits and wraps on overflow.
+/// At most 65535 elements are processed.
+#[inline(never)]
+pub fn asm_sum24(acc: i64, data: &[Int24Raw]) -> i64 {
+    // usize is 16 bits wide on AVR.
+    let n = data.len() as u16;
+    if n == 0 {
+        return acc;
+    }
+    let mut c = acc.to_le_bytes();
+    // SAFETY: The assembly code is manually checked.
+    //         It reads n * 3 bytes from `data`, which is within the bounds of the slice.
+    unsafe {
+        asm!(
+            "1: ld {t0}, Z+",           // load the element
+            "   ld {t1}, Z+",
+            "   ld {t2}, Z+",
+            "   clr {t3}",              // sign extension
+            "   sbrc {t2}, 7",
+            "   com {t3}",
+
+            "   add {c0}, {t0}",        // accumulate
+            "   adc {c1}, {t1}",
+            "   adc {c2}, {t2}",
+            "   adc {c3}, {t3}",
+            "   adc {c4}, {t3}",
+            "   adc {c5}, {t3}",
+
+            "   sbiw {n}, 1",
+            "   brne 1b",               // element counter != 0?
+
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg) _,
+            t3 = out(reg) _,
+
+            c0 = inout(reg) c[0],       // accumulator
+            c1 = inout(reg) c[1],
+            c2 = inout(reg) c[2],
+            c3 = inout(reg) c[3],
+            c4 = inout(reg) c[4],
+            c5 = inout(reg) c[5],
+
+            n = inout(reg_iw) n as i16 => _,
+            inout("Z") data.as_ptr() => _,
+
+            options(readonly, nostack),
+        );
+    }
+    let ext = if c[5] & 0x80 == 0 { 0x00 } else { 0xFF };
+    c[6] = ext;
+    c[7] = ext;
+    i64::from_le_bytes(c)
+}
+
+/// Multiply-accumulate the products of the elements of `a` and `b` onto `acc`.
+///
+/// The accumulator has 56 bits and wraps on overflow.
+/// At most 255 elements are processed.
+#[inline(never)]
+pub fn asm_mac24(acc: i64, a: &[Int24Raw], b: &[Int24Raw]) -> i64 {
+    let n = a.len().min(b.len()).min(255) as u8;
+    if n == 0 {
+        return acc;
+    }
+    let mut c = acc.to_le_bytes();
+    // SAFETY: The assembly code is manually checked.
+    //         It reads n * 3 bytes from `a` and `b`, which is within the bounds
+    //         of both slices.
+    unsafe {
+        asm!(
+            "1: ld {a0}, Z+",           // load multiplicand
+            "   ld {a1}, Z+",
+            "   ld {a2}, Z+",
+            "   ld {b0}, X+",           // load multiplier
+            "   ld {b1}, X+",
+            "   ld {b2}, X+",
+
+            // The multiplication loop can't handle a multiplicand of MIN.
+            "   cpi {a2}, 0x80",        // multiplicand == MIN?
+            "   cpc {a1}, r1",
+            "   cpc {a0}, r1",
+            "   brne 2f",
+            "   mov {t}, {b0}",         // swap multiplicand and multiplier
+            "   mov {b0}, {a0}",
+            "   mov {a0}, {t}",
+            "   mov {t}, {b1}",
+            "   mov {b1}, {a1}",
+            "   mov {a1}, {t}",
+            "   mov {t}, {b2}",
+            "   mov {b2}, {a2}",
+            "   mov {a2}, {t}",
+            "   cpi {a2}, 0x80",        // multiplicand == MIN?
+            "   cpc {a1}, r1",
+            "   cpc {a0}, r1",
+            "   brne 2f",
+            "   ldi {t}, 0x40",         // accumulate MIN * MIN = 2^46
+            "   add {c5}, {t}",
+            "   adc {c6}, r1",
+            "   rjmp 6f",
+
+            "2: ldi {t}, 24",           // bit loop counter
+            "   sub {p3}, {p3}",        // clear upper product and carry
+            "   sub {p4}, {p4}",
+            "   sub {p5}, {p5}",
+
+            "3: brcc 4f",
+            "   add {p3}, {a0}",
+            "   adc {p4}, {a1}",
+            "   adc {p5}, {a2}",
+
+            "4: sbrs {b0}, 0",
+            "   rjmp 5f",
+            "   sub {p3}, {a0}",
+            "   sbc {p4}, {a1}",
+            "   sbc {p5}, {a2}",
+
+            "5: asr {p5}",
+            "   ror {p4}",
+            "   ror {p3}",
+            "   ror {b2}",
+            "   ror {b1}",
+            "   ror {b0}",
+
+            "   dec {t}",
+            "   brne 3b",               // bit loop counter != 0?
+
+            "   add {c0}, {b0}",        // accumulate the product
+            "   adc {c1}, {b1}",
+            "   adc {c2}, {b2}",
+            "   adc {c3}, {p3}",
+            "   adc {c4}, {p4}",
+            "   adc {c5}, {p5}",
+            "   sbrc {p5}, 7",          // sign extend the product; t is 0 here
+            "   ldi {t}, 0xFF",
+            "   adc {c6}, {t}",
+
+            "6: dec {n}",
+            "   brne 1b",               // element counter != 0?
+
+            a0 = out(reg) _,            // multiplicand
+            a1 = out(reg) _,
+            a2 = out(reg_upper) _,
+            b0 = out(reg) _,            // multiplier and product low
+            b1 = out(reg) _,
+            b2 = out(reg) _,
+            p3 = out(reg) _,            // product high
+            p4 = out(reg) _,
+            p5 = out(reg) _,
+
+            c0 = inout(reg) c[0],       // accumulator
+            c1 = inout(reg) c[1],
+            c2 = inout(reg) c[2],
+            c3 = inout(reg) c[3],
+            c4 = inout(reg) c[4],
+            c5 = inout(reg) c[5],
+            c6 = inout(reg) c[6],
+
+            t = out(reg_upper) _,
+            n = inout(reg) n => _,
+
+            inout("Z") a.as_ptr() => _,
+            inout("X") b.as_ptr() => _,
+
+            options(readonly, nostack),
+        );
+    }
+    c[7] = if c[6] & 0x80 == 0 { 0x00 } else { 0xFF };
+    i64::from_le_bytes(c)
+}
+
 #[inline(never)]
 #[allow(unused_assignments)]
 pub fn asm_divsat24(mut a: Int24Raw, mut b: Int24Raw, a_shl8: bool) -> Int24Raw {
@@ -166,7 +699,7 @@ pub fn asm_divsat24(mut a: Int24Raw, mut b: Int24Raw, a_shl8: bool) -> Int24Raw
             "   sbrc {t}, 7",
             "   set",
 
-            // a = abs(a)
+            // a = abs(a) as unsigned 24 bit
             "   sbrs {a2}, 7",
             "   rjmp 1f",
             "   com {a2}",              // negate
@@ -174,14 +707,9 @@ pub fn asm_divsat24(mut a: Int24Raw, mut b: Int24Raw, a_shl8: bool) -> Int24Raw
             "   neg {a0}",
             "   sbci {a1}, 0xFF",
             "   sbci {a2}, 0xFF",
-            "   sbrs {a2}, 7",
-            "   rjmp 1f",
-            "   ldi {a1}, 0xFF",        // saturate to max
-            "   mov {a0}, {a1}",
-            "   ldi {a2}, 0x7F",
             "1:",
 
-            // b = abs(b)
+            // b = abs(b) as unsigned 24 bit
             "   sbrs {b2}, 7",
             "   rjmp 1f",
             "   com {b2}",              // negate
@@ -189,11 +717,6 @@ pub fn asm_divsat24(mut a: Int24Raw, mut b: Int24Raw, a_shl8: bool) -> Int24Raw
             "   neg {b0}",
             "   sbci {b1}, 0xFF",
             "   sbci {b2}, 0xFF",
-            "   sbrs {b2}, 7",
-            "   rjmp 1f",
-            "   ldi {b1}, 0xFF",        // saturate to max
-            "   mov {b0}, {b1}",
-            "   ldi {b2}, 0x7F",
             "1:",
 
             // check if 'a' shall be left shifted by 8 before division
@@ -273,11 +796,11 @@ pub fn asm_divsat24(mut a: Int24Raw, mut b: Int24Raw, a_shl8: bool) -> Int24Raw
             "   rjmp 1b",
 
             "3: cp {a3}, __zero_reg__", // saturate 32 bit result
-            "   breq 80f",
-            "   ldi {a2}, 0x7F",
-            "   ldi {a1}, 0xFF",
-            "   mov {a0}, {a1}",
+            "   brne 4f",
+            "   sbrs {a2}, 7",
             "   rjmp 80f",
+            "4: brts 60f",
+            "   rjmp 70f",
 
             // saturate to negative min
             "60:",
@@ -328,12 +851,75 @@ pub fn asm_divsat24(mut a: Int24Raw, mut b: Int24Raw, a_shl8: bool) -> Int24Raw
     a
 }
 
-#[inline(always)]
-pub fn asm_negsat24(mut a: Int24Raw) -> Int24Raw {
+/// Integer square root of an unsigned 24 bit value. The result has 12 bits.
+#[inline(never)]
+pub fn asm_isqrt24(a: Int24Raw) -> (u8, u8) {
+    let mut q: (u8, u8) = (0, 0);
     // SAFETY: The assembly code is manually checked.
     unsafe {
         asm!(
-            "   mov {t}, {a2}",
+            "   ldi {cnt}, 12",         // loop counter
+            "   clr {r0}",              // clear remainder
+            "   clr {r1}",
+            "   clr {q0}",              // clear root
+            "   clr {q1}",
+
+            "1: lsl {a0}",              // shift the next two bits into the remainder
+            "   rol {a1}",
+            "   rol {a2}",
+            "   rol {r0}",
+            "   rol {r1}",
+            "   lsl {a0}",
+            "   rol {a1}",
+            "   rol {a2}",
+            "   rol {r0}",
+            "   rol {r1}",
+
+            "   lsl {q0}",              // root <<= 1
+            "   rol {q1}",
+
+            "   mov {t0}, {q0}",        // t = (root << 1) | 1
+            "   mov {t1}, {q1}",
+            "   sec",
+            "   rol {t0}",
+            "   rol {t1}",
+
+            "   cp {r0}, {t0}",         // remainder >= t?
+            "   cpc {r1}, {t1}",
+            "   brlo 2f",
+            "   sub {r0}, {t0}",        // remainder -= t
+            "   sbc {r1}, {t1}",
+            "   inc {q0}",              // root |= 1
+
+            "2: dec {cnt}",
+            "   brne 1b",               // loop counter != 0?
+
+            a0 = inout(reg) a.0 => _,   // radicand
+            a1 = inout(reg) a.1 => _,
+            a2 = inout(reg) a.2 => _,
+
+            q0 = out(reg) q.0,          // root
+            q1 = out(reg) q.1,
+
+            r0 = out(reg) _,            // remainder
+            r1 = out(reg) _,
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+
+            cnt = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    q
+}
+
+#[inline(always)]
+pub fn asm_negsat24(mut a: Int24Raw) -> Int24Raw {
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            "   mov {t}, {a2}",
 
             "   com {a2}",
             "   com {a1}",
@@ -361,6 +947,688 @@ pub fn asm_negsat24(mut a: Int24Raw) -> Int24Raw {
     a
 }
 
+/// Saturating 24 bit addition of `b` to the value in memory at `a`.
+#[inline(always)]
+pub fn asm_addsat24_in_place(a: &mut Int24Raw, b: Int24Raw) {
+    // SAFETY: The assembly code is manually checked.
+    //         It reads and writes the 3 bytes of `a`.
+    unsafe {
+        asm!(
+            "   ld {t0}, Z",            // load the accumulator
+            "   ldd {t1}, Z+1",
+            "   ldd {t2}, Z+2",
+
+            "   add {t0}, {b0}",
+            "   adc {t1}, {b1}",
+            "   adc {t2}, {b2}",
+            "   brvc 1f",               // no overflow?
+
+            // saturate to max, if the overflowed sum is negative, else to min
+            "   lsl {t2}",
+            "   sbc {t0}, {t0}",
+            "   mov {t1}, {t0}",
+            "   mov {t2}, {t0}",
+            "   subi {t2}, 0x80",
+
+            "1: st Z, {t0}",            // store the accumulator
+            "   std Z+1, {t1}",
+            "   std Z+2, {t2}",
+
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg_upper) _,
+
+            b0 = in(reg) b.0,
+            b1 = in(reg) b.1,
+            b2 = in(reg) b.2,
+
+            in("Z") a as *mut Int24Raw,
+
+            options(nostack),
+        );
+    }
+}
+
+/// Saturating 24 bit subtraction of `b` from the value in memory at `a`.
+#[inline(always)]
+pub fn asm_subsat24_in_place(a: &mut Int24Raw, b: Int24Raw) {
+    // SAFETY: The assembly code is manually checked.
+    //         It reads and writes the 3 bytes of `a`.
+    unsafe {
+        asm!(
+            "   ld {t0}, Z",            // load the accumulator
+            "   ldd {t1}, Z+1",
+            "   ldd {t2}, Z+2",
+
+            "   sub {t0}, {b0}",
+            "   sbc {t1}, {b1}",
+            "   sbc {t2}, {b2}",
+            "   brvc 1f",               // no overflow?
+
+            // saturate to max, if the overflowed difference is negative, else to min
+            "   lsl {t2}",
+            "   sbc {t0}, {t0}",
+            "   mov {t1}, {t0}",
+            "   mov {t2}, {t0}",
+            "   subi {t2}, 0x80",
+
+            "1: st Z, {t0}",            // store the accumulator
+            "   std Z+1, {t1}",
+            "   std Z+2, {t2}",
+
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg_upper) _,
+
+            b0 = in(reg) b.0,
+            b1 = in(reg) b.1,
+            b2 = in(reg) b.2,
+
+            in("Z") a as *mut Int24Raw,
+
+            options(nostack),
+        );
+    }
+}
+
+/// Saturating 24 bit element-wise addition of the packed elements of `src` to `dst`.
+#[inline(never)]
+pub fn asm_addsat24_buffers(dst: &mut [[u8; 3]], src: &[[u8; 3]]) {
+    // usize is 16 bits wide on AVR.
+    let n = dst.len().min(src.len()) as u16;
+    if n == 0 {
+        return;
+    }
+    // SAFETY: The assembly code is manually checked.
+    //         It reads and writes n * 3 bytes of `dst` and reads n * 3 bytes of `src`,
+    //         which is within the bounds of the slices.
+    unsafe {
+        asm!(
+            "1: ld {t0}, Z",            // load the destination element
+            "   ldd {t1}, Z+1",
+            "   ldd {t2}, Z+2",
+            "   ld {b0}, X+",           // load the source element
+            "   ld {b1}, X+",
+            "   ld {b2}, X+",
+
+            "   add {t0}, {b0}",
+            "   adc {t1}, {b1}",
+            "   adc {t2}, {b2}",
+            "   brvc 2f",               // no overflow?
+
+            // saturate to max, if the overflowed sum is negative, else to min
+            "   lsl {t2}",
+            "   sbc {t0}, {t0}",
+            "   mov {t1}, {t0}",
+            "   mov {t2}, {t0}",
+            "   subi {t2}, 0x80",
+
+            "2: st Z+, {t0}",           // store the destination element
+            "   st Z+, {t1}",
+            "   st Z+, {t2}",
+
+            "   sbiw {n}, 1",
+            "   brne 1b",               // element counter != 0?
+
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg_upper) _,
+            b0 = out(reg) _,
+            b1 = out(reg) _,
+            b2 = out(reg) _,
+
+            n = inout(reg_iw) n as i16 => _,
+            inout("Z") dst.as_mut_ptr() => _,
+            inout("X") src.as_ptr() => _,
+
+            options(nostack),
+        );
+    }
+}
+
+/// Saturating 24 bit element-wise subtraction of the packed elements of `src` from `dst`.
+#[inline(never)]
+pub fn asm_subsat24_buffers(dst: &mut [[u8; 3]], src: &[[u8; 3]]) {
+    // usize is 16 bits wide on AVR.
+    let n = dst.len().min(src.len()) as u16;
+    if n == 0 {
+        return;
+    }
+    // SAFETY: The assembly code is manually checked.
+    //         It reads and writes n * 3 bytes of `dst` and reads n * 3 bytes of `src`,
+    //         which is within the bounds of the slices.
+    unsafe {
+        asm!(
+            "1: ld {t0}, Z",            // load the destination element
+            "   ldd {t1}, Z+1",
+            "   ldd {t2}, Z+2",
+            "   ld {b0}, X+",           // load the source element
+            "   ld {b1}, X+",
+            "   ld {b2}, X+",
+
+            "   sub {t0}, {b0}",
+            "   sbc {t1}, {b1}",
+            "   sbc {t2}, {b2}",
+            "   brvc 2f",               // no overflow?
+
+            // saturate to max, if the overflowed difference is negative, else to min
+            "   lsl {t2}",
+            "   sbc {t0}, {t0}",
+            "   mov {t1}, {t0}",
+            "   mov {t2}, {t0}",
+            "   subi {t2}, 0x80",
+
+            "2: st Z+, {t0}",           // store the destination element
+            "   st Z+, {t1}",
+            "   st Z+, {t2}",
+
+            "   sbiw {n}, 1",
+            "   brne 1b",               // element counter != 0?
+
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg_upper) _,
+            b0 = out(reg) _,
+            b1 = out(reg) _,
+            b2 = out(reg) _,
+
+            n = inout(reg_iw) n as i16 => _,
+            inout("Z") dst.as_mut_ptr() => _,
+            inout("X") src.as_ptr() => _,
+
+            options(nostack),
+        );
+    }
+}
+
+/// Saturating 24 bit negation of all packed elements of `buf`.
+#[inline(never)]
+pub fn asm_negsat24_buffer(buf: &mut [[u8; 3]]) {
+    // usize is 16 bits wide on AVR.
+    let n = buf.len() as u16;
+    if n == 0 {
+        return;
+    }
+    // SAFETY: The assembly code is manually checked.
+    //         It reads and writes n * 3 bytes of `buf`, which is within the bounds of the slice.
+    unsafe {
+        asm!(
+            "1: ld {b0}, Z",            // load the element
+            "   ldd {b1}, Z+1",
+            "   ldd {b2}, Z+2",
+
+            "   clr {t0}",
+            "   clr {t1}",
+            "   clr {t2}",
+            "   sub {t0}, {b0}",
+            "   sbc {t1}, {b1}",
+            "   sbc {t2}, {b2}",
+            "   brvc 2f",               // no overflow?
+
+            // Only the negation of min overflows to min.
+            // Saturate it to max.
+            "   com {t0}",
+            "   com {t1}",
+            "   com {t2}",
+
+            "2: st Z+, {t0}",           // store the element
+            "   st Z+, {t1}",
+            "   st Z+, {t2}",
+
+            "   sbiw {n}, 1",
+            "   brne 1b",               // element counter != 0?
+
+            t0 = out(reg) _,
+            t1 = out(reg) _,
+            t2 = out(reg) _,
+            b0 = out(reg) _,
+            b1 = out(reg) _,
+            b2 = out(reg) _,
+
+            n = inout(reg_iw) n as i16 => _,
+            inout("Z") buf.as_mut_ptr() => _,
+
+            options(nostack),
+        );
+    }
+}
+
+// The constant time operations don't contain any data dependent branches.
+// Conditional operations are done with masks or by skipping a single one word instruction.
+// A skip takes the same 2 cycles as the execution of the skipped instruction.
+
+/// Constant time saturating 24 bit addition.
+#[inline(always)]
+pub fn asm_ct_addsat24(mut a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            "   clr {m}",
+            "   add {a0}, {b0}",
+            "   adc {a1}, {b1}",
+            "   adc {a2}, {b2}",
+            "   brvc 1f",               // no overflow? (taken branch: 2 cycles)
+            "   com {m}",               // m = overflow mask
+            "1:",
+
+            // s = 0xFF, if the overflowed sum is negative (saturate to max)
+            "   mov {s}, {a2}",
+            "   lsl {s}",
+            "   sbc {s}, {s}",
+
+            // saturate: a ^= (a ^ sat) & m
+            "   mov {t}, {a0}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a0}, {t}",
+            "   mov {t}, {a1}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a1}, {t}",
+            "   ldi {t}, 0x80",
+            "   eor {s}, {t}",
+            "   mov {t}, {a2}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a2}, {t}",
+
+            a0 = inout(reg) a.0,
+            a1 = inout(reg) a.1,
+            a2 = inout(reg) a.2,
+
+            b0 = in(reg) b.0,
+            b1 = in(reg) b.1,
+            b2 = in(reg) b.2,
+
+            m = out(reg) _,
+            s = out(reg) _,
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    a
+}
+
+/// Constant time saturating 24 bit subtraction.
+#[inline(always)]
+pub fn asm_ct_subsat24(mut a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            "   clr {m}",
+            "   sub {a0}, {b0}",
+            "   sbc {a1}, {b1}",
+            "   sbc {a2}, {b2}",
+            "   brvc 1f",               // no overflow? (taken branch: 2 cycles)
+            "   com {m}",               // m = overflow mask
+            "1:",
+
+            // s = 0xFF, if the overflowed difference is negative (saturate to max)
+            "   mov {s}, {a2}",
+            "   lsl {s}",
+            "   sbc {s}, {s}",
+
+            // saturate: a ^= (a ^ sat) & m
+            "   mov {t}, {a0}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a0}, {t}",
+            "   mov {t}, {a1}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a1}, {t}",
+            "   ldi {t}, 0x80",
+            "   eor {s}, {t}",
+            "   mov {t}, {a2}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a2}, {t}",
+
+            a0 = inout(reg) a.0,
+            a1 = inout(reg) a.1,
+            a2 = inout(reg) a.2,
+
+            b0 = in(reg) b.0,
+            b1 = in(reg) b.1,
+            b2 = in(reg) b.2,
+
+            m = out(reg) _,
+            s = out(reg) _,
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    a
+}
+
+/// Constant time saturating 24 bit negation.
+#[inline(always)]
+pub fn asm_ct_negsat24(a: Int24Raw) -> Int24Raw {
+    let mut r: Int24Raw = (0, 0, 0);
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            "   clr {m}",
+            "   clr {r0}",
+            "   clr {r1}",
+            "   clr {r2}",
+            "   sub {r0}, {a0}",
+            "   sbc {r1}, {a1}",
+            "   sbc {r2}, {a2}",
+            "   brvc 1f",               // no overflow? (taken branch: 2 cycles)
+            "   com {m}",               // m = overflow mask
+            "1:",
+
+            // Only the negation of min overflows to min.
+            // Invert it to max.
+            "   eor {r0}, {m}",
+            "   eor {r1}, {m}",
+            "   eor {r2}, {m}",
+
+            a0 = in(reg) a.0,
+            a1 = in(reg) a.1,
+            a2 = in(reg) a.2,
+
+            r0 = out(reg) r.0,
+            r1 = out(reg) r.1,
+            r2 = out(reg) r.2,
+
+            m = out(reg) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    r
+}
+
+/// Constant time saturating 24 bit absolute value.
+#[inline(always)]
+pub fn asm_ct_abssat24(mut a: Int24Raw) -> Int24Raw {
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            // s = 0xFF, if negative
+            "   mov {s}, {a2}",
+            "   lsl {s}",
+            "   sbc {s}, {s}",
+
+            // conditional negation: a = (a ^ s) - s
+            "   clr {m}",
+            "   eor {a0}, {s}",
+            "   eor {a1}, {s}",
+            "   eor {a2}, {s}",
+            "   sub {a0}, {s}",
+            "   sbc {a1}, {s}",
+            "   sbc {a2}, {s}",
+            "   brvc 1f",               // no overflow? (taken branch: 2 cycles)
+            "   com {m}",               // m = overflow mask
+            "1:",
+
+            // Only the absolute value of min overflows to min.
+            // Invert it to max.
+            "   eor {a0}, {m}",
+            "   eor {a1}, {m}",
+            "   eor {a2}, {m}",
+
+            a0 = inout(reg) a.0,
+            a1 = inout(reg) a.1,
+            a2 = inout(reg) a.2,
+
+            m = out(reg) _,
+            s = out(reg) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    a
+}
+
+/// Constant time saturating 24 bit multiplication.
+///
+/// This multiplies the absolute values in a fixed count unsigned loop.
+#[inline(always)]
+pub fn asm_ct_mulsat24(a: Int24Raw, mut b: Int24Raw) -> Int24Raw {
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            // s = 0xFF, if the product is negative
+            "   mov {s}, {a2}",
+            "   eor {s}, {b2}",
+            "   lsl {s}",
+            "   sbc {s}, {s}",
+
+            // absolute values: x = (x ^ t) - t
+            "   mov {t}, {a2}",
+            "   lsl {t}",
+            "   sbc {t}, {t}",
+            "   eor {a0}, {t}",
+            "   eor {a1}, {t}",
+            "   eor {a2}, {t}",
+            "   sub {a0}, {t}",
+            "   sbc {a1}, {t}",
+            "   sbc {a2}, {t}",
+            "   mov {t}, {b2}",
+            "   lsl {t}",
+            "   sbc {t}, {t}",
+            "   eor {b0}, {t}",
+            "   eor {b1}, {t}",
+            "   eor {b2}, {t}",
+            "   sub {b0}, {t}",
+            "   sbc {b1}, {t}",
+            "   sbc {b2}, {t}",
+
+            // unsigned multiplication logic
+
+            "   ldi {t}, 24",           // loop counter
+            "   clr {p3}",              // clear upper product
+            "   clr {p4}",
+            "   clr {p5}",
+
+            "1: clc",
+            "   sbrc {b0}, 0",
+            "   add {p3}, {a0}",
+            "   sbrc {b0}, 0",
+            "   adc {p4}, {a1}",
+            "   sbrc {b0}, 0",
+            "   adc {p5}, {a2}",
+
+            "   ror {p5}",
+            "   ror {p4}",
+            "   ror {p3}",
+            "   ror {b2}",
+            "   ror {b1}",
+            "   ror {b0}",
+
+            "   dec {t}",
+            "   brne 1b",               // loop counter != 0?
+
+            // The product fits, if it is less than 0x80_0000 + (1 if negative).
+            "   mov {t}, {s}",
+            "   andi {t}, 1",
+            "   cp {b0}, {t}",
+            "   cpc {b1}, __zero_reg__",
+            "   ldi {t}, 0x80",
+            "   cpc {b2}, {t}",
+            "   cpc {p3}, __zero_reg__",
+            "   cpc {p4}, __zero_reg__",
+            "   cpc {p5}, __zero_reg__",
+            "   sbc {p3}, {p3}",
+            "   com {p3}",              // p3 = overflow mask
+
+            // conditional negation: b = (b ^ s) - s
+            "   eor {b0}, {s}",
+            "   eor {b1}, {s}",
+            "   eor {b2}, {s}",
+            "   sub {b0}, {s}",
+            "   sbc {b1}, {s}",
+            "   sbc {b2}, {s}",
+
+            // s = 0xFF, if the product is positive (saturate to max)
+            "   com {s}",
+
+            // saturate: b ^= (b ^ sat) & p3
+            "   mov {t}, {b0}",
+            "   eor {t}, {s}",
+            "   and {t}, {p3}",
+            "   eor {b0}, {t}",
+            "   mov {t}, {b1}",
+            "   eor {t}, {s}",
+            "   and {t}, {p3}",
+            "   eor {b1}, {t}",
+            "   ldi {t}, 0x80",
+            "   eor {s}, {t}",
+            "   mov {t}, {b2}",
+            "   eor {t}, {s}",
+            "   and {t}, {p3}",
+            "   eor {b2}, {t}",
+
+            a0 = inout(reg) a.0 => _,   // multiplicand
+            a1 = inout(reg) a.1 => _,
+            a2 = inout(reg) a.2 => _,
+
+            b0 = inout(reg) b.0,        // multiplier and product low
+            b1 = inout(reg) b.1,
+            b2 = inout(reg) b.2,
+            p3 = out(reg) _,            // product high
+            p4 = out(reg) _,
+            p5 = out(reg) _,
+
+            s = out(reg) _,
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    b
+}
+
+/// Constant time saturating 24 bit division.
+///
+/// This divides the absolute values in a fixed count unsigned restoring division loop.
+/// The division by zero results in the same saturated values as [asm_divsat24].
+#[inline(always)]
+pub fn asm_ct_divsat24(mut a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    // SAFETY: The assembly code is manually checked.
+    unsafe {
+        asm!(
+            // s = 0xFF, if the quotient is negative
+            "   mov {s}, {a2}",
+            "   eor {s}, {b2}",
+            "   lsl {s}",
+            "   sbc {s}, {s}",
+
+            // absolute values: x = (x ^ t) - t
+            "   mov {t}, {a2}",
+            "   lsl {t}",
+            "   sbc {t}, {t}",
+            "   eor {a0}, {t}",
+            "   eor {a1}, {t}",
+            "   eor {a2}, {t}",
+            "   sub {a0}, {t}",
+            "   sbc {a1}, {t}",
+            "   sbc {a2}, {t}",
+            "   mov {t}, {b2}",
+            "   lsl {t}",
+            "   sbc {t}, {t}",
+            "   eor {b0}, {t}",
+            "   eor {b1}, {t}",
+            "   eor {b2}, {t}",
+            "   sub {b0}, {t}",
+            "   sbc {b1}, {t}",
+            "   sbc {b2}, {t}",
+
+            // unsigned division logic
+
+            "   ldi {t}, 24",           // loop counter
+            "   clr {r0}",              // clear remainder
+            "   clr {r1}",
+            "   clr {r2}",
+
+            "1: lsl {a0}",              // shift dividend into remainder
+            "   rol {a1}",
+            "   rol {a2}",
+            "   rol {r0}",
+            "   rol {r1}",
+            "   rol {r2}",
+
+            "   sub {r0}, {b0}",        // trial subtraction
+            "   sbc {r1}, {b1}",
+            "   sbc {r2}, {b2}",
+            "   sbc {m}, {m}",          // m = 0xFF, if the divisor didn't fit
+
+            "   sbrc {m}, 0",           // restore remainder
+            "   add {r0}, {b0}",
+            "   sbrc {m}, 0",
+            "   adc {r1}, {b1}",
+            "   sbrc {m}, 0",
+            "   adc {r2}, {b2}",
+            "   sbrs {m}, 0",           // set quotient bit
+            "   inc {a0}",
+
+            "   dec {t}",
+            "   brne 1b",               // loop counter != 0?
+
+            // The quotient fits, if it is less than 0x80_0000 + (1 if negative).
+            "   mov {t}, {s}",
+            "   andi {t}, 1",
+            "   cp {a0}, {t}",
+            "   cpc {a1}, __zero_reg__",
+            "   ldi {t}, 0x80",
+            "   cpc {a2}, {t}",
+            "   sbc {m}, {m}",
+            "   com {m}",               // m = overflow mask
+
+            // conditional negation: a = (a ^ s) - s
+            "   eor {a0}, {s}",
+            "   eor {a1}, {s}",
+            "   eor {a2}, {s}",
+            "   sub {a0}, {s}",
+            "   sbc {a1}, {s}",
+            "   sbc {a2}, {s}",
+
+            // s = 0xFF, if the quotient is positive (saturate to max)
+            "   com {s}",
+
+            // saturate: a ^= (a ^ sat) & m
+            "   mov {t}, {a0}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a0}, {t}",
+            "   mov {t}, {a1}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a1}, {t}",
+            "   ldi {t}, 0x80",
+            "   eor {s}, {t}",
+            "   mov {t}, {a2}",
+            "   eor {t}, {s}",
+            "   and {t}, {m}",
+            "   eor {a2}, {t}",
+
+            a0 = inout(reg) a.0,        // dividend and quotient
+            a1 = inout(reg) a.1,
+            a2 = inout(reg) a.2,
+
+            b0 = inout(reg) b.0 => _,   // divisor
+            b1 = inout(reg) b.1 => _,
+            b2 = inout(reg) b.2 => _,
+
+            r0 = out(reg) _,            // remainder
+            r1 = out(reg) _,
+            r2 = out(reg) _,
+
+            m = out(reg) _,
+            s = out(reg) _,
+            t = out(reg_upper) _,
+
+            options(pure, nomem, nostack),
+        );
+    }
+    a
+}
+
 #[inline(always)]
 #[allow(unused_assignments)]
 pub fn asm_shl24(mut a: Int24Raw, mut count: u8) -> Int24Raw {
@@ -441,4 +1709,71 @@ pub fn asm_ge24(a: Int24Raw, b: Int24Raw) -> bool {
     c == 0
 }
 
+/// Disable interrupts and return the previous SREG.
+///
+/// This is a compiler memory barrier.
+#[inline(always)]
+pub fn asm_irq_disable() -> u8 {
+    let sreg: u8;
+    // SAFETY: Disabling interrupts is always safe.
+    unsafe {
+        asm!(
+            "   in {s}, __SREG__",
+            "   cli",
+
+            s = out(reg) sreg,
+
+            options(nostack),
+        );
+    }
+    sreg
+}
+
+/// Restore the SREG that has been returned by [asm_irq_disable].
+///
+/// This re-enables interrupts, if they were enabled before.
+/// This is a compiler memory barrier.
+#[inline(always)]
+pub fn asm_irq_restore(sreg: u8) {
+    // SAFETY: The SREG is restored to the state before asm_irq_disable.
+    unsafe {
+        asm!(
+            "   out __SREG__, {s}",
+
+            s = in(reg) sreg,
+
+            options(nostack),
+        );
+    }
+}
+
+/// Read 3 bytes from program memory at `ptr`.
+///
+/// # Safety
+///
+/// `ptr` must point to 3 bytes in the lower 64 KiB of program memory.
+#[inline(always)]
+pub unsafe fn asm_lpm24(ptr: *const Int24Raw) -> Int24Raw {
+    let b0: u8;
+    let b1: u8;
+    let b2: u8;
+    // SAFETY: The caller guarantees that `ptr` points to program memory.
+    unsafe {
+        asm!(
+            "   lpm {b0}, Z+",
+            "   lpm {b1}, Z+",
+            "   lpm {b2}, Z",
+
+            b0 = out(reg) b0,
+            b1 = out(reg) b1,
+            b2 = out(reg) b2,
+
+            inout("Z") ptr => _,
+
+            options(pure, readonly, nostack),
+        );
+    }
+    (b0, b1, b2)
+}
+
 // vim: ts=4 sw=4 expandtab
@@ -28,6 +28,48 @@ pub fn asm_mulsat24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
     }
 }
 
+pub fn asm_mul24_lo(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    from_i32(to_i32(a).wrapping_mul(to_i32(b)))
+}
+
+pub fn asm_mul24_wide(a: Int24Raw, b: Int24Raw) -> (Int24Raw, Int24Raw) {
+    let c = (to_i32(a) as i64 * to_i32(b) as i64).to_le_bytes();
+    ((c[0], c[1], c[2]), (c[3], c[4], c[5]))
+}
+
+pub fn asm_mul24x16_shr8(a: Int24Raw, b: i16) -> i32 {
+    ((to_i32(a) as i64 * b as i64) >> 8) as i32
+}
+
+pub fn asm_mul24x16(a: Int24Raw, b: i16) -> [u8; 5] {
+    let p = (to_i32(a) as i64 * b as i64).to_le_bytes();
+    [p[0], p[1], p[2], p[3], p[4]]
+}
+
+pub fn asm_mul24x8u(a: Int24Raw, b: u8) -> [u8; 4] {
+    (to_i32(a) * b as i32).to_le_bytes()
+}
+
+pub fn asm_div24x8u(a: Int24Raw, b: u8) -> Int24Raw {
+    from_i32(to_i32(a) / b as i32)
+}
+
+pub fn asm_sum24(acc: i64, data: &[Int24Raw]) -> i64 {
+    let mut acc = acc;
+    for v in data.iter().take(0xFFFF) {
+        acc = acc.wrapping_add(to_i32(*v) as i64);
+    }
+    (acc << 16) >> 16
+}
+
+pub fn asm_mac24(acc: i64, a: &[Int24Raw], b: &[Int24Raw]) -> i64 {
+    let mut acc = acc;
+    for (a, b) in a.iter().zip(b.iter()).take(255) {
+        acc = acc.wrapping_add(to_i32(*a) as i64 * to_i32(*b) as i64);
+    }
+    (acc << 8) >> 8
+}
+
 pub fn asm_divsat24(a: Int24Raw, b: Int24Raw, a_shl8: bool) -> Int24Raw {
     if b == (0, 0, 0) {
         if a.2 & 0x80 == 0 {
@@ -43,7 +85,8 @@ pub fn asm_divsat24(a: Int24Raw, b: Int24Raw, a_shl8: bool) -> Int24Raw {
         if a_shl8 {
             a <<= 8;
         }
-        let c = a / b;
+        // Zero and MIN / -1 are handled above, so the division never fails.
+        let c = a.checked_div(b).unwrap_or(0);
         if c > 0x7F_FFFF {
             from_i32(0x7F_FFFF)
         } else if c < -0x80_0000 {
@@ -54,6 +97,12 @@ pub fn asm_divsat24(a: Int24Raw, b: Int24Raw, a_shl8: bool) -> Int24Raw {
     }
 }
 
+pub fn asm_isqrt24(a: Int24Raw) -> (u8, u8) {
+    let a = to_i32(a) as u32 & 0xFF_FFFF;
+    let q = a.isqrt() as u16;
+    (q as u8, (q >> 8) as u8)
+}
+
 pub fn asm_negsat24(a: Int24Raw) -> Int24Raw {
     let b = from_i32(to_i32(a).wrapping_neg());
     if a.2 & 0x80 != 0 && b.2 & 0x80 != 0 {
@@ -63,16 +112,90 @@ pub fn asm_negsat24(a: Int24Raw) -> Int24Raw {
     }
 }
 
+pub fn asm_addsat24_in_place(a: &mut Int24Raw, b: Int24Raw) {
+    *a = from_i32((to_i32(*a) + to_i32(b)).clamp(-0x80_0000, 0x7F_FFFF));
+}
+
+pub fn asm_subsat24_in_place(a: &mut Int24Raw, b: Int24Raw) {
+    *a = from_i32((to_i32(*a) - to_i32(b)).clamp(-0x80_0000, 0x7F_FFFF));
+}
+
+pub fn asm_addsat24_buffers(dst: &mut [[u8; 3]], src: &[[u8; 3]]) {
+    for (d, s) in dst.iter_mut().zip(src.iter()) {
+        let mut v = (d[0], d[1], d[2]);
+        asm_addsat24_in_place(&mut v, (s[0], s[1], s[2]));
+        *d = [v.0, v.1, v.2];
+    }
+}
+
+pub fn asm_subsat24_buffers(dst: &mut [[u8; 3]], src: &[[u8; 3]]) {
+    for (d, s) in dst.iter_mut().zip(src.iter()) {
+        let mut v = (d[0], d[1], d[2]);
+        asm_subsat24_in_place(&mut v, (s[0], s[1], s[2]));
+        *d = [v.0, v.1, v.2];
+    }
+}
+
+pub fn asm_negsat24_buffer(buf: &mut [[u8; 3]]) {
+    for d in buf.iter_mut() {
+        let v = asm_negsat24((d[0], d[1], d[2]));
+        *d = [v.0, v.1, v.2];
+    }
+}
+
+// The generic constant time operations only have the same results as the AVR implementations.
+// There is no guarantee about the execution time.
+
+pub fn asm_ct_addsat24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    from_i32((to_i32(a) + to_i32(b)).clamp(-0x80_0000, 0x7F_FFFF))
+}
+
+pub fn asm_ct_subsat24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    from_i32((to_i32(a) - to_i32(b)).clamp(-0x80_0000, 0x7F_FFFF))
+}
+
+pub fn asm_ct_negsat24(a: Int24Raw) -> Int24Raw {
+    asm_negsat24(a)
+}
+
+pub fn asm_ct_abssat24(a: Int24Raw) -> Int24Raw {
+    if a.2 & 0x80 == 0 { a } else { asm_negsat24(a) }
+}
+
+pub fn asm_ct_mulsat24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    asm_mulsat24(a, b)
+}
+
+pub fn asm_ct_divsat24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    asm_divsat24(a, b, false)
+}
+
 pub fn asm_shl24(a: Int24Raw, count: u8) -> Int24Raw {
-    from_i32(to_i32(a) << count)
+    // All bits are shifted out for counts of 24 and more.
+    from_i32(to_i32(a).checked_shl(count.into()).unwrap_or(0))
 }
 
 pub fn asm_shr24(a: Int24Raw, count: u8) -> Int24Raw {
-    from_i32(to_i32(a) >> count)
+    // Only the sign is left for counts of 24 and more.
+    from_i32(to_i32(a) >> count.min(31))
 }
 
 pub fn asm_ge24(a: Int24Raw, b: Int24Raw) -> bool {
     to_i32(a) >= to_i32(b)
 }
 
+pub fn asm_irq_disable() -> u8 {
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    0
+}
+
+pub fn asm_irq_restore(_sreg: u8) {
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
+pub unsafe fn asm_lpm24(ptr: *const Int24Raw) -> Int24Raw {
+    // SAFETY: The caller guarantees that `ptr` is valid.
+    unsafe { ptr.read() }
+}
+
 // vim: ts=4 sw=4 expandtab
@@ -0,0 +1,248 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{
+    Int24,
+    raw::{mul24_q8_8, mul24_q15},
+};
+
+/// `log2(1 + i / 16)` as Q0.16.
+const LOG2_TABLE: [u16; 16] = [
+    0, 5732, 11136, 16248, 21098, 25711, 30109, 34312, 38336, 42196, 45904, 49472, 52911, 56229,
+    59434, 62534,
+];
+
+/// `2^(i / 16) - 1` as Q0.16.
+const EXP2_TABLE: [u16; 16] = [
+    0, 2902, 5932, 9096, 12400, 15850, 19454, 23216, 27146, 31249, 35534, 40009, 44682, 49562,
+    54658, 59979,
+];
+
+/// `20 * log10(2)` as Q8.24.
+const DB_PER_LOG2: i64 = 101_008_905;
+
+/// `1 / (20 * log10(2))` as Q8.24.
+const LOG2_PER_DB: i64 = 2_786_635;
+
+/// Advance the xorshift32 pseudo random number generator `state`.
+fn xorshift32(state: &mut u32) -> u32 {
+    let mut x = *state;
+    if x == 0 {
+        x = 0x2545_F491;
+    }
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    *state = x;
+    x
+}
+
+/// Linearly interpolate the Q0.16 function `table` between the entries `i` and `i + 1`
+/// with the Q0.16 fraction `f`. The entry after the last one is 1.0.
+fn interp(table: &[u16; 16], i: usize, f: u32) -> u32 {
+    let t0 = table[i] as u32;
+    let t1 = table.get(i + 1).map_or(0x1_0000, |t| *t as u32);
+    t0 + (((t1 - t0) * f) >> 16)
+}
+
+impl Int24 {
+    /// Construct a new [Int24] from a 24 bit audio sample that is left justified in a 32 bit word.
+    ///
+    /// The sample is in the upper 24 bits. The lower 8 bits are ignored.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i2s_left_justified(0xFFFF_FE00), Int24::from_i16(-2));
+    /// ```
+    pub const fn from_i2s_left_justified(word: u32) -> Self {
+        let b = word.to_le_bytes();
+        Self::from_raw((b[1], b[2], b[3]))
+    }
+
+    /// Construct a new [Int24] from a 24 bit audio sample that is right justified in a 32 bit word.
+    ///
+    /// The sample is in the lower 24 bits. The upper 8 bits are ignored.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i2s_right_justified(0x00FF_FFFE), Int24::from_i16(-2));
+    /// ```
+    pub const fn from_i2s_right_justified(word: u32) -> Self {
+        Self::from_bits(word)
+    }
+
+    /// Convert this [Int24] to an audio sample that is left justified in a 32 bit word.
+    ///
+    /// The sample is placed in the upper 24 bits and the lower 8 bits are zero.
+    pub const fn to_i2s_left_justified(self) -> u32 {
+        u32::from_le_bytes([0, self.0.0, self.0.1, self.0.2])
+    }
+
+    /// Convert this [Int24] to an audio sample that is right justified in a 32 bit word.
+    ///
+    /// The sample is placed in the lower 24 bits and is sign extended into the upper 8 bits.
+    pub const fn to_i2s_right_justified(self) -> u32 {
+        self.to_i32() as u32
+    }
+
+    /// Multiply this sample by the Q8.8 fixed-point `gain` and saturate the result.
+    ///
+    /// A `gain` of `0x100` is 1.0 and `0x80` is 0.5.
+    /// The product is rounded towards negative infinity.
+    ///
+    /// This multiplication of 24 by 16 bits is considerably faster than a full 24 bit multiplication.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let s = Int24::from_i16(1000);
+    /// assert_eq!(s.apply_gain(0x80), Int24::from_i16(500));
+    /// assert_eq!(s.apply_gain(-0x200), Int24::from_i16(-2000));
+    /// ```
+    #[inline(never)]
+    pub fn apply_gain(self, gain_q8_8: i16) -> Self {
+        Self::from_raw(mul24_q8_8(self.0, gain_q8_8))
+    }
+
+    /// Construct a new Q0.23 sample from the Q15 fixed-point value `v`.
+    ///
+    /// This is exact. The lower 8 bits of the sample are zero.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_q15(0x4000), Int24::from_i32(0x40_0000)); // 0.5
+    /// assert_eq!(Int24::from_q15(i16::MIN), Int24::MIN); // -1.0
+    /// ```
+    pub const fn from_q15(v: i16) -> Self {
+        let b = v.to_le_bytes();
+        Self::from_raw((0, b[0], b[1]))
+    }
+
+    /// Convert this Q0.23 sample to the Q15 fixed-point format.
+    ///
+    /// The sample is rounded to the nearest Q15 value and saturated.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i32(0x12_3480).to_q15_sat(), 0x1235);
+    /// assert_eq!(Int24::MAX.to_q15_sat(), i16::MAX);
+    /// ```
+    pub const fn to_q15_sat(self) -> i16 {
+        let v = (self.to_i32() + 0x80) >> 8;
+        if v > i16::MAX as i32 {
+            i16::MAX
+        } else {
+            v as i16
+        }
+    }
+
+    /// Multiply this sample by the Q15 fixed-point coefficient `coeff` and saturate the result.
+    ///
+    /// A `coeff` of `0x4000` is 0.5 and `-0x8000` is -1.0.
+    /// The product is rounded towards negative infinity.
+    ///
+    /// This allows using published Q15 coefficient sets, e.g. of filter designs, with 24 bit samples.
+    /// Like [Int24::apply_gain] this is a multiplication of 24 by 16 bits.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let s = Int24::from_i32(0x40_0000);
+    /// assert_eq!(s.mul_q15(0x4000), Int24::from_i32(0x20_0000));
+    /// assert_eq!(Int24::MIN.mul_q15(i16::MIN), Int24::MAX); // saturated
+    /// ```
+    #[inline(never)]
+    pub fn mul_q15(self, coeff: i16) -> Self {
+        Self::from_raw(mul24_q15(self.0, coeff))
+    }
+
+    /// Reduce this 24 bit sample to a 16 bit sample with TPDF dither and rounding.
+    ///
+    /// Triangular probability density function dither with an amplitude of ±1 16 bit LSB
+    /// is added before the sample is rounded to the nearest 16 bit value and saturated.
+    /// This decorrelates the quantization error from the signal.
+    ///
+    /// `rng_state` is the state of the xorshift32 pseudo random number generator.
+    /// It can be initialized to any value. A state of zero is replaced by a fixed seed.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let mut rng = 1;
+    /// let s = Int24::from_i32(0x12_3480).to_i16_dithered(&mut rng);
+    /// assert!(s == 0x1234 || s == 0x1235);
+    /// ```
+    #[inline(never)]
+    pub fn to_i16_dithered(self, rng_state: &mut u32) -> i16 {
+        let r = xorshift32(rng_state);
+        let dither = (r & 0xFF) as i32 - ((r >> 8) & 0xFF) as i32;
+        let v = (self.to_i32() + dither + 0x80) >> 8;
+        v.clamp(i16::MIN as i32, i16::MAX as i32) as i16
+    }
+
+    /// Get the level of the magnitude of this sample in dB relative to full scale (dBFS) as Q8.8.
+    ///
+    /// Full scale `2^23` is 0 dB. So the result is never positive.
+    /// Levels below -128 dB and zero saturate to `i16::MIN`.
+    ///
+    /// The logarithm is approximated with a table and linear interpolation.
+    /// The approximation error is below 0.01 dB.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::MIN.to_db_q8_8(), 0);
+    /// // Half scale is -6.02 dB.
+    /// assert_eq!(Int24::from_i32(0x40_0000).to_db_q8_8(), -1541);
+    /// ```
+    #[inline(never)]
+    pub fn to_db_q8_8(self) -> i16 {
+        let x = self.to_i32().unsigned_abs();
+        if x == 0 {
+            return i16::MIN;
+        }
+        // log2(x / 2^23) as Q16.16.
+        let n = 31 - x.leading_zeros();
+        let m = x << (31 - n);
+        let frac = interp(&LOG2_TABLE, ((m >> 27) & 0xF) as usize, (m >> 11) & 0xFFFF);
+        let log2 = ((n as i64 - 23) << 16) + frac as i64;
+        let db = (log2 * DB_PER_LOG2 + (1 << 31)) >> 32;
+        db.max(i16::MIN as i64) as i16
+    }
+
+    /// Construct a new [Int24] magnitude from a level in dB relative to full scale (dBFS) as Q8.8.
+    ///
+    /// This is the inverse of [Int24::to_db_q8_8].
+    /// Levels of 0 dB and above saturate to [Int24::MAX].
+    ///
+    /// The exponential function is approximated with a table and linear interpolation.
+    /// The relative approximation error is below 0.1 percent.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// // -20 dB is a tenth of full scale.
+    /// let x = Int24::from_db_q8_8(-20 * 256);
+    /// assert!((x.to_i32() - 0x0C_CCCD).abs() < 0x400);
+    /// ```
+    #[inline(never)]
+    pub fn from_db_q8_8(db_q8_8: i16) -> Self {
+        if db_q8_8 >= 0 {
+            return Self::MAX;
+        }
+        // log2(x) as Q16.16.
+        let log2 = ((db_q8_8 as i64 * LOG2_PER_DB + (1 << 15)) >> 16) + (23 << 16);
+        let e = (log2 >> 16) as u32;
+        let f = (log2 & 0xFFFF) as u32;
+        let mant = 0x1_0000 + interp(&EXP2_TABLE, (f >> 12) as usize, (f & 0xFFF) << 4);
+        let x = ((mant as u64) << e) + 0x8000;
+        Self::from_i32((x >> 16) as i32)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
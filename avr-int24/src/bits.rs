@@ -0,0 +1,235 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::Int24;
+
+/// XOR every bit of `x` with all bits above it.
+const fn prefix_xor8(mut x: u8) -> u8 {
+    x ^= x >> 1;
+    x ^= x >> 2;
+    x ^= x >> 4;
+    x
+}
+
+/// Get 0xFF, if bit 0 of `x` is set, else 0.
+const fn lsb_mask(x: u8) -> u8 {
+    0_u8.wrapping_sub(x & 1)
+}
+
+impl Int24 {
+    /// Construct a new [Int24] from the 24 bit two's complement pattern in the low bits of `bits`.
+    ///
+    /// The bits above bit 23 are ignored. This never saturates.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_bits(0xFF_FFFE), Int24::from_i16(-2));
+    /// assert_eq!(Int24::from_bits(0x0100_0005), Int24::from_i16(5));
+    /// ```
+    pub const fn from_bits(bits: u32) -> Self {
+        let b = bits.to_le_bytes();
+        Self::from_raw((b[0], b[1], b[2]))
+    }
+
+    /// Get the 24 bit two's complement pattern of this [Int24] in the low bits.
+    ///
+    /// The bits above bit 23 are zero.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i16(-2).to_bits(), 0xFF_FFFE);
+    /// ```
+    pub const fn to_bits(self) -> u32 {
+        u32::from_le_bytes([self.0.0, self.0.1, self.0.2, 0])
+    }
+
+    /// Convert the 24 bit pattern of this [Int24] to reflected binary Gray code.
+    ///
+    /// The conversion operates on the bit pattern as returned by [Int24::to_bits].
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_bits(0b1011).to_gray(), Int24::from_bits(0b1110));
+    /// ```
+    pub const fn to_gray(self) -> Self {
+        let (b0, b1, b2) = self.0;
+        Self::from_raw((
+            b0 ^ ((b0 >> 1) | (b1 << 7)),
+            b1 ^ ((b1 >> 1) | (b2 << 7)),
+            b2 ^ (b2 >> 1),
+        ))
+    }
+
+    /// Convert this 24 bit reflected binary Gray code to the binary bit pattern.
+    ///
+    /// This is the inverse of [Int24::to_gray].
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// // 24 bit absolute encoder position.
+    /// let pos = Int24::from_bits(0xA4_0000).from_gray().to_bits();
+    /// assert_eq!(pos, 0xC7_FFFF);
+    /// ```
+    pub const fn from_gray(self) -> Self {
+        let (g0, g1, g2) = self.0;
+        let b2 = prefix_xor8(g2);
+        let b1 = prefix_xor8(g1) ^ lsb_mask(b2);
+        let b0 = prefix_xor8(g0) ^ lsb_mask(b1);
+        Self::from_raw((b0, b1, b2))
+    }
+
+    /// Convert the magnitude of this [Int24] to packed BCD.
+    ///
+    /// Returns `true`, if the value is negative, and the 7 BCD digits of the magnitude.
+    /// The least significant digit is in the low nibble.
+    /// The conversion uses the shift and add 3 algorithm, so that no division is needed.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i32(-1234567).to_bcd(), (true, 0x0123_4567));
+    /// assert_eq!(Int24::MIN.to_bcd(), (true, 0x0838_8608));
+    /// ```
+    pub const fn to_bcd(self) -> (bool, u32) {
+        let v = self.to_i32();
+        let mut mag = v.unsigned_abs() << 8;
+        let mut bcd = 0_u32;
+        let mut i = 0;
+        while i < 24 {
+            // Add 3 to all digits that are bigger than 4, then shift in the next bit.
+            let adj = (bcd + 0x3333_3333) & 0x8888_8888;
+            bcd += (adj >> 2) | (adj >> 3);
+            bcd = (bcd << 1) | (mag >> 31);
+            mag <<= 1;
+            i += 1;
+        }
+        (v < 0, bcd)
+    }
+
+    /// Construct a new [Int24] from the 8 packed BCD digits `bcd` and the sign `negative`.
+    ///
+    /// The least significant digit is in the low nibble.
+    /// Values outside of the [Int24] range are saturated.
+    ///
+    /// Returns `None`, if a digit is not a decimal digit.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_bcd(true, 0x0123_4567), Some(Int24::from_i32(-1234567)));
+    /// assert_eq!(Int24::from_bcd(false, 0x0000_00A0), None);
+    /// ```
+    pub const fn from_bcd(negative: bool, bcd: u32) -> Option<Self> {
+        let mut v = 0_i32;
+        let mut i = 0;
+        while i < 8 {
+            let digit = (bcd >> (28 - i * 4)) & 0xF;
+            if digit > 9 {
+                return None;
+            }
+            v = v * 10 + digit as i32;
+            i += 1;
+        }
+        Some(Self::from_i32(if negative { -v } else { v }))
+    }
+
+    /// Construct a new [Int24] from the `bits` wide two's complement field in the low bits of `value`.
+    ///
+    /// The field is sign extended from bit `bits - 1`. The bits of `value` above the field are ignored.
+    /// Fields that are wider than 24 bits are saturated.
+    /// A `bits` of 0 returns zero and a `bits` bigger than 32 is treated as 32.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// // 18 bit ADC result.
+    /// assert_eq!(Int24::from_bits_signed(0x3_FFFF, 18), Int24::from_i16(-1));
+    /// assert_eq!(Int24::from_bits_signed(0x1_FFFF, 18), Int24::from_i32(0x1_FFFF));
+    /// assert_eq!(Int24::from_bits_signed(0x2_0000, 18), Int24::from_i32(-0x2_0000));
+    /// ```
+    pub const fn from_bits_signed(value: u32, bits: u8) -> Self {
+        if bits == 0 {
+            return Self::zero();
+        }
+        let shift = 32 - if bits > 32 { 32 } else { bits } as u32;
+        Self::from_i32(((value << shift) as i32) >> shift)
+    }
+
+    /// Check if this [Int24] is a power of two.
+    ///
+    /// Zero and negative values are not powers of two.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert!(Int24::from_i16(1024).is_power_of_two());
+    /// assert!(!Int24::from_i16(1000).is_power_of_two());
+    /// assert!(!Int24::from_i16(-1024).is_power_of_two());
+    /// ```
+    pub const fn is_power_of_two(self) -> bool {
+        let v = self.to_i32();
+        v > 0 && (v as u32).is_power_of_two()
+    }
+
+    /// Get the smallest power of two that is greater than or equal to `self`.
+    ///
+    /// Values smaller than or equal to one return one.
+    /// Values above `2^22` saturate to [Int24::MAX], which is not a power of two.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i16(1000).next_power_of_two(), Int24::from_i16(1024));
+    /// assert_eq!(Int24::from_i16(-5).next_power_of_two(), Int24::from_i16(1));
+    /// ```
+    pub const fn next_power_of_two(self) -> Self {
+        match self.checked_next_power_of_two() {
+            Some(v) => v,
+            None => Self::MAX,
+        }
+    }
+
+    /// Get the smallest power of two that is greater than or equal to `self`.
+    ///
+    /// Values smaller than or equal to one return one.
+    /// Returns `None`, if the power of two doesn't fit into 24 bits.
+    pub const fn checked_next_power_of_two(self) -> Option<Self> {
+        let v = self.to_i32();
+        if v <= 1 {
+            Some(Self::from_i8(1))
+        } else if v > 1 << 22 {
+            None
+        } else {
+            Some(Self::from_i32((v as u32).next_power_of_two() as i32))
+        }
+    }
+
+    /// Get the base 2 logarithm of `self`, rounded down.
+    ///
+    /// For a power of two this is the shift count that is equivalent to
+    /// a multiplication or division by `self`.
+    /// Returns `None`, if `self` is zero or negative.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i16(1024).checked_ilog2(), Some(10));
+    /// assert_eq!(Int24::from_i16(1023).checked_ilog2(), Some(9));
+    /// assert_eq!(Int24::zero().checked_ilog2(), None);
+    /// ```
+    pub const fn checked_ilog2(self) -> Option<u8> {
+        let v = self.to_i32();
+        if v <= 0 {
+            None
+        } else {
+            Some((v as u32).ilog2() as u8)
+        }
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,119 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Error, Int24, Lut24};
+
+/// Length of one persisted breakpoint in bytes.
+const POINT_LEN: usize = 2 * crate::PERSIST_LEN;
+
+/// Piecewise linear calibration curve with `N` breakpoints.
+///
+/// The curve consists of `(raw, corrected)` breakpoints sorted by ascending raw value.
+/// Values between two breakpoints are linearly interpolated
+/// and values outside of the curve are clamped, just like [Lut24].
+/// Unlike [Lut24] the breakpoints are owned, so that they can be loaded at runtime,
+/// for example from EEPROM with [Calibration::read_from].
+///
+/// ```
+/// use avr_int24::{Calibration, Int24};
+///
+/// let i = Int24::from_i16;
+/// let cal = Calibration::new([(i(0), i(10)), (i(1000), i(990)), (i(2000), i(2010))]);
+/// assert_eq!(cal.apply(i(500)), i(500));
+/// assert_eq!(cal.apply(i(1500)), i(1500));
+/// assert_eq!(cal.apply(i(-5)), i(10));
+///
+/// let mut eeprom = [0xFF; Calibration::<3>::PERSIST_LEN];
+/// cal.write_to(&mut eeprom).unwrap();
+/// assert_eq!(Calibration::<3>::read_from(&eeprom), Ok(cal));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Calibration<const N: usize> {
+    points: [(Int24, Int24); N],
+}
+
+impl<const N: usize> Calibration<N> {
+    /// Length of the persisted curve in bytes.
+    ///
+    /// Each raw and corrected value is a checksummed record of [crate::PERSIST_LEN] bytes.
+    pub const PERSIST_LEN: usize = N * POINT_LEN;
+
+    /// Construct a new calibration curve from `(raw, corrected)` breakpoints.
+    ///
+    /// The breakpoints must be sorted by ascending raw value.
+    pub const fn new(points: [(Int24, Int24); N]) -> Self {
+        Self { points }
+    }
+
+    /// Get the breakpoints.
+    pub const fn points(&self) -> &[(Int24, Int24); N] {
+        &self.points
+    }
+
+    /// Get a [Lut24] view of the breakpoints.
+    pub const fn lut(&self) -> Lut24<'_> {
+        Lut24::new(&self.points)
+    }
+
+    /// Check if the breakpoints are sorted by ascending raw value.
+    pub fn is_sorted(&self) -> bool {
+        self.points.windows(2).all(|w| w[0].0 <= w[1].0)
+    }
+
+    /// Apply the calibration curve to the raw value `x`.
+    ///
+    /// A curve without breakpoints always returns zero.
+    pub fn apply(&self, x: Int24) -> Int24 {
+        self.lut().lookup(x)
+    }
+
+    /// Write the breakpoints as checksummed records to the start of `buf`.
+    ///
+    /// The curve is [Calibration::PERSIST_LEN] bytes long.
+    ///
+    /// Returns [Error::SliceTooShort] and writes nothing, if `buf` is too short.
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<(), Error> {
+        let len = buf.len();
+        let Some(buf) = buf.get_mut(..Self::PERSIST_LEN) else {
+            return Err(Error::SliceTooShort {
+                needed: Self::PERSIST_LEN,
+                len,
+            });
+        };
+        let recs = buf.as_chunks_mut::<POINT_LEN>().0;
+        for (rec, &(raw, corrected)) in recs.iter_mut().zip(self.points.iter()) {
+            let (r, c) = rec.split_at_mut(crate::PERSIST_LEN);
+            raw.write_to(r)?;
+            corrected.write_to(c)?;
+        }
+        Ok(())
+    }
+
+    /// Read a calibration curve that has been written by [Calibration::write_to].
+    ///
+    /// Returns [Error::SliceTooShort], if `buf` is too short,
+    /// [Error::Checksum], if any checksum doesn't match,
+    /// and [Error::Unsorted], if the breakpoints are not sorted.
+    pub fn read_from(buf: &[u8]) -> Result<Self, Error> {
+        let Some(buf) = buf.get(..Self::PERSIST_LEN) else {
+            return Err(Error::SliceTooShort {
+                needed: Self::PERSIST_LEN,
+                len: buf.len(),
+            });
+        };
+        let mut points = [(Int24::zero(), Int24::zero()); N];
+        for (rec, point) in buf.as_chunks::<POINT_LEN>().0.iter().zip(points.iter_mut()) {
+            let (r, c) = rec.split_at(crate::PERSIST_LEN);
+            *point = (Int24::read_from(r)?, Int24::read_from(c)?);
+        }
+        let cal = Self::new(points);
+        if cal.is_sorted() {
+            Ok(cal)
+        } else {
+            Err(Error::Unsorted)
+        }
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,113 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, raw::with_irq_disabled};
+use core::cell::UnsafeCell;
+
+/// Interrupt safe cell for sharing an [Int24] between an interrupt service routine and the main loop.
+///
+/// The 3 bytes of an [Int24] can't be accessed atomically on AVR.
+/// All accesses to the cell are done in short critical sections with interrupts disabled,
+/// so that no access can observe a partially written value.
+/// The previous interrupt enable state is restored after each access.
+///
+/// On AVR the cell can be placed in a `static`.
+///
+/// ```
+/// use avr_int24::{Int24, Int24Cell};
+///
+/// // On AVR this would be a: static COUNTER: Int24Cell
+/// let counter = Int24Cell::new(Int24::zero());
+///
+/// // In the interrupt service routine:
+/// counter.fetch_add(Int24::from_i16(1));
+///
+/// // In the main loop:
+/// let count = counter.swap(Int24::zero());
+/// assert_eq!(count, Int24::from_i16(1));
+/// ```
+pub struct Int24Cell {
+    value: UnsafeCell<Int24>,
+}
+
+// SAFETY: AVR is single core and all accesses to the value are done with interrupts disabled.
+#[cfg(target_arch = "avr")]
+unsafe impl Sync for Int24Cell {}
+
+impl Int24Cell {
+    /// Construct a new cell containing `value`.
+    pub const fn new(value: Int24) -> Self {
+        Self {
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Get the contained value.
+    pub fn load(&self) -> Int24 {
+        // SAFETY: The value is only accessed with interrupts disabled.
+        with_irq_disabled(|| unsafe { self.value.get().read() })
+    }
+
+    /// Set the contained value to `value`.
+    pub fn store(&self, value: Int24) {
+        // SAFETY: The value is only accessed with interrupts disabled.
+        with_irq_disabled(|| unsafe { self.value.get().write(value) });
+    }
+
+    /// Set the contained value to `value` and return the previous value.
+    pub fn swap(&self, value: Int24) -> Int24 {
+        self.update(|_| value)
+    }
+
+    /// Add `value` to the contained value with saturation and return the previous value.
+    pub fn fetch_add(&self, value: Int24) -> Int24 {
+        self.update(|v| v + value)
+    }
+
+    /// Subtract `value` from the contained value with saturation and return the previous value.
+    pub fn fetch_sub(&self, value: Int24) -> Int24 {
+        self.update(|v| v - value)
+    }
+
+    /// Replace the contained value `v` by `f(v)` and return the previous value.
+    ///
+    /// `f` runs with interrupts disabled and should be short.
+    pub fn update<F: FnOnce(Int24) -> Int24>(&self, f: F) -> Int24 {
+        with_irq_disabled(|| {
+            let p = self.value.get();
+            // SAFETY: The value is only accessed with interrupts disabled.
+            //         No reference to the value is held while `f` runs.
+            let prev = unsafe { p.read() };
+            let next = f(prev);
+            unsafe { p.write(next) };
+            prev
+        })
+    }
+
+    /// Get a mutable reference to the contained value.
+    ///
+    /// No critical section is needed, because the cell is borrowed mutably.
+    pub fn get_mut(&mut self) -> &mut Int24 {
+        self.value.get_mut()
+    }
+
+    /// Consume the cell and return the contained value.
+    pub fn into_inner(self) -> Int24 {
+        self.value.into_inner()
+    }
+}
+
+impl Default for Int24Cell {
+    fn default() -> Self {
+        Self::new(Int24::zero())
+    }
+}
+
+impl core::fmt::Debug for Int24Cell {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Int24Cell").field(&self.load()).finish()
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,398 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! On-target self-test of the register usage of the assembly routines.
+//!
+//! An assembly routine that modifies a register without declaring it
+//! silently corrupts the surrounding code.
+//! This test calls each assembly routine with sentinel values in the surrounding registers
+//! and verifies that only the declared operands have been modified.
+//!
+//! Each routine is called through a wrapper function with the AVR calling convention.
+//! Before the call, the call-saved registers r2 to r17, r28 and r29 are filled with sentinels.
+//! After the call, the sentinels, the zero register r1, the stack pointer
+//! and the interrupt flag SREG.I must be unchanged.
+//! Inside the wrapper, additional sentinel values are kept alive across the routine,
+//! so that the compiler places them in the registers that the routine doesn't use,
+//! including the call-clobbered registers.
+//!
+//! This module is only available on AVR with the `clobber-test` feature.
+//!
+//! ```no_run
+//! let ok = avr_int24::clobber::check_all(|name, clobbered| {
+//!     if !clobbered.is_empty() {
+//!         // Report `name` and `clobbered`.
+//!     }
+//! });
+//! ```
+
+use crate::{
+    Int24, int24_progmem,
+    raw::{
+        Int24Raw, abs24, add24_buffers, add24_in_place, ct_abs24, ct_add24, ct_div24, ct_mul24,
+        ct_neg24, ct_sub24, div24, div24_u8, ge24, isqrt24, mac24, mul24, mul24_i16, mul24_lo,
+        mul24_q8_8, mul24_u8, mul24_wide, neg24, neg24_buffer, shl24, shl24_by8_div24, shr24,
+        sub24_buffers, sub24_in_place, sum24, with_irq_disabled,
+    },
+};
+use core::{arch::asm, hint::black_box};
+
+/// Registers and state that have been modified without being declared.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Clobbered {
+    /// Bit mask of the modified call-saved registers.
+    ///
+    /// Bit `n` is register `r<n>`. Bit 1 is the zero register,
+    /// which has not been restored to zero.
+    pub regs: u32,
+
+    /// Bit mask of the modified sentinel values, which were alive in unknown registers.
+    pub live: u16,
+
+    /// The stack pointer has been modified.
+    pub stack: bool,
+
+    /// The interrupt flag SREG.I has been modified.
+    pub irq_flag: bool,
+}
+
+impl Clobbered {
+    /// Returns `true`, if nothing has been modified.
+    pub const fn is_empty(&self) -> bool {
+        self.regs == 0 && self.live == 0 && !self.stack && !self.irq_flag
+    }
+}
+
+/// The registers r1 to r17, r28 and r29 in the order they are stored in [Frame].
+const SAVED_REGS: [u8; 19] = [
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 28, 29,
+];
+
+/// Sentinel of the call-saved register `r<reg>`.
+///
+/// This must match the expression in [call].
+const fn sentinel(reg: u8) -> u8 {
+    0x5A ^ reg.wrapping_mul(0x25)
+}
+
+/// The state before and after the call, as stored by [call].
+#[repr(C)]
+#[derive(Default)]
+struct Frame {
+    sreg_before: u8,
+    sp_before: [u8; 2],
+    sreg_after: u8,
+    sp_after: [u8; 2],
+    regs: [u8; 19],
+}
+
+impl Frame {
+    fn clobbered(&self) -> Clobbered {
+        let mut regs = 0;
+        for (reg, value) in SAVED_REGS.iter().zip(self.regs) {
+            let expected = if *reg == 1 { 0 } else { sentinel(*reg) };
+            if value != expected {
+                regs |= 1 << reg;
+            }
+        }
+        Clobbered {
+            regs,
+            live: 0,
+            stack: self.sp_before != self.sp_after,
+            irq_flag: (self.sreg_before ^ self.sreg_after) & 0x80 != 0,
+        }
+    }
+}
+
+/// Operands and results of a wrapped routine.
+#[repr(C)]
+struct Ctx {
+    a: Int24Raw,
+    b: Int24Raw,
+    live: u16,
+}
+
+/// Wrapper of an assembly routine.
+type Op = extern "C" fn(&mut Ctx);
+
+/// Call `op` with sentinels in the call-saved registers and record the state in `frame`.
+#[inline(never)]
+fn call(op: Op, ctx: &mut Ctx, frame: &mut Frame) {
+    // SAFETY: All modified registers are saved and restored or declared.
+    unsafe {
+        asm!(
+            // Save the call-saved registers.
+            ".irp reg, 2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,28,29",
+            "   push r\\reg",
+            ".endr",
+
+            // Record the state before the call.
+            "   in r18, __SREG__",
+            "   st X+, r18",
+            "   in r18, __SP_L__",
+            "   st X+, r18",
+            "   in r18, __SP_H__",
+            "   st X+, r18",
+            "   push r26",
+            "   push r27",
+
+            // Fill the call-saved registers with sentinels.
+            ".irp reg, 2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,28,29",
+            "   ldi r18, (0x5A ^ (\\reg * 0x25)) & 0xFF",
+            "   mov r\\reg, r18",
+            ".endr",
+
+            "   icall",
+
+            // Record the state after the call.
+            "   in r18, __SREG__",
+            "   pop r27",
+            "   pop r26",
+            "   st X+, r18",
+            "   in r18, __SP_L__",
+            "   st X+, r18",
+            "   in r18, __SP_H__",
+            "   st X+, r18",
+            "   st X+, r1",
+            ".irp reg, 2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,28,29",
+            "   st X+, r\\reg",
+            ".endr",
+
+            // Restore the zero register and the call-saved registers.
+            "   clr r1",
+            ".irp reg, 29,28,17,16,15,14,13,12,11,10,9,8,7,6,5,4,3,2",
+            "   pop r\\reg",
+            ".endr",
+
+            inout("X") frame as *mut Frame => _,
+            inout("Z") op => _,
+            inout("r25r24") ctx as *mut Ctx => _,
+            out("r18") _,
+            out("r19") _,
+            out("r20") _,
+            out("r21") _,
+            out("r22") _,
+            out("r23") _,
+        );
+    }
+}
+
+/// Number of the sentinel values that are kept alive across the routine.
+const LIVE: usize = 10;
+
+/// Sentinel of the live value `i`.
+///
+/// This must match the sequence in [live_load].
+const fn live_sentinel(i: usize) -> u8 {
+    0xC3_u8.wrapping_sub((i as u8).wrapping_mul(0x35))
+}
+
+/// Load the live sentinel values into registers chosen by the compiler.
+///
+/// This is ordered before the routine, because it is a compiler memory barrier
+/// and the operands are loaded from memory.
+#[inline(always)]
+fn live_load() -> [u8; LIVE] {
+    let v: [u8; LIVE];
+    // SAFETY: Only the declared outputs are modified.
+    unsafe {
+        let (v0, v1, v2, v3, v4, v5, v6, v7, v8, v9);
+        asm!(
+            "   ldi {t}, 0xC3",
+            "   mov {v0}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v1}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v2}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v3}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v4}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v5}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v6}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v7}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v8}, {t}",
+            "   subi {t}, 0x35",
+            "   mov {v9}, {t}",
+
+            t = out(reg_upper) _,
+            v0 = out(reg) v0,
+            v1 = out(reg) v1,
+            v2 = out(reg) v2,
+            v3 = out(reg) v3,
+            v4 = out(reg) v4,
+            v5 = out(reg) v5,
+            v6 = out(reg) v6,
+            v7 = out(reg) v7,
+            v8 = out(reg) v8,
+            v9 = out(reg) v9,
+
+            options(nostack),
+        );
+        v = [v0, v1, v2, v3, v4, v5, v6, v7, v8, v9];
+    }
+    v
+}
+
+/// Get the bit mask of the modified live sentinel values.
+///
+/// This is ordered after the routine, because it is a compiler memory barrier
+/// and the result of the routine has been passed to `black_box` before.
+#[inline(always)]
+fn live_check(mut v: [u8; LIVE]) -> u16 {
+    // SAFETY: The asm block only contains a comment.
+    unsafe {
+        let [v0, v1, v2, v3, v4, v5, v6, v7, v8, v9] = &mut v;
+        asm!(
+            "; live values {0} {1} {2} {3} {4} {5} {6} {7} {8} {9}",
+            inout(reg) * v0,
+            inout(reg) * v1,
+            inout(reg) * v2,
+            inout(reg) * v3,
+            inout(reg) * v4,
+            inout(reg) * v5,
+            inout(reg) * v6,
+            inout(reg) * v7,
+            inout(reg) * v8,
+            inout(reg) * v9,
+            options(nostack),
+        );
+    }
+    live_mask(&v)
+}
+
+/// Compare the live values `v` to their sentinels.
+///
+/// This is not inlined to keep the wrappers small.
+#[inline(never)]
+fn live_mask(v: &[u8; LIVE]) -> u16 {
+    let mut mask = 0;
+    for (i, value) in v.iter().enumerate() {
+        if *value != live_sentinel(i) {
+            mask |= 1 << i;
+        }
+    }
+    mask
+}
+
+/// Generate the table of the wrapped routines.
+macro_rules! ops {
+    ($($name:literal => |$a:ident, $b:ident| $body:expr;)*) => {
+        [$(
+            ($name, {
+                extern "C" fn op(ctx: &mut Ctx) {
+                    let live = live_load();
+                    let ($a, $b) = (ctx.a, ctx.b);
+                    black_box($body);
+                    ctx.live |= live_check(live);
+                }
+                op as Op
+            }),
+        )*]
+    };
+}
+
+int24_progmem! {
+    static TABLE: [Int24; 2] = [Int24::from_i32(0x12_3456), Int24::MIN];
+}
+
+/// The wrapped routines.
+const OPS: [(&str, Op); 29] = ops! {
+    "mulsat24" => |a, b| mul24(a, b);
+    "mul24_lo" => |a, b| mul24_lo(a, b);
+    "mul24_wide" => |a, b| mul24_wide(a, b);
+    "mul24x16_shr8" => |a, b| mul24_q8_8(a, i16::from_le_bytes([b.0, b.1]));
+    "mul24x16" => |a, b| mul24_i16(a, i16::from_le_bytes([b.0, b.1]));
+    "mul24x8u" => |a, b| mul24_u8(a, b.0);
+    "div24x8u" => |a, b| div24_u8(a, b.0);
+    "sum24" => |a, b| sum24(0, &[a, b, a]);
+    "mac24" => |a, b| mac24(0, &[a, b], &[b, a]);
+    "divsat24" => |a, b| div24(a, b);
+    "divsat24_shl8" => |a, b| shl24_by8_div24(a, b);
+    "isqrt24" => |a, _b| isqrt24(abs24(a));
+    "negsat24" => |a, _b| neg24(a);
+    "addsat24_in_place" => |a, b| {
+        let mut a = a;
+        add24_in_place(&mut a, b);
+        a
+    };
+    "subsat24_in_place" => |a, b| {
+        let mut a = a;
+        sub24_in_place(&mut a, b);
+        a
+    };
+    "addsat24_buffers" => |a, b| {
+        let mut dst = [[a.0, a.1, a.2]; 2];
+        add24_buffers(&mut dst, &[[b.0, b.1, b.2]; 2]);
+        dst
+    };
+    "subsat24_buffers" => |a, b| {
+        let mut dst = [[a.0, a.1, a.2]; 2];
+        sub24_buffers(&mut dst, &[[b.0, b.1, b.2]; 2]);
+        dst
+    };
+    "negsat24_buffer" => |a, _b| {
+        let mut buf = [[a.0, a.1, a.2]; 2];
+        neg24_buffer(&mut buf);
+        buf
+    };
+    "ct_addsat24" => |a, b| ct_add24(a, b);
+    "ct_subsat24" => |a, b| ct_sub24(a, b);
+    "ct_negsat24" => |a, _b| ct_neg24(a);
+    "ct_abssat24" => |a, _b| ct_abs24(a);
+    "ct_mulsat24" => |a, b| ct_mul24(a, b);
+    "ct_divsat24" => |a, b| ct_div24(a, b);
+    "shl24" => |a, b| shl24(a, b.0 & 0x1F);
+    "shr24" => |a, b| shr24(a, b.0 & 0x1F);
+    "ge24" => |a, b| ge24(a, b);
+    "irq_disable_restore" => |a, b| with_irq_disabled(|| mul24_lo(a, b));
+    "lpm24" => |_a, b| TABLE.get(usize::from(b.0 & 1));
+};
+
+/// Operands that exercise the normal and the saturating paths of the routines.
+const OPERANDS: [(Int24, Int24); 6] = [
+    (Int24::from_i32(0x12_3456), Int24::from_i32(-0x65)),
+    (Int24::from_i32(-0x65), Int24::from_i32(0x12_3456)),
+    (Int24::MAX, Int24::MAX),
+    (Int24::MIN, Int24::from_i32(-1)),
+    (Int24::from_i32(-0x7F_FFFF), Int24::zero()),
+    (Int24::from_i32(0x1000), Int24::from_i32(0x40)),
+];
+
+/// Check all assembly routines for undeclared modifications of registers.
+///
+/// Each routine is called with several operands.
+/// `report` is called once for each routine with its name
+/// and the combined modifications of all calls.
+///
+/// Returns `true`, if no routine modified anything undeclared.
+pub fn check_all(mut report: impl FnMut(&'static str, Clobbered)) -> bool {
+    let mut ok = true;
+    for (name, op) in OPS {
+        let mut clobbered = Clobbered::default();
+        for (a, b) in OPERANDS {
+            let mut ctx = Ctx {
+                a: a.0,
+                b: b.0,
+                live: 0,
+            };
+            let mut frame = Frame::default();
+            call(op, &mut ctx, &mut frame);
+            let c = frame.clobbered();
+            clobbered.regs |= c.regs;
+            clobbered.live |= ctx.live;
+            clobbered.stack |= c.stack;
+            clobbered.irq_flag |= c.irq_flag;
+        }
+        ok &= clobbered.is_empty();
+        report(name, clobbered);
+    }
+    ok
+}
+
+// vim: ts=4 sw=4 expandtab
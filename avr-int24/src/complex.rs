@@ -0,0 +1,139 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, Int48};
+
+/// Complex number with [Int24] real and imaginary parts.
+///
+/// All operations saturate the parts to the [Int24] range.
+/// Products are calculated with 48 bit intermediate values.
+///
+/// ```
+/// use avr_int24::{Complex24, Int24};
+///
+/// let a = Complex24::from_i16(3, 4);
+/// let b = Complex24::from_i16(1, -2);
+/// assert_eq!(a * b, Complex24::from_i16(11, -2));
+/// assert_eq!(a.power(), avr_int24::Int48::from_i32(25));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Complex24 {
+    /// Real part.
+    pub re: Int24,
+    /// Imaginary part.
+    pub im: Int24,
+}
+
+impl Complex24 {
+    /// Construct a new complex number from the real and imaginary parts.
+    pub const fn new(re: Int24, im: Int24) -> Self {
+        Self { re, im }
+    }
+
+    /// Construct a new complex number from 16 bit real and imaginary parts.
+    pub const fn from_i16(re: i16, im: i16) -> Self {
+        Self::new(Int24::from_i16(re), Int24::from_i16(im))
+    }
+
+    /// Construct a new zero complex number.
+    pub const fn zero() -> Self {
+        Self::new(Int24::zero(), Int24::zero())
+    }
+
+    /// Get the complex conjugate with saturation.
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Multiply two complex numbers and arithmetically right shift the parts
+    /// of the 48 bit intermediate product by `count` bits.
+    ///
+    /// This is the multiplication of fixed-point complex numbers.
+    /// For Q0.23 parts, `count` is 23.
+    pub fn mul_shr(self, other: Self, count: u8) -> Self {
+        let re = Int48::mul_int24(self.re, other.re) - Int48::mul_int24(self.im, other.im);
+        let im = Int48::mul_int24(self.re, other.im) + Int48::mul_int24(self.im, other.re);
+        Self::new(re.to_int24_shr(count), im.to_int24_shr(count))
+    }
+
+    /// Get the squared magnitude `re² + im²`.
+    ///
+    /// This never saturates.
+    pub fn power(self) -> Int48 {
+        Int48::mul_int24(self.re, self.re) + Int48::mul_int24(self.im, self.im)
+    }
+
+    /// Get the approximate magnitude `sqrt(re² + im²)` without multiplication and square root.
+    ///
+    /// This uses the alpha max plus beta min algorithm
+    /// `max(max, 7/8 * max + 1/2 * min)` of the absolute values of the parts.
+    /// The approximation error is between -3% and +0.8%.
+    /// The result is saturated to [Int24::MAX].
+    ///
+    /// ```
+    /// use avr_int24::{Complex24, Int24};
+    ///
+    /// let m = Complex24::from_i16(3000, -4000).magnitude_approx().to_i32();
+    /// assert!((4850..=5040).contains(&m));
+    /// ```
+    pub fn magnitude_approx(self) -> Int24 {
+        let a = self.re.to_i32().unsigned_abs();
+        let b = self.im.to_i32().unsigned_abs();
+        let (max, min) = if a > b { (a, b) } else { (b, a) };
+        let m = max.max(max - (max >> 3) + (min >> 1));
+        Int24::from_i32(m as i32)
+    }
+}
+
+impl core::ops::Add for Complex24 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl core::ops::AddAssign for Complex24 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub for Complex24 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl core::ops::SubAssign for Complex24 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Mul for Complex24 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.mul_shr(other, 0)
+    }
+}
+
+impl core::ops::MulAssign for Complex24 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl core::ops::Neg for Complex24 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,144 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::Int24;
+
+/// Slew rate limiter.
+///
+/// Each update moves the output towards the target by at most `max_step`.
+/// The difference between the output and the target is calculated with
+/// full width, so the limiter behaves correctly over the whole [Int24] range.
+///
+/// ```
+/// use avr_int24::{Int24, SlewLimiter};
+///
+/// let mut slew = SlewLimiter::new(Int24::zero(), Int24::from_i16(10));
+/// assert_eq!(slew.update(Int24::from_i16(25)), Int24::from_i16(10));
+/// assert_eq!(slew.update(Int24::from_i16(25)), Int24::from_i16(20));
+/// assert_eq!(slew.update(Int24::from_i16(25)), Int24::from_i16(25));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SlewLimiter {
+    value: Int24,
+    max_step: Int24,
+}
+
+impl SlewLimiter {
+    /// Construct a new slew rate limiter with the output `value`.
+    ///
+    /// The absolute value of `max_step` is used.
+    pub fn new(value: Int24, max_step: Int24) -> Self {
+        Self {
+            value,
+            max_step: max_step.abs(),
+        }
+    }
+
+    /// Change the maximum step per update.
+    ///
+    /// The absolute value of `max_step` is used.
+    pub fn set_max_step(&mut self, max_step: Int24) {
+        self.max_step = max_step.abs();
+    }
+
+    /// Set the output to `value` without slew rate limiting.
+    pub fn reset(&mut self, value: Int24) {
+        self.value = value;
+    }
+
+    /// Get the current output.
+    pub fn value(&self) -> Int24 {
+        self.value
+    }
+
+    /// Move the output towards `target` and return the new output.
+    pub fn update(&mut self, target: Int24) -> Int24 {
+        let diff = target.to_i32() - self.value.to_i32();
+        let step = self.max_step.to_i32();
+        self.value = if diff > step {
+            self.value + self.max_step
+        } else if diff < -step {
+            self.value - self.max_step
+        } else {
+            target
+        };
+        self.value
+    }
+}
+
+/// Comparator with hysteresis.
+///
+/// The state switches to `true`, if the input reaches the upper threshold,
+/// and it switches to `false`, if the input reaches the lower threshold.
+/// Between the thresholds the state is kept.
+///
+/// ```
+/// use avr_int24::{Hysteresis, Int24};
+///
+/// let mut hyst = Hysteresis::new(Int24::from_i16(10), Int24::from_i16(20));
+/// assert!(!hyst.update(Int24::from_i16(15)));
+/// assert!(hyst.update(Int24::from_i16(20)));
+/// assert!(hyst.update(Int24::from_i16(15)));
+/// assert!(!hyst.update(Int24::from_i16(10)));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Hysteresis {
+    low: Int24,
+    high: Int24,
+    state: bool,
+}
+
+impl Hysteresis {
+    /// Construct a new comparator with the thresholds `low` and `high` and the state `false`.
+    ///
+    /// The thresholds are swapped, if `low` is bigger than `high`.
+    pub fn new(low: Int24, high: Int24) -> Self {
+        Self {
+            low: low.min(high),
+            high: low.max(high),
+            state: false,
+        }
+    }
+
+    /// Set the state without comparing.
+    pub fn reset(&mut self, state: bool) {
+        self.state = state;
+    }
+
+    /// Get the current state.
+    pub fn state(&self) -> bool {
+        self.state
+    }
+
+    /// Compare `x` to the thresholds and return the new state.
+    pub fn update(&mut self, x: Int24) -> bool {
+        if x >= self.high {
+            self.state = true;
+        } else if x <= self.low {
+            self.state = false;
+        }
+        self.state
+    }
+}
+
+impl Int24 {
+    /// Apply a deadband of `width` around zero.
+    ///
+    /// Values in the range `-width..=width` become zero.
+    /// All other values are moved towards zero by `width`,
+    /// so that the output is continuous.
+    /// The absolute value of `width` is used.
+    pub fn deadband(self, width: Self) -> Self {
+        let width = width.abs();
+        if self > width {
+            self - width
+        } else if self < -width {
+            self + width
+        } else {
+            Self::zero()
+        }
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
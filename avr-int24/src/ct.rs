@@ -0,0 +1,82 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{
+    Int24,
+    raw::{ct_abs24, ct_add24, ct_div24, ct_mul24, ct_neg24, ct_sub24},
+};
+
+/// Constant execution time operations.
+///
+/// The results are the same as the results of the regular saturating operations.
+/// On AVR the cycle count doesn't depend on the operand values.
+/// There are no early exits and the saturation is done without branches.
+/// That makes the execution time of control interrupt routines deterministic.
+///
+/// | Operation | AVR cycles |
+/// |-----------|------------|
+/// | `ct_add`  | 23         |
+/// | `ct_sub`  | 23         |
+/// | `ct_mul`  | 441        |
+/// | `ct_div`  | 558        |
+/// | `ct_neg`  | 12         |
+/// | `ct_abs`  | 15         |
+///
+/// The cycle counts don't include the function call overhead, which is constant as well.
+/// On non-AVR targets there is no guarantee about the execution time.
+impl Int24 {
+    /// Add and saturate two [Int24] in constant time.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::MAX.ct_add(Int24::from_i8(1));
+    /// assert_eq!(a, Int24::MAX);
+    /// ```
+    #[inline(never)]
+    pub fn ct_add(self, other: Self) -> Self {
+        Self::from_raw(ct_add24(self.0, other.0))
+    }
+
+    /// Subtract and saturate two [Int24] in constant time.
+    #[inline(never)]
+    pub fn ct_sub(self, other: Self) -> Self {
+        Self::from_raw(ct_sub24(self.0, other.0))
+    }
+
+    /// Multiply and saturate two [Int24] in constant time.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::from_i16(-300).ct_mul(Int24::from_i16(20));
+    /// assert_eq!(a, Int24::from_i16(-6000));
+    /// ```
+    #[inline(never)]
+    pub fn ct_mul(self, other: Self) -> Self {
+        Self::from_raw(ct_mul24(self.0, other.0))
+    }
+
+    /// Divide and saturate two [Int24] in constant time.
+    ///
+    /// The division by zero saturates like [Int24::div].
+    #[inline(never)]
+    pub fn ct_div(self, other: Self) -> Self {
+        Self::from_raw(ct_div24(self.0, other.0))
+    }
+
+    /// Two's complement negate and saturate `self` in constant time.
+    #[inline(never)]
+    pub fn ct_neg(self) -> Self {
+        Self::from_raw(ct_neg24(self.0))
+    }
+
+    /// Get the saturated absolute value of `self` in constant time.
+    #[inline(never)]
+    pub fn ct_abs(self) -> Self {
+        Self::from_raw(ct_abs24(self.0))
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,242 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{
+    Frac24, Int24, Int48,
+    raw::{conv::i24raw_to_i32, mul24_wide, raw_max, raw_min},
+};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum Kind {
+    Zero,
+    One,
+    MinusOne,
+    Min,
+    Magic,
+    MagicAdd,
+    MagicSub,
+}
+
+/// 48/17 in Q2.21. The constant of the initial reciprocal estimate.
+const RECIP_C0: i32 = 5_921_370;
+
+/// 32/17 in Q2.21. The slope of the initial reciprocal estimate.
+const RECIP_C1: i32 = 3_947_580;
+
+/// 2.0 in Q2.21.
+const RECIP_TWO: i32 = 2 << 21;
+
+/// Wrap a value to signed 24 bit.
+const fn wrap24(v: i32) -> i32 {
+    (v << 8) >> 8
+}
+
+/// Calculate the magic multiplier and the shift count for signed division by `d`.
+///
+/// `d` must not be -1, 0, 1 or MIN.
+///
+/// See: Henry S. Warren, Jr. - Hacker's Delight - Integer division by constants.
+const fn magic(d: i32) -> (i32, u8) {
+    const MASK: u32 = 0xFF_FFFF;
+    const TWO23: u32 = 1 << 23;
+
+    let ad = d.unsigned_abs();
+    let t = TWO23 + if d < 0 { 1 } else { 0 };
+    // `ad` and `anc` are never zero.
+    // The checked division just keeps the panic paths out of the code.
+    let Some(rem) = t.checked_rem(ad) else {
+        return (0, 0);
+    };
+    let anc = t - 1 - rem;
+    let (Some(mut q1), Some(mut q2)) = (TWO23.checked_div(anc), TWO23.checked_div(ad)) else {
+        return (0, 0);
+    };
+    let mut p = 23;
+    let mut r1 = TWO23 - q1 * anc;
+    let mut r2 = TWO23 - q2 * ad;
+    loop {
+        p += 1;
+        q1 = (q1 * 2) & MASK;
+        r1 *= 2;
+        if r1 >= anc {
+            q1 = (q1 + 1) & MASK;
+            r1 -= anc;
+        }
+        q2 = (q2 * 2) & MASK;
+        r2 *= 2;
+        if r2 >= ad {
+            q2 = (q2 + 1) & MASK;
+            r2 -= ad;
+        }
+        let delta = ad - r2;
+        if !(q1 < delta || (q1 == delta && r1 == 0)) {
+            break;
+        }
+    }
+    let m = wrap24(((q2 + 1) & MASK) as i32);
+    let m = if d < 0 { wrap24(-m) } else { m };
+    (m, p - 24)
+}
+
+/// Precomputed [Int24] divisor for fast repeated division.
+///
+/// Construction calculates a magic multiplier for the divisor.
+/// The division [DivisorInt24::divide] then uses a multiplication and a shift
+/// instead of the long division loop.
+///
+/// The quotient is rounded towards zero and saturated, just like [Int24::div].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DivisorInt24 {
+    divisor: Int24,
+    magic: Int24,
+    shift: u8,
+    kind: Kind,
+}
+
+impl DivisorInt24 {
+    /// Precompute the divisor `d`.
+    pub const fn new(d: Int24) -> Self {
+        let v = d.to_i32();
+        let (kind, magic, shift) = match v {
+            0 => (Kind::Zero, 0, 0),
+            1 => (Kind::One, 0, 0),
+            -1 => (Kind::MinusOne, 0, 0),
+            -0x80_0000 => (Kind::Min, 0, 0),
+            _ => {
+                let (m, s) = magic(v);
+                let kind = if v > 0 && m < 0 {
+                    Kind::MagicAdd
+                } else if v < 0 && m > 0 {
+                    Kind::MagicSub
+                } else {
+                    Kind::Magic
+                };
+                (kind, m, s)
+            }
+        };
+        Self {
+            divisor: d,
+            magic: Int24::from_i32(magic),
+            shift,
+            kind,
+        }
+    }
+
+    /// Get the divisor value.
+    pub const fn divisor(&self) -> Int24 {
+        self.divisor
+    }
+
+    /// Divide `x` by this divisor and saturate.
+    #[inline(never)]
+    pub fn divide(&self, x: Int24) -> Int24 {
+        match self.kind {
+            Kind::Zero => {
+                if x.to_i32() < 0 {
+                    Int24::from_raw(raw_min())
+                } else {
+                    Int24::from_raw(raw_max())
+                }
+            }
+            Kind::One => x,
+            Kind::MinusOne => x.neg(),
+            Kind::Min => {
+                if x.0 == raw_min() {
+                    Int24::from_i8(1)
+                } else {
+                    Int24::zero()
+                }
+            }
+            Kind::Magic | Kind::MagicAdd | Kind::MagicSub => {
+                let (_, hi) = mul24_wide(self.magic.0, x.0);
+                let mut q = i24raw_to_i32(hi);
+                match self.kind {
+                    Kind::MagicAdd => q += x.to_i32(),
+                    Kind::MagicSub => q -= x.to_i32(),
+                    _ => (),
+                }
+                q >>= self.shift;
+                if q < 0 {
+                    q += 1;
+                }
+                Int24::from_i32(q)
+            }
+        }
+    }
+}
+
+impl Int24 {
+    /// Get the reciprocal `1 / self` in Q0.23 format.
+    ///
+    /// The reciprocal is calculated with a linear estimate that is refined by three
+    /// Newton-Raphson iterations. The remaining error is corrected,
+    /// so that the result is `2^23 / self` rounded to the nearest value.
+    /// The reciprocals of zero and one saturate to [Frac24::MAX].
+    ///
+    /// Multiplications by the reciprocal can replace repeated divisions by a slowly changing value.
+    /// The quotients of such multiplications are rounded towards negative infinity
+    /// and may be off by one compared to [Int24::div].
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let r = Int24::from_i16(1000).recip_q23();
+    /// assert_eq!(r.to_bits(), Int24::from_i16(8389));
+    /// assert_eq!(r * Int24::from_i32(500_000), Int24::from_i16(500));
+    /// ```
+    #[inline(never)]
+    pub fn recip_q23(self) -> Frac24 {
+        let x = self.to_i32();
+        let a = x.unsigned_abs();
+        match x {
+            0 | 1 => return Frac24::MAX,
+            -1 => return Frac24::MIN,
+            -0x80_0000 => return Frac24::from_bits(Int24::from_i8(-1)),
+            _ => (),
+        }
+
+        // Normalize to d = a / 2^(k + 1) in the range [0.5, 1) in Q0.23.
+        let k = a.ilog2() as u8;
+        let d = Int24::from_i32((a << (22 - k)) as i32);
+
+        // Linear estimate y = 48/17 - 32/17 * d in Q2.21 with an error of at most 1/17.
+        let mut y = Int24::from_i32(RECIP_C0) - d.mul_shr(Int24::from_i32(RECIP_C1), 23);
+        // Newton-Raphson: y = y * (2 - d * y)
+        for _ in 0..3 {
+            let e = Int24::from_i32(RECIP_TWO) - d.mul_shr(y, 23);
+            y = y.mul_shr(e, 21);
+        }
+
+        // The estimate is off by at most one.
+        // Round to nearest with the exact remainder e = 2^23 - r * a.
+        let a = a as i32;
+        let mut r = y.shr(k - 1).to_i32();
+        let p = Int48::mul_int24(Int24::from_i32(r), Int24::from_i32(a)).to_i64() as i32;
+        let e = (1 << 23) - p;
+        let half = a >> 1;
+        if e > half {
+            r += 1;
+        } else if e < -half {
+            r -= 1;
+        }
+
+        Frac24::from_bits(Int24::from_i32(if x < 0 { -r } else { r }))
+    }
+}
+
+impl core::ops::Div<DivisorInt24> for Int24 {
+    type Output = Self;
+
+    fn div(self, other: DivisorInt24) -> Self {
+        other.divide(self)
+    }
+}
+
+impl core::ops::DivAssign<DivisorInt24> for Int24 {
+    fn div_assign(&mut self, other: DivisorInt24) {
+        self.0 = (*self / other).0;
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,122 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Fix24, Int24};
+
+/// Errors of the fallible operations that return a [Result].
+///
+/// This implements [core::error::Error], so that the errors can be propagated with `?`.
+///
+/// ```
+/// use avr_int24::{Error, Int24};
+///
+/// fn parse_frame(frame: &[u8]) -> Result<i16, Error> {
+///     let v = Int24::try_from_be_slice(frame)?;
+///     i16::try_from(v)
+/// }
+///
+/// assert_eq!(parse_frame(&[0xFF, 0xFF, 0xFE]), Ok(-2));
+/// assert_eq!(parse_frame(&[0x01, 0x00, 0x00]), Err(Error::OutOfRange));
+/// assert_eq!(
+///     parse_frame(&[0x01]).unwrap_err().to_string(),
+///     "slice of 1 bytes is too short, 3 bytes needed"
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The value is out of the range of the target type.
+    OutOfRange,
+    /// The string is not a valid decimal number.
+    Parse,
+    /// The byte slice is shorter than the number of bytes needed.
+    SliceTooShort {
+        /// Number of bytes needed.
+        needed: usize,
+        /// Length of the slice.
+        len: usize,
+    },
+    /// The scale or the number of fractional bits or decimal places is out of range.
+    InvalidScale,
+    /// The checksum of a persisted record doesn't match.
+    Checksum,
+    /// The breakpoints of a persisted calibration curve are not sorted.
+    Unsorted,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::OutOfRange => write!(f, "value out of range"),
+            Self::Parse => write!(f, "invalid decimal number"),
+            Self::SliceTooShort { needed, len } => {
+                write!(
+                    f,
+                    "slice of {len} bytes is too short, {needed} bytes needed"
+                )
+            }
+            Self::InvalidScale => write!(f, "scale out of range"),
+            Self::Checksum => write!(f, "checksum mismatch"),
+            Self::Unsorted => write!(f, "breakpoints not sorted"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Conversion that fails with [Error::OutOfRange], if the value doesn't fit into 24 bits.
+///
+/// Use [Int24::from_i32] for a saturating conversion.
+impl TryFrom<i32> for Int24 {
+    type Error = Error;
+
+    fn try_from(v: i32) -> Result<Self, Error> {
+        if (-0x80_0000..=0x7F_FFFF).contains(&v) {
+            Ok(Self::from_i32(v))
+        } else {
+            Err(Error::OutOfRange)
+        }
+    }
+}
+
+/// Conversions that fail with [Error::OutOfRange], if the value doesn't fit into the target type.
+macro_rules! impl_try_from_int24 {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<Int24> for $t {
+                type Error = Error;
+
+                fn try_from(v: Int24) -> Result<Self, Error> {
+                    <$t>::try_from(v.to_i32()).map_err(|_| Error::OutOfRange)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_int24!(i8, i16, u8, u16, u32, usize);
+
+/// Parse a decimal integer string like `-1234`.
+///
+/// See [Int24::parse_decimal] with a scale of zero.
+impl core::str::FromStr for Int24 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::parse_decimal(s, 0)
+    }
+}
+
+/// Parse a decimal number string like `-3.1416`.
+///
+/// See [Fix24::parse].
+impl<const FRAC: u8> core::str::FromStr for Fix24<FRAC> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::parse(s)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
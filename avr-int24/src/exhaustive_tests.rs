@@ -0,0 +1,184 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Exhaustive differential tests of the operations against plain integer math.
+//!
+//! These tests are only built with the `exhaustive-test` feature.
+//! They take a long time to run. Run them in release mode:
+//!
+//! ```sh
+//! cargo test --release --features exhaustive-test
+//! ```
+
+use crate::{Int24, XorShift24};
+
+const MIN: i32 = -0x80_0000;
+const MAX: i32 = 0x7F_FFFF;
+
+/// Number of dense samples near each of the limits.
+const NEAR_LIMIT: i32 = 0x1000;
+
+/// Saturate `v` to the 24 bit range.
+fn sat(v: i64) -> i32 {
+    v.clamp(MIN as i64, MAX as i64) as i32
+}
+
+/// Saturated division with the division by zero semantics of [Int24].
+fn div_ref(a: i64, b: i64) -> i32 {
+    if b == 0 {
+        if a < 0 { MIN } else { MAX }
+    } else {
+        sat(a / b)
+    }
+}
+
+/// All 16 bit operands and dense samples near the 24 bit limits.
+fn operands() -> impl Iterator<Item = i32> {
+    (i16::MIN as i32..=i16::MAX as i32)
+        .chain(MIN..MIN + NEAR_LIMIT)
+        .chain(MAX - NEAR_LIMIT + 1..=MAX)
+}
+
+/// Edge case operands: small values, powers of two and their neighbors, and the limits.
+fn edges() -> Vec<i32> {
+    let mut v: Vec<i32> = (-64..=64).collect();
+    for k in 7..=23 {
+        let p = 1_i32 << k;
+        for x in [p - 1, p, p + 1] {
+            v.push(x.min(MAX));
+            v.push((-x).max(MIN));
+        }
+    }
+    v.extend(MIN..MIN + 8);
+    v.extend(MAX - 7..=MAX);
+    v.sort_unstable();
+    v.dedup();
+    v
+}
+
+/// Check the binary operation `f` against the reference `r` for all pairs
+/// of [operands] and [edges] in both orders.
+fn sweep2(name: &str, f: impl Fn(Int24, Int24) -> Int24, r: impl Fn(i64, i64) -> i32) {
+    let edges = edges();
+    for a in operands() {
+        for &b in &edges {
+            for (a, b) in [(a, b), (b, a)] {
+                let res = f(Int24::from_i32(a), Int24::from_i32(b)).to_i32();
+                let reference = r(a as i64, b as i64);
+                assert_eq!(res, reference, "{name}: a={a} b={b}");
+            }
+        }
+    }
+}
+
+/// Check the unary operation `f` against the reference `r` for all 24 bit values.
+fn sweep1(name: &str, f: impl Fn(Int24) -> Int24, r: impl Fn(i64) -> i32) {
+    for a in MIN..=MAX {
+        let res = f(Int24::from_i32(a)).to_i32();
+        assert_eq!(res, r(a as i64), "{name}: a={a}");
+    }
+}
+
+#[test]
+fn exhaustive_add_sub() {
+    sweep2("add", |a, b| a + b, |a, b| sat(a + b));
+    sweep2("sub", |a, b| a - b, |a, b| sat(a - b));
+}
+
+#[test]
+fn exhaustive_mul() {
+    sweep2("mul", |a, b| a * b, |a, b| sat(a * b));
+    for count in [0, 1, 7, 8, 15, 16, 23, 24, 31, 32, 47] {
+        sweep2(
+            "mul_shr",
+            |a, b| a.mul_shr(b, count),
+            |a, b| sat((a * b) >> count),
+        );
+    }
+}
+
+#[test]
+fn exhaustive_div() {
+    sweep2("div", |a, b| a / b, div_ref);
+    sweep2("shl8div", |a, b| a.shl8div(b), |a, b| div_ref(a << 8, b));
+    for count in [0, 1, 7, 8, 16, 23, 24] {
+        sweep2(
+            "shl_div",
+            |a, b| a.shl_div(b, count),
+            |a, b| div_ref(a << count, b),
+        );
+    }
+}
+
+#[test]
+fn exhaustive_small_operands() {
+    for b in i16::MIN..=i16::MAX {
+        for a in edges() {
+            let res = Int24::from_i32(a).mul_i16(b).to_i32();
+            assert_eq!(res, sat(a as i64 * b as i64), "mul_i16: a={a} b={b}");
+        }
+    }
+    for b in 0..=u8::MAX {
+        for a in operands() {
+            let res = Int24::from_i32(a).mul_u8(b).to_i32();
+            assert_eq!(res, sat(a as i64 * b as i64), "mul_u8: a={a} b={b}");
+            let res = Int24::from_i32(a).div_u8(b).to_i32();
+            assert_eq!(res, div_ref(a as i64, b as i64), "div_u8: a={a} b={b}");
+        }
+    }
+}
+
+#[test]
+fn exhaustive_unary() {
+    sweep1("neg", |a| -a, |a| sat(-a));
+    sweep1("abs", |a| a.abs(), |a| sat(a.abs()));
+    sweep1("isqrt", |a| a.isqrt(), |a| a.max(0).isqrt() as i32);
+}
+
+#[test]
+fn exhaustive_recip_q23() {
+    sweep1(
+        "recip_q23",
+        |a| a.recip_q23().to_bits(),
+        |a| match a {
+            0 => MAX,
+            _ => sat(((1 << 24) / a + (1 << 24) / a % 2) >> 1),
+        },
+    );
+}
+
+#[test]
+fn exhaustive_shift() {
+    for count in 0..24 {
+        // The left shift truncates to 24 bits.
+        sweep1("shl", |a| a.shl(count), |a| ((a << count) as i32) << 8 >> 8);
+        sweep1("shr", |a| a.shr(count), |a| (a >> count) as i32);
+    }
+}
+
+#[test]
+fn exhaustive_xorshift24_period() {
+    let mut rng = XorShift24::new(1);
+    let mut seen = vec![false; 1 << 24];
+    for _ in 0..(1 << 24) - 1 {
+        let v = rng.next_int24().to_bits() as usize;
+        assert!(v != 0 && !seen[v], "xorshift24: repeated {v:#X}");
+        seen[v] = true;
+    }
+    assert_eq!(rng.state(), 1);
+}
+
+#[test]
+fn exhaustive_cmp() {
+    let edges = edges();
+    for a in operands() {
+        for &b in &edges {
+            let (x, y) = (Int24::from_i32(a), Int24::from_i32(b));
+            assert_eq!(x.cmp(y), a.cmp(&b), "cmp: a={a} b={b}");
+            assert_eq!(y.cmp(x), b.cmp(&a), "cmp: a={b} b={a}");
+        }
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,76 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! C ABI exports of the core operations.
+//!
+//! C has no portable 24 bit integer type.
+//! Therefore, all values are passed as `int32_t`.
+//! Arguments outside of the 24 bit range are saturated before the operation.
+//! Results are always within the 24 bit range.
+//!
+//! The C declarations are in `include/avr_int24.h`.
+
+use crate::Int24;
+
+/// Saturate `a` to the 24 bit range.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_sat(a: i32) -> i32 {
+    Int24::from_i32(a).to_i32()
+}
+
+/// Saturate `a` to the 24 bit range and then to the 16 bit range.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_to_i16(a: i32) -> i16 {
+    Int24::from_i32(a).to_i16()
+}
+
+/// Saturating addition.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_add(a: i32, b: i32) -> i32 {
+    (Int24::from_i32(a) + Int24::from_i32(b)).to_i32()
+}
+
+/// Saturating subtraction.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_sub(a: i32, b: i32) -> i32 {
+    (Int24::from_i32(a) - Int24::from_i32(b)).to_i32()
+}
+
+/// Saturating multiplication.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_mul(a: i32, b: i32) -> i32 {
+    (Int24::from_i32(a) * Int24::from_i32(b)).to_i32()
+}
+
+/// Saturating division. Division by zero saturates.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_div(a: i32, b: i32) -> i32 {
+    (Int24::from_i32(a) / Int24::from_i32(b)).to_i32()
+}
+
+/// Saturating negation.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_neg(a: i32) -> i32 {
+    (-Int24::from_i32(a)).to_i32()
+}
+
+/// Saturating absolute value.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_abs(a: i32) -> i32 {
+    Int24::from_i32(a).abs().to_i32()
+}
+
+/// Left shift without saturation.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_shl(a: i32, count: u8) -> i32 {
+    (Int24::from_i32(a) << count).to_i32()
+}
+
+/// Arithmetic right shift.
+#[unsafe(no_mangle)]
+pub extern "C" fn int24_shr(a: i32, count: u8) -> i32 {
+    (Int24::from_i32(a) >> count).to_i32()
+}
+
+// vim: ts=4 sw=4 expandtab
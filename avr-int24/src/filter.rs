@@ -0,0 +1,207 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{
+    Fix24, Frac24, Int24, Int48,
+    raw::{conv::i64_to_i24raw_sat, mac24},
+};
+
+/// Exponential moving average low pass filter.
+///
+/// Each update calculates `y += (x - y) / 2^shift`.
+///
+/// The filter state is kept with `shift` additional fractional bits,
+/// so that the output converges exactly to a constant input and no
+/// precision is lost to truncation.
+/// The output is rounded to the nearest integer.
+///
+/// ```
+/// use avr_int24::{Ema24, Int24};
+///
+/// let mut ema = Ema24::new(2);
+/// assert_eq!(ema.update(Int24::from_i16(100)), Int24::from_i16(25));
+/// assert_eq!(ema.update(Int24::from_i16(100)), Int24::from_i16(44));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Ema24 {
+    acc: Int48,
+    shift: u8,
+}
+
+impl Ema24 {
+    /// Construct a new filter with the coefficient `1 / 2^shift` and an output of zero.
+    ///
+    /// Shift counts bigger than 23 are treated as 23.
+    pub const fn new(shift: u8) -> Self {
+        Self {
+            acc: Int48::zero(),
+            shift: if shift > 23 { 23 } else { shift },
+        }
+    }
+
+    /// Set the filter output to `value`.
+    ///
+    /// This can be used to prime the filter with the first sample.
+    pub fn reset(&mut self, value: Int24) {
+        self.acc = Int48::from_int24(value).shl(self.shift);
+    }
+
+    /// Get the current filter output.
+    pub fn value(&self) -> Int24 {
+        let half = Int48::from_i32((1 << self.shift) >> 1);
+        (self.acc + half).to_int24_shr(self.shift)
+    }
+
+    /// Feed the next input sample into the filter and return the new output.
+    pub fn update(&mut self, x: Int24) -> Int24 {
+        self.acc = self.acc + Int48::from_int24(x) - Int48::from_int24(self.value());
+        self.value()
+    }
+}
+
+/// FIR filter with `TAPS` Q0.23 coefficients.
+///
+/// Each update calculates `y = c[0] * x[n] + c[1] * x[n - 1] + ... + c[TAPS - 1] * x[n - TAPS + 1]`.
+///
+/// The products are accumulated with 56 bits and without intermediate saturation.
+/// Only the final output is rounded to the nearest integer and saturated.
+///
+/// ```
+/// use avr_int24::{Fir24, Frac24, Int24};
+///
+/// // Moving average over two samples.
+/// let mut fir = Fir24::new([Frac24::HALF, Frac24::HALF]);
+/// assert_eq!(fir.update(Int24::from_i16(100)), Int24::from_i16(50));
+/// assert_eq!(fir.update(Int24::from_i16(200)), Int24::from_i16(150));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Fir24<const TAPS: usize> {
+    /// Coefficients in reversed order. The first coefficient belongs to the oldest sample.
+    coefs: [Int24; TAPS],
+    /// Ring buffer of the past input samples.
+    delay: [Int24; TAPS],
+    /// Position of the oldest sample in `delay`.
+    pos: usize,
+}
+
+impl<const TAPS: usize> Fir24<TAPS> {
+    const TAPS_CHECK: () = assert!(TAPS > 0, "Fir24: TAPS must not be zero");
+
+    /// Construct a new filter with the given coefficients and all past samples set to zero.
+    pub const fn new(coefs: [Frac24; TAPS]) -> Self {
+        let () = Self::TAPS_CHECK;
+        let mut rcoefs = [Int24::zero(); TAPS];
+        let mut i = 0;
+        while i < TAPS {
+            rcoefs[i] = coefs[TAPS - 1 - i].to_bits();
+            i += 1;
+        }
+        Self {
+            coefs: rcoefs,
+            delay: [Int24::zero(); TAPS],
+            pos: 0,
+        }
+    }
+
+    /// Set all past samples to zero.
+    pub fn reset(&mut self) {
+        self.delay = [Int24::zero(); TAPS];
+        self.pos = 0;
+    }
+
+    /// Feed the next input sample into the filter and return the new output.
+    pub fn update(&mut self, x: Int24) -> Int24 {
+        // Replace the oldest sample by the new one.
+        self.delay[self.pos] = x;
+        self.pos += 1;
+        if self.pos >= TAPS {
+            self.pos = 0;
+        }
+
+        // Convolve the samples from the oldest to the newest.
+        let split = TAPS - self.pos;
+        let coefs = Int24::as_raw_slice(&self.coefs);
+        let delay = Int24::as_raw_slice(&self.delay);
+        let acc = mac24(1 << 22, &coefs[..split], &delay[self.pos..]);
+        let acc = mac24(acc, &coefs[split..], &delay[..self.pos]);
+        Int24::from_raw(i64_to_i24raw_sat(acc >> 23))
+    }
+}
+
+/// Biquad IIR filter in direct form I with Q-format coefficients.
+///
+/// Each update calculates `y = b0 * x[n] + b1 * x[n - 1] + b2 * x[n - 2] - a1 * y[n - 1] - a2 * y[n - 2]`.
+/// The coefficient `a0` is normalized to 1.
+///
+/// The coefficients have `FRAC` fractional bits.
+/// For typical filters the feedback coefficients are in the range `-2.0..2.0`,
+/// so `Fix24<22>` is a good choice.
+///
+/// The products are accumulated with 56 bits and without intermediate saturation.
+/// Only the output is rounded to the nearest integer and saturated.
+///
+/// ```
+/// use avr_int24::{Biquad24, Fix24, Int24};
+///
+/// // y = 0.5 * x + 0.5 * y[n - 1]
+/// let half = Fix24::<22>::from_bits(Int24::from_i32(1 << 21));
+/// let mut iir = Biquad24::new(half, Fix24::ZERO, Fix24::ZERO, -half, Fix24::ZERO);
+/// assert_eq!(iir.update(Int24::from_i16(100)), Int24::from_i16(50));
+/// assert_eq!(iir.update(Int24::from_i16(100)), Int24::from_i16(75));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Biquad24<const FRAC: u8> {
+    /// Coefficients `b0`, `b1`, `b2`, `-a1`, `-a2`.
+    coefs: [Int24; 5],
+    /// State `x[n]`, `x[n - 1]`, `x[n - 2]`, `y[n - 1]`, `y[n - 2]`.
+    state: [Int24; 5],
+}
+
+impl<const FRAC: u8> Biquad24<FRAC> {
+    /// Construct a new filter with the given coefficients and all past samples set to zero.
+    pub const fn new(
+        b0: Fix24<FRAC>,
+        b1: Fix24<FRAC>,
+        b2: Fix24<FRAC>,
+        a1: Fix24<FRAC>,
+        a2: Fix24<FRAC>,
+    ) -> Self {
+        Self {
+            coefs: [
+                b0.to_bits(),
+                b1.to_bits(),
+                b2.to_bits(),
+                a1.to_bits().const_neg(),
+                a2.to_bits().const_neg(),
+            ],
+            state: [Int24::zero(); 5],
+        }
+    }
+
+    /// Set all past samples to zero.
+    pub fn reset(&mut self) {
+        self.state = [Int24::zero(); 5];
+    }
+
+    /// Feed the next input sample into the filter and return the new output.
+    pub fn update(&mut self, x: Int24) -> Int24 {
+        self.state[2] = self.state[1];
+        self.state[1] = self.state[0];
+        self.state[0] = x;
+
+        let round = (1 << FRAC) >> 1;
+        let acc = mac24(
+            round,
+            Int24::as_raw_slice(&self.coefs),
+            Int24::as_raw_slice(&self.state),
+        );
+        let y = Int24::from_raw(i64_to_i24raw_sat(acc >> FRAC));
+
+        self.state[4] = self.state[3];
+        self.state[3] = y;
+        y
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
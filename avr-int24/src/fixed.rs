@@ -0,0 +1,626 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, raw::conv::i64_to_i24raw_sat};
+
+/// Implement the arithmetic operator traits for a fixed-point type.
+macro_rules! impl_fixed_ops {
+    ($t:ty) => {
+        impl core::ops::Add for $t {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self::add(self, other)
+            }
+        }
+
+        impl core::ops::AddAssign for $t {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl core::ops::Sub for $t {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self::sub(self, other)
+            }
+        }
+
+        impl core::ops::SubAssign for $t {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl core::ops::Mul for $t {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self::mul(self, other)
+            }
+        }
+
+        impl core::ops::MulAssign for $t {
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl core::ops::Div for $t {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self {
+                Self::div(self, other)
+            }
+        }
+
+        impl core::ops::DivAssign for $t {
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl core::ops::Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::neg(self)
+            }
+        }
+    };
+}
+
+/// Signed Q16.8 fixed-point number.
+///
+/// The value is stored in an [Int24] with 16 integer bits (including the sign)
+/// and 8 fractional bits.
+///
+/// All arithmetic operations saturate.
+///
+/// ```
+/// use avr_int24::I16F8;
+///
+/// let a = I16F8::from_int(3);
+/// let b = I16F8::from_bits(avr_int24::Int24::from_i16(0x80)); // 0.5
+///
+/// assert_eq!(a * b, I16F8::from_bits(avr_int24::Int24::from_i16(0x180))); // 1.5
+/// assert_eq!((a / b).to_int(), 6);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+#[repr(transparent)]
+pub struct I16F8(Int24);
+
+#[allow(clippy::should_implement_trait)]
+impl I16F8 {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u8 = 8;
+
+    /// The smallest value that can be represented by [I16F8].
+    pub const MIN: Self = Self(Int24::MIN);
+
+    /// The largest value that can be represented by [I16F8].
+    pub const MAX: Self = Self(Int24::MAX);
+
+    /// Zero.
+    pub const ZERO: Self = Self(Int24::zero());
+
+    /// One.
+    pub const ONE: Self = Self(Int24::from_i16(1 << 8));
+
+    /// Construct a new [I16F8] from the raw fixed-point bits.
+    pub const fn from_bits(bits: Int24) -> Self {
+        Self(bits)
+    }
+
+    /// Get the raw fixed-point bits.
+    pub const fn to_bits(self) -> Int24 {
+        self.0
+    }
+
+    /// Construct a new [I16F8] from an integer.
+    pub const fn from_int(v: i16) -> Self {
+        Self(Int24::from_i16(v).shl8())
+    }
+
+    /// Convert this [I16F8] to an integer.
+    /// The fractional bits are rounded towards negative infinity.
+    pub const fn to_int(self) -> i16 {
+        self.0.shr8().cast_to_i16()
+    }
+
+    /// Add and saturate two [I16F8].
+    #[inline(always)]
+    pub fn add(self, other: Self) -> Self {
+        Self(self.0.add(other.0))
+    }
+
+    /// Subtract and saturate two [I16F8].
+    #[inline(always)]
+    pub fn sub(self, other: Self) -> Self {
+        Self(self.0.sub(other.0))
+    }
+
+    /// Multiply and saturate two [I16F8].
+    #[inline(always)]
+    pub fn mul(self, other: Self) -> Self {
+        Self(self.0.mul_shr(other.0, Self::FRAC_BITS))
+    }
+
+    /// Divide and saturate two [I16F8].
+    #[inline(always)]
+    pub fn div(self, other: Self) -> Self {
+        Self(self.0.shl8div(other.0))
+    }
+
+    /// Negate and saturate `self`.
+    #[inline(always)]
+    pub fn neg(self) -> Self {
+        Self(self.0.neg())
+    }
+
+    /// Get the saturated absolute value of `self`.
+    #[inline(always)]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+impl From<i16> for I16F8 {
+    fn from(v: i16) -> Self {
+        Self::from_int(v)
+    }
+}
+
+impl_fixed_ops!(I16F8);
+
+/// Signed Q8.16 fixed-point number.
+///
+/// The value is stored in an [Int24] with 8 integer bits (including the sign)
+/// and 16 fractional bits.
+/// This format is useful for high resolution fractional values
+/// such as PID integrator states.
+///
+/// All arithmetic operations saturate.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+#[repr(transparent)]
+pub struct I8F16(Int24);
+
+#[allow(clippy::should_implement_trait)]
+impl I8F16 {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u8 = 16;
+
+    /// The smallest value that can be represented by [I8F16].
+    pub const MIN: Self = Self(Int24::MIN);
+
+    /// The largest value that can be represented by [I8F16].
+    pub const MAX: Self = Self(Int24::MAX);
+
+    /// Zero.
+    pub const ZERO: Self = Self(Int24::zero());
+
+    /// One.
+    pub const ONE: Self = Self(Int24::from_raw((0x00, 0x00, 0x01)));
+
+    /// Construct a new [I8F16] from the raw fixed-point bits.
+    pub const fn from_bits(bits: Int24) -> Self {
+        Self(bits)
+    }
+
+    /// Get the raw fixed-point bits.
+    pub const fn to_bits(self) -> Int24 {
+        self.0
+    }
+
+    /// Construct a new [I8F16] from an integer.
+    pub const fn from_int(v: i8) -> Self {
+        Self(Int24::from_i8(v).shl16())
+    }
+
+    /// Construct and saturate a new [I8F16] from a 16 bit integer.
+    pub const fn from_i16(v: i16) -> Self {
+        if v > i8::MAX as i16 {
+            Self::MAX
+        } else if v < i8::MIN as i16 {
+            Self::MIN
+        } else {
+            Self::from_int(v as i8)
+        }
+    }
+
+    /// Convert this [I8F16] to an integer.
+    /// The fractional bits are rounded towards negative infinity.
+    pub const fn to_int(self) -> i8 {
+        self.0.shr16().cast_to_i8()
+    }
+
+    /// Convert this [I8F16] to a 16 bit integer.
+    /// The fractional bits are rounded towards negative infinity.
+    pub const fn to_i16(self) -> i16 {
+        self.to_int() as i16
+    }
+
+    /// Construct and saturate a new [I8F16] from an [I16F8].
+    pub const fn from_i16f8(v: I16F8) -> Self {
+        let v = v.to_bits().to_i32();
+        Self(Int24::from_i32(if v > 0x7FFF {
+            0x7F_FFFF
+        } else if v < -0x8000 {
+            -0x80_0000
+        } else {
+            v << 8
+        }))
+    }
+
+    /// Convert this [I8F16] to an [I16F8].
+    /// The dropped fractional bits are rounded towards negative infinity.
+    pub const fn to_i16f8(self) -> I16F8 {
+        I16F8::from_bits(self.0.shr8())
+    }
+
+    /// Add and saturate two [I8F16].
+    #[inline(always)]
+    pub fn add(self, other: Self) -> Self {
+        Self(self.0.add(other.0))
+    }
+
+    /// Subtract and saturate two [I8F16].
+    #[inline(always)]
+    pub fn sub(self, other: Self) -> Self {
+        Self(self.0.sub(other.0))
+    }
+
+    /// Multiply and saturate two [I8F16].
+    #[inline(always)]
+    pub fn mul(self, other: Self) -> Self {
+        Self(self.0.mul_shr(other.0, Self::FRAC_BITS))
+    }
+
+    /// Divide and saturate two [I8F16].
+    #[inline(always)]
+    pub fn div(self, other: Self) -> Self {
+        Self(self.0.shl_div(other.0, Self::FRAC_BITS))
+    }
+
+    /// Negate and saturate `self`.
+    #[inline(always)]
+    pub fn neg(self) -> Self {
+        Self(self.0.neg())
+    }
+
+    /// Get the saturated absolute value of `self`.
+    #[inline(always)]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+impl From<i8> for I8F16 {
+    fn from(v: i8) -> Self {
+        Self::from_int(v)
+    }
+}
+
+impl From<I16F8> for I8F16 {
+    fn from(v: I16F8) -> Self {
+        Self::from_i16f8(v)
+    }
+}
+
+impl From<I8F16> for I16F8 {
+    fn from(v: I8F16) -> Self {
+        v.to_i16f8()
+    }
+}
+
+impl_fixed_ops!(I8F16);
+
+/// Signed Q0.23 fractional number in the range [-1, 1).
+///
+/// The value is stored in an [Int24] with the sign bit and 23 fractional bits.
+/// This is the natural coefficient format for filters operating on 24 bit samples.
+///
+/// All arithmetic operations saturate.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+#[repr(transparent)]
+pub struct Frac24(Int24);
+
+#[allow(clippy::should_implement_trait)]
+impl Frac24 {
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u8 = 23;
+
+    /// The smallest value that can be represented by [Frac24]: -1.0
+    pub const MIN: Self = Self(Int24::MIN);
+
+    /// The largest value that can be represented by [Frac24]: 1.0 - 2^-23
+    pub const MAX: Self = Self(Int24::MAX);
+
+    /// Zero.
+    pub const ZERO: Self = Self(Int24::zero());
+
+    /// One half.
+    pub const HALF: Self = Self(Int24::from_raw((0x00, 0x00, 0x40)));
+
+    /// Construct a new [Frac24] from the raw fractional bits.
+    pub const fn from_bits(bits: Int24) -> Self {
+        Self(bits)
+    }
+
+    /// Get the raw fractional bits.
+    pub const fn to_bits(self) -> Int24 {
+        self.0
+    }
+
+    /// Add and saturate two [Frac24].
+    #[inline(always)]
+    pub fn add(self, other: Self) -> Self {
+        Self(self.0.add(other.0))
+    }
+
+    /// Subtract and saturate two [Frac24].
+    #[inline(always)]
+    pub fn sub(self, other: Self) -> Self {
+        Self(self.0.sub(other.0))
+    }
+
+    /// Fractional multiplication of two [Frac24].
+    ///
+    /// The 48 bit product is shifted right by 23 bits.
+    /// The only case that saturates is `-1.0 * -1.0`.
+    #[inline(always)]
+    pub fn mul(self, other: Self) -> Self {
+        Self(self.0.mul_shr(other.0, Self::FRAC_BITS))
+    }
+
+    /// Multiply the integer `v` by this fraction.
+    ///
+    /// The 48 bit product is shifted right by 23 bits,
+    /// so this scales a 24 bit sample by the coefficient `self`.
+    #[inline(always)]
+    pub fn mul_int(self, v: Int24) -> Int24 {
+        v.mul_shr(self.0, Self::FRAC_BITS)
+    }
+
+    /// Divide and saturate two [Frac24].
+    ///
+    /// The result saturates, if the magnitude of `self` is not smaller than the magnitude of `other`.
+    #[inline(always)]
+    pub fn div(self, other: Self) -> Self {
+        Self(self.0.shl_div(other.0, Self::FRAC_BITS))
+    }
+
+    /// Negate and saturate `self`.
+    #[inline(always)]
+    pub fn neg(self) -> Self {
+        Self(self.0.neg())
+    }
+
+    /// Get the saturated absolute value of `self`.
+    #[inline(always)]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+impl core::ops::Mul<Int24> for Frac24 {
+    type Output = Int24;
+
+    fn mul(self, other: Int24) -> Int24 {
+        self.mul_int(other)
+    }
+}
+
+impl_fixed_ops!(Frac24);
+
+/// Arithmetically shift `v` left (positive `count`) or right (negative `count`)
+/// and saturate the result.
+const fn shift_sat(v: Int24, count: i8) -> Int24 {
+    let v = v.to_i32() as i64;
+    let v = if count >= 0 { v << count } else { v >> -count };
+    Int24::from_raw(i64_to_i24raw_sat(v))
+}
+
+/// Generic signed fixed-point number with `FRAC` fractional bits.
+///
+/// The value is stored in an [Int24].
+/// `FRAC` must be in the range 0 to 23.
+/// This is checked at compile time.
+///
+/// Only values with the same format can be added or subtracted.
+/// Multiplication and division accept any format for the right hand side operand
+/// and return the format of the left hand side operand.
+/// Conversions between formats are explicit via [Fix24::rescale].
+///
+/// All arithmetic operations saturate.
+///
+/// ```
+/// use avr_int24::{Fix24, Int24};
+///
+/// let a: Fix24<8> = Fix24::from_int(Int24::from_i16(3));
+/// let b: Fix24<12> = Fix24::from_bits(Int24::from_i16(0x800)); // 0.5
+///
+/// let c = a * b; // Fix24<8>
+/// assert_eq!(c.to_bits(), Int24::from_i16(0x180)); // 1.5
+///
+/// let d: Fix24<12> = c.rescale();
+/// assert_eq!(d.to_bits(), Int24::from_i16(0x1800));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+#[repr(transparent)]
+pub struct Fix24<const FRAC: u8>(Int24);
+
+#[allow(clippy::should_implement_trait)]
+impl<const FRAC: u8> Fix24<FRAC> {
+    const FRAC_CHECK: () = assert!(FRAC <= 23, "Fix24: FRAC must not be bigger than 23");
+
+    /// Number of fractional bits.
+    pub const FRAC_BITS: u8 = FRAC;
+
+    /// The smallest value that can be represented.
+    pub const MIN: Self = Self::from_bits(Int24::MIN);
+
+    /// The largest value that can be represented.
+    pub const MAX: Self = Self::from_bits(Int24::MAX);
+
+    /// Zero.
+    pub const ZERO: Self = Self::from_bits(Int24::zero());
+
+    /// Construct a new [Fix24] from the raw fixed-point bits.
+    pub const fn from_bits(bits: Int24) -> Self {
+        let () = Self::FRAC_CHECK;
+        Self(bits)
+    }
+
+    /// Get the raw fixed-point bits.
+    pub const fn to_bits(self) -> Int24 {
+        self.0
+    }
+
+    /// Construct and saturate a new [Fix24] from an integer.
+    pub const fn from_int(v: Int24) -> Self {
+        Self::from_bits(shift_sat(v, FRAC as i8))
+    }
+
+    /// Convert this [Fix24] to an integer.
+    /// The fractional bits are rounded towards negative infinity.
+    pub const fn to_int(self) -> Int24 {
+        shift_sat(self.0, -(FRAC as i8))
+    }
+
+    /// Convert this [Fix24] to a different number of fractional bits.
+    ///
+    /// Adding fractional bits saturates the value.
+    /// Removed fractional bits are rounded towards negative infinity.
+    pub const fn rescale<const TO: u8>(self) -> Fix24<TO> {
+        Fix24::from_bits(shift_sat(self.0, TO as i8 - FRAC as i8))
+    }
+
+    /// Add and saturate two [Fix24].
+    #[inline(always)]
+    pub fn add(self, other: Self) -> Self {
+        Self(self.0.add(other.0))
+    }
+
+    /// Subtract and saturate two [Fix24].
+    #[inline(always)]
+    pub fn sub(self, other: Self) -> Self {
+        Self(self.0.sub(other.0))
+    }
+
+    /// Multiply and saturate two [Fix24].
+    /// The result has the format of `self`.
+    #[inline(always)]
+    pub fn mul<const F: u8>(self, other: Fix24<F>) -> Self {
+        Self(self.0.mul_shr(other.0, F))
+    }
+
+    /// Divide and saturate two [Fix24].
+    /// The result has the format of `self`.
+    #[inline(always)]
+    pub fn div<const F: u8>(self, other: Fix24<F>) -> Self {
+        Self(self.0.shl_div(other.0, F))
+    }
+
+    /// Negate and saturate `self`.
+    #[inline(always)]
+    pub fn neg(self) -> Self {
+        Self(self.0.neg())
+    }
+
+    /// Get the saturated absolute value of `self`.
+    #[inline(always)]
+    pub fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+impl<const FRAC: u8> core::ops::Add for Fix24<FRAC> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::add(self, other)
+    }
+}
+
+impl<const FRAC: u8> core::ops::AddAssign for Fix24<FRAC> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const FRAC: u8> core::ops::Sub for Fix24<FRAC> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::sub(self, other)
+    }
+}
+
+impl<const FRAC: u8> core::ops::SubAssign for Fix24<FRAC> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const FRAC: u8, const F: u8> core::ops::Mul<Fix24<F>> for Fix24<FRAC> {
+    type Output = Self;
+
+    fn mul(self, other: Fix24<F>) -> Self {
+        Self::mul(self, other)
+    }
+}
+
+impl<const FRAC: u8, const F: u8> core::ops::MulAssign<Fix24<F>> for Fix24<FRAC> {
+    fn mul_assign(&mut self, other: Fix24<F>) {
+        *self = *self * other;
+    }
+}
+
+impl<const FRAC: u8, const F: u8> core::ops::Div<Fix24<F>> for Fix24<FRAC> {
+    type Output = Self;
+
+    fn div(self, other: Fix24<F>) -> Self {
+        Self::div(self, other)
+    }
+}
+
+impl<const FRAC: u8, const F: u8> core::ops::DivAssign<Fix24<F>> for Fix24<FRAC> {
+    fn div_assign(&mut self, other: Fix24<F>) {
+        *self = *self / other;
+    }
+}
+
+impl<const FRAC: u8> core::ops::Neg for Fix24<FRAC> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+/// Implement the conversions between a fixed-point type and the equivalent [Fix24].
+macro_rules! impl_fix24_conv {
+    ($t:ty, $frac:literal) => {
+        impl From<$t> for Fix24<$frac> {
+            fn from(v: $t) -> Self {
+                Self::from_bits(v.to_bits())
+            }
+        }
+
+        impl From<Fix24<$frac>> for $t {
+            fn from(v: Fix24<$frac>) -> Self {
+                Self::from_bits(v.to_bits())
+            }
+        }
+    };
+}
+
+impl_fix24_conv!(I16F8, 8);
+impl_fix24_conv!(I8F16, 16);
+impl_fix24_conv!(Frac24, 23);
+
+// vim: ts=4 sw=4 expandtab
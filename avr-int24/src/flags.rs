@@ -0,0 +1,213 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{
+    Int24,
+    raw::{
+        abs24, conv::i48raw_to_i64, div24, is_neg24, mul24_wide, neg24, raw_min, shl24_by8_div24,
+    },
+};
+
+impl Int24 {
+    /// Add and saturate two [Int24].
+    /// The returned flag is `true`, if the result has been saturated.
+    #[inline(never)]
+    pub fn add_flagged(self, other: Self) -> (Self, bool) {
+        let v = self.to_i32() + other.to_i32();
+        let r = Self::from_i32(v);
+        (r, r.to_i32() != v)
+    }
+
+    /// Subtract and saturate two [Int24].
+    /// The returned flag is `true`, if the result has been saturated.
+    #[inline(never)]
+    pub fn sub_flagged(self, other: Self) -> (Self, bool) {
+        let v = self.to_i32() - other.to_i32();
+        let r = Self::from_i32(v);
+        (r, r.to_i32() != v)
+    }
+
+    /// Multiply and saturate two [Int24].
+    /// The returned flag is `true`, if the result has been saturated.
+    #[inline(never)]
+    pub fn mul_flagged(self, other: Self) -> (Self, bool) {
+        let (lo, hi) = mul24_wide(self.0, other.0);
+        if (hi == (0x00, 0x00, 0x00) && !is_neg24(lo)) || (hi == (0xFF, 0xFF, 0xFF) && is_neg24(lo))
+        {
+            (Self::from_raw(lo), false)
+        } else if is_neg24(hi) {
+            (Self::MIN, true)
+        } else {
+            (Self::MAX, true)
+        }
+    }
+
+    /// Multiply two [Int24] and arithmetically right shift the product by `count` bits.
+    /// The result is saturated to signed 24 bit.
+    /// The returned flag is `true`, if the result has been saturated.
+    #[inline(never)]
+    pub fn mul_shr_flagged(self, other: Self, count: u8) -> (Self, bool) {
+        let count = if count > 47 { 47 } else { count };
+        let v = i48raw_to_i64(mul24_wide(self.0, other.0)) >> count;
+        if v > 0x7F_FFFF {
+            (Self::MAX, true)
+        } else if v < -0x80_0000 {
+            (Self::MIN, true)
+        } else {
+            (Self::from_i32(v as i32), false)
+        }
+    }
+
+    /// Divide and saturate two [Int24].
+    /// The returned flag is `true`, if the result has been saturated.
+    /// Division by zero is reported as saturation.
+    #[inline(never)]
+    pub fn div_flagged(self, other: Self) -> (Self, bool) {
+        let sat = other == Self::zero() || (self == Self::MIN && other == Self::from_i8(-1));
+        (Self::from_raw(div24(self.0, other.0)), sat)
+    }
+
+    /// Left shift `self` by 8 bits and then divide the shifted value by `other`.
+    /// The result is saturated to signed 24 bit.
+    /// The returned flag is `true`, if the result has been saturated.
+    /// Division by zero is reported as saturation.
+    #[inline(never)]
+    pub fn shl8div_flagged(self, other: Self) -> (Self, bool) {
+        let a = self.to_i32().unsigned_abs() as u64 * 256;
+        let b = other.to_i32().unsigned_abs() as u64;
+        let limit = if is_neg24(self.0) != is_neg24(other.0) {
+            0x80_0001 * b
+        } else {
+            0x80_0000 * b
+        };
+        (Self::from_raw(shl24_by8_div24(self.0, other.0)), a >= limit)
+    }
+
+    /// Left shift `self` by `count` bits and then divide the shifted value by `other`.
+    /// The result is saturated to signed 24 bit.
+    /// The returned flag is `true`, if the result has been saturated.
+    /// Division by zero is reported as saturation.
+    #[inline(never)]
+    pub fn shl_div_flagged(self, other: Self, count: u8) -> (Self, bool) {
+        let count = if count > 24 { 24 } else { count };
+        let b = other.to_i32();
+        if b == 0 {
+            (Self::from_raw(div24(self.0, other.0)), true)
+        } else {
+            let v = ((self.to_i32() as i64) << count) / b as i64;
+            if v > 0x7F_FFFF {
+                (Self::MAX, true)
+            } else if v < -0x80_0000 {
+                (Self::MIN, true)
+            } else {
+                (Self::from_i32(v as i32), false)
+            }
+        }
+    }
+
+    /// Two's complement negate and saturate `self`.
+    /// The returned flag is `true`, if the result has been saturated.
+    #[inline(never)]
+    pub fn neg_flagged(self) -> (Self, bool) {
+        (Self::from_raw(neg24(self.0)), self.0 == raw_min())
+    }
+
+    /// Get the saturated absolute value of `self`.
+    /// The returned flag is `true`, if the result has been saturated.
+    #[inline(never)]
+    pub fn abs_flagged(self) -> (Self, bool) {
+        (Self::from_raw(abs24(self.0)), self.0 == raw_min())
+    }
+}
+
+/// Sticky saturation flag for a chain of [Int24] operations.
+///
+/// All operations performed through this context saturate exactly like the
+/// plain [Int24] operations.
+/// In addition to that, the context remembers whether any of the operations saturated.
+///
+/// ```
+/// use avr_int24::{Int24, Int24Flags};
+///
+/// let mut f = Int24Flags::new();
+/// let a = f.mul(Int24::from_i16(30_000), Int24::from_i16(1_000));
+/// let b = f.add(a, Int24::from_i16(1));
+/// assert_eq!(b, Int24::MAX);
+/// assert!(f.saturated());
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Int24Flags {
+    saturated: bool,
+}
+
+impl Int24Flags {
+    /// Construct a new context with the saturation flag cleared.
+    pub const fn new() -> Self {
+        Self { saturated: false }
+    }
+
+    /// Returns `true`, if any operation saturated since the last clear.
+    pub const fn saturated(&self) -> bool {
+        self.saturated
+    }
+
+    /// Clear the saturation flag.
+    pub fn clear(&mut self) {
+        self.saturated = false;
+    }
+
+    /// Record the result of a `*_flagged` operation and return the value.
+    #[inline(always)]
+    pub fn track(&mut self, result: (Int24, bool)) -> Int24 {
+        self.saturated |= result.1;
+        result.0
+    }
+
+    /// Add and saturate two [Int24].
+    pub fn add(&mut self, a: Int24, b: Int24) -> Int24 {
+        self.track(a.add_flagged(b))
+    }
+
+    /// Subtract and saturate two [Int24].
+    pub fn sub(&mut self, a: Int24, b: Int24) -> Int24 {
+        self.track(a.sub_flagged(b))
+    }
+
+    /// Multiply and saturate two [Int24].
+    pub fn mul(&mut self, a: Int24, b: Int24) -> Int24 {
+        self.track(a.mul_flagged(b))
+    }
+
+    /// Multiply two [Int24] and arithmetically right shift the product by `count` bits.
+    pub fn mul_shr(&mut self, a: Int24, b: Int24, count: u8) -> Int24 {
+        self.track(a.mul_shr_flagged(b, count))
+    }
+
+    /// Divide and saturate two [Int24].
+    pub fn div(&mut self, a: Int24, b: Int24) -> Int24 {
+        self.track(a.div_flagged(b))
+    }
+
+    /// Left shift `a` by 8 bits and then divide by `b` with saturation.
+    pub fn shl8div(&mut self, a: Int24, b: Int24) -> Int24 {
+        self.track(a.shl8div_flagged(b))
+    }
+
+    /// Left shift `a` by `count` bits and then divide by `b` with saturation.
+    pub fn shl_div(&mut self, a: Int24, b: Int24, count: u8) -> Int24 {
+        self.track(a.shl_div_flagged(b, count))
+    }
+
+    /// Two's complement negate and saturate `a`.
+    pub fn neg(&mut self, a: Int24) -> Int24 {
+        self.track(a.neg_flagged())
+    }
+
+    /// Get the saturated absolute value of `a`.
+    pub fn abs(&mut self, a: Int24) -> Int24 {
+        self.track(a.abs_flagged())
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,134 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::Int24;
+
+/// Rounding mode of the floating point to [Int24] conversions.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+// Only `NearestAway` is used by `int24_table!` without the `float` feature.
+#[cfg_attr(not(feature = "float"), allow(dead_code))]
+pub enum Rounding {
+    /// Round to the nearest integer with ties to the even integer.
+    ///
+    /// This is the IEEE 754 default rounding.
+    #[default]
+    NearestEven,
+    /// Round to the nearest integer with ties away from zero.
+    ///
+    /// This is the rounding of [int24_table](crate::int24_table).
+    NearestAway,
+    /// Round towards zero.
+    Truncate,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+}
+
+#[cfg(feature = "float")]
+impl Int24 {
+    /// Convert and saturate an `f64` to [Int24] with the rounding mode `mode`.
+    ///
+    /// Values outside of the [Int24] range saturate, including the infinities.
+    /// NaN is converted to zero.
+    /// The result only depends on the value and the rounding mode,
+    /// so tables generated on the host and on the target are identical.
+    ///
+    /// ```
+    /// use avr_int24::{Int24, Rounding};
+    ///
+    /// assert_eq!(Int24::from_f64(2.5, Rounding::NearestEven), Int24::from_i16(2));
+    /// assert_eq!(Int24::from_f64(3.5, Rounding::NearestEven), Int24::from_i16(4));
+    /// assert_eq!(Int24::from_f64(2.5, Rounding::NearestAway), Int24::from_i16(3));
+    /// assert_eq!(Int24::from_f64(-2.5, Rounding::Truncate), Int24::from_i16(-2));
+    /// assert_eq!(Int24::from_f64(-2.5, Rounding::Floor), Int24::from_i16(-3));
+    /// assert_eq!(Int24::from_f64(-2.5, Rounding::Ceil), Int24::from_i16(-2));
+    /// assert_eq!(Int24::from_f64(1e9, Rounding::Floor), Int24::MAX);
+    /// assert_eq!(Int24::from_f64(f64::NAN, Rounding::Ceil), Int24::zero());
+    /// ```
+    pub const fn from_f64(v: f64, mode: Rounding) -> Self {
+        Self::round_f64(v, mode)
+    }
+
+    /// Convert and saturate an `f32` to [Int24] with the rounding mode `mode`.
+    ///
+    /// See [Int24::from_f64].
+    pub const fn from_f32(v: f32, mode: Rounding) -> Self {
+        Self::from_f64(v as f64, mode)
+    }
+
+    /// Convert this [Int24] to an `f64`.
+    ///
+    /// This conversion is exact.
+    pub const fn to_f64(self) -> f64 {
+        self.to_i32() as f64
+    }
+
+    /// Convert this [Int24] to an `f32`.
+    ///
+    /// This conversion is exact, because the 24 bits fit into the `f32` significand.
+    pub const fn to_f32(self) -> f32 {
+        self.to_i32() as f32
+    }
+}
+
+impl Int24 {
+    /// Round and saturate an `f64` to [Int24] with the rounding mode `mode`.
+    ///
+    /// This is shared by [Int24::from_f64] and [int24_table](crate::int24_table),
+    /// so both convert the same way.
+    pub(crate) const fn round_f64(v: f64, mode: Rounding) -> Self {
+        // Limit the value, so that the rounding can't overflow.
+        // NaN stays NaN and is converted to zero by the cast.
+        let v = v.clamp(-16_777_216.0, 16_777_216.0);
+        // `f64::floor` and friends are not available in `no_std`.
+        let t = v as i64;
+        let frac = v - t as f64;
+        let v = match mode {
+            Rounding::NearestEven => {
+                if frac > 0.5 || (frac == 0.5 && t & 1 != 0) {
+                    t + 1
+                } else if frac < -0.5 || (frac == -0.5 && t & 1 != 0) {
+                    t - 1
+                } else {
+                    t
+                }
+            }
+            Rounding::NearestAway => {
+                if frac >= 0.5 {
+                    t + 1
+                } else if frac <= -0.5 {
+                    t - 1
+                } else {
+                    t
+                }
+            }
+            Rounding::Truncate => t,
+            Rounding::Floor => {
+                if frac < 0.0 {
+                    t - 1
+                } else {
+                    t
+                }
+            }
+            Rounding::Ceil => {
+                if frac > 0.0 {
+                    t + 1
+                } else {
+                    t
+                }
+            }
+        };
+        let v = if v > 0x7F_FFFF {
+            0x7F_FFFF
+        } else if v < -0x80_0000 {
+            -0x80_0000
+        } else {
+            v
+        };
+        Self::from_i32(v as i32)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
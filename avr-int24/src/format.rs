@@ -0,0 +1,233 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Error, Fix24, Int24};
+
+/// Buffer size that is big enough for any formatted [Int24] value.
+pub const FORMAT_BUF_LEN: usize = 20;
+
+/// Maximum number of decimal places.
+const MAX_DECIMALS: u8 = 9;
+
+/// Integer part limit of parsed values. Everything above saturates anyway.
+const PARSE_INT_LIMIT: u64 = 1 << 24;
+
+/// Parsed decimal number string.
+struct Decimal {
+    neg: bool,
+    /// Integer part, limited to [PARSE_INT_LIMIT].
+    int: u64,
+    /// Fractional part scaled by 10^[MAX_DECIMALS]. Further digits are truncated.
+    frac: u64,
+}
+
+impl Decimal {
+    /// Parse a string like `-3.1416`.
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.as_bytes();
+        let (neg, s) = match s.first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let mut int = 0;
+        let mut frac = 0;
+        let mut frac_digits = 0;
+        let mut digits = 0;
+        let mut point = false;
+        for c in s {
+            match c {
+                b'.' if !point => point = true,
+                b'0'..=b'9' => {
+                    let d = (c - b'0') as u64;
+                    if !point {
+                        int = (int * 10 + d).min(PARSE_INT_LIMIT);
+                    } else if frac_digits < MAX_DECIMALS {
+                        frac = frac * 10 + d;
+                        frac_digits += 1;
+                    }
+                    digits += 1;
+                }
+                _ => return None,
+            }
+        }
+        if digits == 0 {
+            return None;
+        }
+        let frac = frac * 10_u64.pow((MAX_DECIMALS - frac_digits) as u32);
+        Some(Self { neg, int, frac })
+    }
+
+    /// Divide `frac` by 10^[MAX_DECIMALS] and round ties away from zero.
+    fn round_frac(frac: u64) -> u64 {
+        const DIV: u64 = 10_u64.pow(MAX_DECIMALS as u32);
+        (frac + DIV / 2) / DIV
+    }
+
+    /// Apply the sign to the magnitude `mag` and saturate to [Int24].
+    fn to_int24(&self, mag: u64) -> Int24 {
+        let mag = mag.min(PARSE_INT_LIMIT) as i32;
+        Int24::from_i32(if self.neg { -mag } else { mag })
+    }
+}
+
+/// Write the decimal `mag` with a decimal point `decimals` digits from the right into `buf`.
+fn write_decimal(neg: bool, mut mag: u64, decimals: u8, buf: &mut [u8]) -> Result<&str, Error> {
+    let mut tmp = [0_u8; FORMAT_BUF_LEN];
+    let mut len = 0;
+    // The temporary buffer is always big enough.
+    // The checked access just keeps the panic paths out of the code.
+    let mut push = |c| {
+        if let Some(t) = tmp.get_mut(len) {
+            *t = c;
+            len += 1;
+        }
+    };
+    let mut digits = 0;
+    loop {
+        if digits == decimals && decimals > 0 {
+            push(b'.');
+        }
+        push(b'0' + (mag % 10) as u8);
+        mag /= 10;
+        digits += 1;
+        if mag == 0 && digits > decimals {
+            break;
+        }
+    }
+    if neg {
+        push(b'-');
+    }
+    let buf_len = buf.len();
+    let Some(buf) = buf.get_mut(..len) else {
+        return Err(Error::SliceTooShort {
+            needed: len,
+            len: buf_len,
+        });
+    };
+    for (b, t) in buf.iter_mut().zip(tmp.iter().take(len).rev()) {
+        *b = *t;
+    }
+    // SAFETY: The buffer only contains ASCII characters.
+    Ok(unsafe { core::str::from_utf8_unchecked(buf) })
+}
+
+impl Int24 {
+    /// Format `self` as decimal number with a decimal point `scale` digits from the right.
+    ///
+    /// For example the value `-12345` with a `scale` of 3 is formatted as `-12.345`.
+    /// The formatted string is written to `buf` and a reference to it is returned.
+    ///
+    /// Returns [Error::SliceTooShort], if `buf` is too small,
+    /// and [Error::InvalidScale], if `scale` is bigger than 9.
+    /// A buffer of [FORMAT_BUF_LEN] bytes is always big enough.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let mut buf = [0; avr_int24::FORMAT_BUF_LEN];
+    /// let s = Int24::from_i32(-12345).format_decimal(3, &mut buf).unwrap();
+    /// assert_eq!(s, "-12.345");
+    /// ```
+    pub fn format_decimal(self, scale: u8, buf: &mut [u8]) -> Result<&str, Error> {
+        if scale > MAX_DECIMALS {
+            return Err(Error::InvalidScale);
+        }
+        let v = self.to_i32();
+        write_decimal(v < 0, v.unsigned_abs() as u64, scale, buf)
+    }
+
+    /// Format `self` interpreted as fixed-point number with `frac_bits` fractional bits
+    /// as decimal number with `decimals` decimal places.
+    ///
+    /// The value is rounded to the nearest decimal place. Ties are rounded away from zero.
+    /// The formatted string is written to `buf` and a reference to it is returned.
+    ///
+    /// Returns [Error::SliceTooShort], if `buf` is too small,
+    /// and [Error::InvalidScale], if `frac_bits` is bigger than 23 or if `decimals` is bigger than 9.
+    /// A buffer of [FORMAT_BUF_LEN] bytes is always big enough.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let mut buf = [0; avr_int24::FORMAT_BUF_LEN];
+    /// let s = Int24::from_i32(0x1_40).format_fixed(8, 2, &mut buf).unwrap();
+    /// assert_eq!(s, "1.25");
+    /// ```
+    pub fn format_fixed(self, frac_bits: u8, decimals: u8, buf: &mut [u8]) -> Result<&str, Error> {
+        if frac_bits > 23 || decimals > MAX_DECIMALS {
+            return Err(Error::InvalidScale);
+        }
+        let v = self.to_i32();
+        let mag = v.unsigned_abs() as u64 * 10_u64.pow(decimals as u32);
+        let mag = (mag + ((1 << frac_bits) >> 1)) >> frac_bits;
+        write_decimal(v < 0 && mag != 0, mag, decimals, buf)
+    }
+
+    /// Parse a decimal number string like `-3.1416` into an [Int24]
+    /// with a decimal point `scale` digits from the right.
+    ///
+    /// For example the string `-12.345` with a `scale` of 3 is parsed as `-12345`.
+    /// Excess decimal places are rounded to the nearest value. Ties are rounded away from zero.
+    /// The result is saturated, if it does not fit into [Int24].
+    ///
+    /// Returns [Error::Parse], if the string is not a valid decimal number,
+    /// and [Error::InvalidScale], if `scale` is bigger than 9.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::parse_decimal("-12.345", 3).unwrap();
+    /// assert_eq!(a, Int24::from_i32(-12345));
+    /// ```
+    pub fn parse_decimal(s: &str, scale: u8) -> Result<Self, Error> {
+        if scale > MAX_DECIMALS {
+            return Err(Error::InvalidScale);
+        }
+        let d = Decimal::parse(s).ok_or(Error::Parse)?;
+        let mul = 10_u64.pow(scale as u32);
+        Ok(d.to_int24(d.int * mul + Decimal::round_frac(d.frac * mul)))
+    }
+
+    /// Parse a decimal number string like `-3.1416` into an [Int24]
+    /// interpreted as fixed-point number with `frac_bits` fractional bits.
+    ///
+    /// The value is rounded to the nearest fixed-point value. Ties are rounded away from zero.
+    /// The result is saturated, if it does not fit into [Int24].
+    ///
+    /// Returns [Error::Parse], if the string is not a valid decimal number,
+    /// and [Error::InvalidScale], if `frac_bits` is bigger than 23.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::parse_fixed("1.25", 8).unwrap();
+    /// assert_eq!(a, Int24::from_i32(0x1_40));
+    /// ```
+    pub fn parse_fixed(s: &str, frac_bits: u8) -> Result<Self, Error> {
+        if frac_bits > 23 {
+            return Err(Error::InvalidScale);
+        }
+        let d = Decimal::parse(s).ok_or(Error::Parse)?;
+        Ok(d.to_int24((d.int << frac_bits) + Decimal::round_frac(d.frac << frac_bits)))
+    }
+}
+
+impl<const FRAC: u8> Fix24<FRAC> {
+    /// Format this fixed-point number as decimal number with `decimals` decimal places.
+    ///
+    /// See [Int24::format_fixed].
+    pub fn format(self, decimals: u8, buf: &mut [u8]) -> Result<&str, Error> {
+        self.to_bits().format_fixed(FRAC, decimals, buf)
+    }
+
+    /// Parse a decimal number string into this fixed-point format.
+    ///
+    /// See [Int24::parse_fixed].
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        Int24::parse_fixed(s, FRAC).map(Self::from_bits)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,252 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{
+    Int24,
+    raw::{
+        conv::{i48raw_to_i64, i64_to_i24raw_sat},
+        mul24_wide,
+    },
+};
+
+/// 48 bit signed integer for wide intermediate results.
+///
+/// This type is meant for accumulators and products of [Int24] values.
+/// All operations saturate to the 48 bit range.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+pub struct Int48(i64);
+
+#[allow(clippy::should_implement_trait)]
+impl Int48 {
+    /// The smallest value that can be represented by [Int48].
+    pub const MIN: Self = Self(-0x8000_0000_0000);
+
+    /// The largest value that can be represented by [Int48].
+    pub const MAX: Self = Self(0x7FFF_FFFF_FFFF);
+
+    /// Construct a new zero [Int48].
+    pub const fn zero() -> Self {
+        Self(0)
+    }
+
+    /// Construct a new [Int48] from an [Int24].
+    pub const fn from_int24(v: Int24) -> Self {
+        Self(v.to_i32() as i64)
+    }
+
+    /// Construct a new [Int48] from a signed 32 bit integer.
+    pub const fn from_i32(v: i32) -> Self {
+        Self(v as i64)
+    }
+
+    /// Construct and saturate a new [Int48] from a signed 64 bit integer.
+    pub const fn from_i64(v: i64) -> Self {
+        if v > Self::MAX.0 {
+            Self::MAX
+        } else if v < Self::MIN.0 {
+            Self::MIN
+        } else {
+            Self(v)
+        }
+    }
+
+    /// Convert this [Int48] to a signed 64 bit integer.
+    pub const fn to_i64(self) -> i64 {
+        self.0
+    }
+
+    /// Convert and saturate this [Int48] to an [Int24].
+    pub const fn to_int24(self) -> Int24 {
+        Int24::from_raw(i64_to_i24raw_sat(self.0))
+    }
+
+    /// Arithmetically right shift `self` by `count` bits and then
+    /// convert and saturate the result to an [Int24].
+    pub const fn to_int24_shr(self, count: u8) -> Int24 {
+        self.shr(count).to_int24()
+    }
+
+    /// Multiply two [Int24] to a full 48 bit product.
+    /// This operation never saturates.
+    #[inline(never)]
+    pub fn mul_int24(a: Int24, b: Int24) -> Self {
+        Self(i48raw_to_i64(mul24_wide(a.0, b.0)))
+    }
+
+    /// Add and saturate two [Int48].
+    pub const fn add(self, other: Self) -> Self {
+        Self::from_i64(self.0 + other.0)
+    }
+
+    /// Subtract and saturate two [Int48].
+    pub const fn sub(self, other: Self) -> Self {
+        Self::from_i64(self.0 - other.0)
+    }
+
+    /// Multiply and saturate two [Int48].
+    pub const fn mul(self, other: Self) -> Self {
+        let v = self.0 as i128 * other.0 as i128;
+        if v > Self::MAX.0 as i128 {
+            Self::MAX
+        } else if v < Self::MIN.0 as i128 {
+            Self::MIN
+        } else {
+            Self(v as i64)
+        }
+    }
+
+    /// Divide and saturate two [Int48].
+    ///
+    /// Division by zero saturates to [Int48::MAX] or [Int48::MIN], depending on the sign of `self`.
+    pub const fn div(self, other: Self) -> Self {
+        match self.0.checked_div(other.0) {
+            Some(v) => Self::from_i64(v),
+            None if self.0 < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Two's complement negate and saturate `self`.
+    pub const fn neg(self) -> Self {
+        Self::from_i64(-self.0)
+    }
+
+    /// Get the saturated absolute value of `self`.
+    pub const fn abs(self) -> Self {
+        Self::from_i64(self.0.abs())
+    }
+
+    /// Arithmetically right shift `self` by `count` bits.
+    ///
+    /// Shift counts bigger than 47 are treated as 47.
+    pub const fn shr(self, count: u8) -> Self {
+        let count = if count > 47 { 47 } else { count };
+        Self(self.0 >> count)
+    }
+
+    /// Left shift and saturate `self` by `count` bits.
+    ///
+    /// Shift counts bigger than 47 are treated as 47.
+    pub const fn shl(self, count: u8) -> Self {
+        let count = if count > 47 { 47 } else { count };
+        let v = (self.0 as i128) << count;
+        if v > Self::MAX.0 as i128 {
+            Self::MAX
+        } else if v < Self::MIN.0 as i128 {
+            Self::MIN
+        } else {
+            Self(v as i64)
+        }
+    }
+
+    /// Restrict `self` to the range `min..=max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        if self.0 < min.0 {
+            min
+        } else if self.0 > max.0 {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true`, if `self` is negative.
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+}
+
+impl From<Int24> for Int48 {
+    fn from(v: Int24) -> Self {
+        Self::from_int24(v)
+    }
+}
+
+impl core::iter::Sum for Int48 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, v| acc + v)
+    }
+}
+
+impl core::ops::Add for Int48 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::add(self, other)
+    }
+}
+
+impl core::ops::AddAssign for Int48 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub for Int48 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::sub(self, other)
+    }
+}
+
+impl core::ops::SubAssign for Int48 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Mul for Int48 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::mul(self, other)
+    }
+}
+
+impl core::ops::MulAssign for Int48 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl core::ops::Div for Int48 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::div(self, other)
+    }
+}
+
+impl core::ops::DivAssign for Int48 {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl core::ops::Neg for Int48 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+impl core::ops::Shl<u8> for Int48 {
+    type Output = Self;
+
+    fn shl(self, count: u8) -> Self {
+        Self::shl(self, count)
+    }
+}
+
+impl core::ops::Shr<u8> for Int48 {
+    type Output = Self;
+
+    fn shr(self, count: u8) -> Self {
+        Self::shr(self, count)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
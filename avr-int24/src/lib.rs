@@ -8,6 +8,10 @@
 //! It is designed for use on AVR microcontrollers.
 //!
 //! No operation from this crate ever panics.
+//! This is checked at link time by `tests/no_panic.rs` for the generic backend
+//! and in the firmware build of the test program for the AVR assembly routines.
+//! Panics on saturation can be requested explicitly with the optional `strict-overflow`
+//! feature and the `strict_*` operations of the optional `strict-ops` feature.
 //!
 //! The operations don't overflow or underflow.
 //! Numeric limits are handled by saturating the result instead.
@@ -42,32 +46,98 @@
 //! assert_eq!(c.to_i32(), -30_000);
 //!
 //! // Arithmetic right shift
-//! let c = a >> 2;
+//! let c = a >> 2_u8;
 //! assert_eq!(c.to_i32(), 7_500);
 //!
 //! // Left shift
-//! let c = a << 2;
+//! let c = a << 2_u8;
 //! assert_eq!(c.to_i32(), 120_000);
 //!
-//! // Saturation
+//! // Mixed operations with i16 and i8
+//! let c = a * 2_i16 + 5_i8;
+//! assert_eq!(c.to_i32(), 60_005);
+//! ```
+//!
+//! Saturation (this panics in debug builds with the `strict-overflow` feature):
+//!
+#![cfg_attr(feature = "strict-overflow", doc = "```no_run")]
+#![cfg_attr(not(feature = "strict-overflow"), doc = "```")]
+//! use avr_int24::Int24;
+//!
+//! let a = Int24::from_i16(30_000);
+//! let b = Int24::from_i16(10_000);
 //! let c = a * b;
 //! assert_eq!(c.to_i32(), 0x7F_FFFF);
 //! let c = a * -b;
 //! assert_eq!(c.to_i32(), -0x80_0000);
 //! ```
+//!
+//! ## Crate features
+//!
+//! - `strict-overflow`: Panic, if an operation saturates.
+//!   This is only effective in debug builds (`debug_assertions`) and is meant for catching
+//!   overflow bugs during host testing.
+//!   Release builds keep the non-panicking saturating behavior.
+//! - `strict-ops`: Enable the `strict_*` operations (`Int24::strict_add`, ...),
+//!   which panic, if the result does not fit.
+//!   They are meant for host side tooling and tests.
+//! - `ffi`: Export the core operations with the C ABI (module `ffi`).
+//!   The C declarations are in `include/avr_int24.h`.
+//! - `float`: Enable the `f32` and `f64` conversions (`Int24::from_f32`, ...)
+//!   with a selectable rounding mode.
+//! - `clobber-test`: Enable the on-target self-test of the register usage
+//!   of the assembly routines (module `clobber`, only on AVR).
+//! - `exhaustive-test`: Build the exhaustive differential tests for host testing.
+//!   Run them with `cargo test --release --features exhaustive-test`.
 
 #![cfg_attr(not(test), no_std)]
 #![cfg_attr(target_arch = "avr", feature(asm_experimental_arch))]
 
-pub use crate::raw::Int24Raw;
+#[cfg(feature = "float")]
+pub use crate::float::Rounding;
 use crate::raw::{
-    abs24, add24,
+    abs24, add24, add24_in_place, add24_lo,
     conv::{
         cast_i24raw_to_i8, cast_i24raw_to_i16, i8_to_i24raw, i16_to_i24raw, i24raw_to_i8_sat,
-        i24raw_to_i16_sat, i24raw_to_i32, i32_to_i24raw_sat,
+        i24raw_to_i16_sat, i24raw_to_i32, i32_to_i24raw_sat, i64_to_i24raw_sat,
     },
-    div24, eq24, ge24, mul24, neg24, raw_zero, shl24, shl24_by8, shl24_by8_div24, shl24_by16,
-    shr24, shr24_by8, shr24_by16, sub24,
+    div24, div24_u8, eq24, ge24, isqrt24, mul24, mul24_const, mul24_i16, mul24_lo, mul24_shr,
+    mul24_u8, neg24, raw_max, raw_min, raw_zero, shl24, shl24_by8, shl24_by8_div24, shl24_by16,
+    shl24_div24, shr24, shr24_by8, shr24_by16, sub24, sub24_in_place,
+};
+pub use crate::{
+    angle::Angle24,
+    calibration::Calibration,
+    cell::Int24Cell,
+    complex::Complex24,
+    control::{Hysteresis, SlewLimiter},
+    divisor::DivisorInt24,
+    error::Error,
+    filter::{Biquad24, Ema24, Fir24},
+    fixed::{Fix24, Frac24, I8F16, I16F8},
+    flags::Int24Flags,
+    format::FORMAT_BUF_LEN,
+    int48::Int48,
+    lut::Lut24,
+    matrix::Mat3x3,
+    nco::Nco24,
+    packed::{
+        ByteOrder, Int24Iter, Int24Slice, Int24SliceMut, Int24Writer, add_buffers, decode_be_slice,
+        decode_le_slice, encode_be_slice, encode_le_slice, neg_buffer, sub_buffers,
+    },
+    persist::PERSIST_LEN,
+    pid::Pid24,
+    poly::poly_eval,
+    progmem::Int24ProgMem,
+    random::XorShift24,
+    ratio::Ratio24,
+    raw::Int24Raw,
+    search::bisect,
+    selftest::{SelfTestFailure, SelfTestOp},
+    traits::SaturatingInt,
+    vector::{Vec2, Vec3},
+    volatile::VolatileInt24,
+    wrapping::{SaturatingInt24, WrappingInt24},
 };
 
 #[cfg(not(target_arch = "avr"))]
@@ -83,7 +153,71 @@ use asm_avr as asm;
 #[cfg(any(feature = "__internal_test__", test))]
 pub mod unit_tests;
 
+#[cfg(all(
+    test,
+    feature = "exhaustive-test",
+    not(all(feature = "strict-overflow", debug_assertions))
+))]
+mod exhaustive_tests;
+#[cfg(kani)]
+mod proofs;
+
+pub mod adc;
+mod angle;
+mod audio;
+mod bits;
+mod calibration;
+mod cell;
+#[cfg(all(feature = "clobber-test", target_arch = "avr"))]
+pub mod clobber;
+mod complex;
+mod control;
+mod ct;
+mod divisor;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod filter;
+mod fixed;
+mod flags;
+mod float;
+mod format;
+mod int48;
+mod lut;
+mod matrix;
+pub mod modbus;
+mod nco;
+mod packed;
+mod persist;
+mod pid;
+mod poly;
+mod progmem;
+mod random;
+mod ratio;
 mod raw;
+mod scale;
+mod search;
+mod selftest;
+pub mod stats;
+#[cfg(feature = "strict-ops")]
+mod strict;
+mod table;
+mod traits;
+mod trig;
+mod vector;
+pub mod volatile;
+mod wrapping;
+
+/// Panic, if the flagged operation saturated and the `strict-overflow` feature is active.
+macro_rules! strict_overflow_check {
+    ($flagged:expr) => {
+        #[cfg(all(feature = "strict-overflow", debug_assertions))]
+        {
+            let (_, saturated) = $flagged;
+            assert!(!saturated, "Int24 operation saturated");
+        }
+    };
+}
 
 /// Shorthand for [Int24].
 pub type I24 = Int24;
@@ -95,6 +229,12 @@ pub struct Int24(Int24Raw);
 
 #[allow(clippy::should_implement_trait)]
 impl Int24 {
+    /// The smallest value that can be represented by [Int24].
+    pub const MIN: Self = Self(raw_min());
+
+    /// The largest value that can be represented by [Int24].
+    pub const MAX: Self = Self(raw_max());
+
     /// Construct a new zero [Int24].
     pub const fn zero() -> Self {
         Self(raw_zero())
@@ -115,6 +255,50 @@ impl Int24 {
         Self::from_raw((bytes[0], bytes[1], bytes[2]))
     }
 
+    /// Construct a new [Int24] from raw big endian bytes.
+    pub const fn from_be_bytes(bytes: [u8; 3]) -> Self {
+        Self::from_raw((bytes[2], bytes[1], bytes[0]))
+    }
+
+    /// Construct a new [Int24] from the first 3 little endian bytes of `bytes`.
+    ///
+    /// Additional bytes are ignored.
+    /// Returns [Error::SliceTooShort], if `bytes` is shorter than 3 bytes.
+    ///
+    /// ```
+    /// use avr_int24::{Error, Int24};
+    ///
+    /// let frame = [0x01, 0x02, 0x03, 0xAA];
+    /// assert_eq!(Int24::try_from_le_slice(&frame), Ok(Int24::from_i32(0x03_0201)));
+    /// assert_eq!(
+    ///     Int24::try_from_le_slice(&frame[3..]),
+    ///     Err(Error::SliceTooShort { needed: 3, len: 1 })
+    /// );
+    /// ```
+    pub const fn try_from_le_slice(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes {
+            [b0, b1, b2, ..] => Ok(Self::from_le_bytes([*b0, *b1, *b2])),
+            _ => Err(Error::SliceTooShort {
+                needed: 3,
+                len: bytes.len(),
+            }),
+        }
+    }
+
+    /// Construct a new [Int24] from the first 3 big endian bytes of `bytes`.
+    ///
+    /// Additional bytes are ignored.
+    /// Returns [Error::SliceTooShort], if `bytes` is shorter than 3 bytes.
+    pub const fn try_from_be_slice(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes {
+            [b0, b1, b2, ..] => Ok(Self::from_be_bytes([*b0, *b1, *b2])),
+            _ => Err(Error::SliceTooShort {
+                needed: 3,
+                len: bytes.len(),
+            }),
+        }
+    }
+
     /// Construct a new [Int24] from a signed 8 bit integer.
     pub const fn from_i8(v: i8) -> Self {
         Self::from_raw(i8_to_i24raw(v))
@@ -130,11 +314,61 @@ impl Int24 {
         Self(i32_to_i24raw_sat(v))
     }
 
+    /// View a slice of [Int24] as a slice of raw tuples.
+    pub(crate) const fn as_raw_slice(data: &[Self]) -> &[Int24Raw] {
+        // SAFETY: Int24 is a transparent wrapper around Int24Raw.
+        unsafe { core::slice::from_raw_parts(data.as_ptr().cast(), data.len()) }
+    }
+
     /// Convert this [Int24] to little endian bytes.
     pub const fn to_le_bytes(self) -> [u8; 3] {
         [self.0.0, self.0.1, self.0.2]
     }
 
+    /// Convert this [Int24] to big endian bytes.
+    pub const fn to_be_bytes(self) -> [u8; 3] {
+        [self.0.2, self.0.1, self.0.0]
+    }
+
+    /// Write this [Int24] as little endian bytes to the first 3 bytes of `buf`.
+    ///
+    /// Additional bytes are not modified.
+    /// Returns [Error::SliceTooShort] and writes nothing, if `buf` is shorter than 3 bytes.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let mut frame = [0xAA; 4];
+    /// Int24::from_i32(0x03_0201).write_le_to(&mut frame[1..]).unwrap();
+    /// assert_eq!(frame, [0xAA, 0x01, 0x02, 0x03]);
+    /// assert!(Int24::zero().write_le_to(&mut frame[2..]).is_err());
+    /// ```
+    pub const fn write_le_to(self, buf: &mut [u8]) -> Result<(), Error> {
+        let len = buf.len();
+        match buf {
+            [b0, b1, b2, ..] => {
+                [*b0, *b1, *b2] = self.to_le_bytes();
+                Ok(())
+            }
+            _ => Err(Error::SliceTooShort { needed: 3, len }),
+        }
+    }
+
+    /// Write this [Int24] as big endian bytes to the first 3 bytes of `buf`.
+    ///
+    /// Additional bytes are not modified.
+    /// Returns [Error::SliceTooShort] and writes nothing, if `buf` is shorter than 3 bytes.
+    pub const fn write_be_to(self, buf: &mut [u8]) -> Result<(), Error> {
+        let len = buf.len();
+        match buf {
+            [b0, b1, b2, ..] => {
+                [*b0, *b1, *b2] = self.to_be_bytes();
+                Ok(())
+            }
+            _ => Err(Error::SliceTooShort { needed: 3, len }),
+        }
+    }
+
     /// Convert and saturate this [Int24] to a signed 8 bit integer.
     pub const fn to_i8(self) -> i8 {
         i24raw_to_i8_sat(self.0)
@@ -150,6 +384,39 @@ impl Int24 {
         i24raw_to_i32(self.0)
     }
 
+    /// Convert this [Int24] to an unsigned 8 bit integer.
+    ///
+    /// Returns `None`, if the value is negative or bigger than `u8::MAX`.
+    pub const fn to_u8_checked(self) -> Option<u8> {
+        let v = self.to_i32();
+        if v >= 0 && v <= u8::MAX as i32 {
+            Some(v as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Convert this [Int24] to a `usize`, e.g. for indexing a slice.
+    ///
+    /// Returns `None`, if the value is negative or bigger than `usize::MAX`.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let table = [10, 20, 30];
+    /// let i = Int24::from_i16(2);
+    /// assert_eq!(i.to_usize_checked().and_then(|i| table.get(i)), Some(&30));
+    /// assert_eq!(Int24::from_i16(-1).to_usize_checked(), None);
+    /// ```
+    pub const fn to_usize_checked(self) -> Option<usize> {
+        let v = self.to_i32();
+        if v >= 0 && v as u64 <= usize::MAX as u64 {
+            Some(v as usize)
+        } else {
+            None
+        }
+    }
+
     /// Cast this [Int24] to a signed 8 bit integer *without* *saturation*.
     pub const fn cast_to_i8(self) -> i8 {
         cast_i24raw_to_i8(self.0)
@@ -163,6 +430,7 @@ impl Int24 {
     /// Add and saturate two [Int24].
     #[inline(never)]
     pub fn add(self, other: Self) -> Self {
+        strict_overflow_check!(self.add_flagged(other));
         Self::from_raw(add24(self.0, other.0))
     }
 
@@ -175,9 +443,25 @@ impl Int24 {
         Self::from_i32(self.to_i32() + other.to_i32())
     }
 
+    /// Add two [Int24] without saturation.
+    ///
+    /// This omits the overflow handling of [Int24::add].
+    /// With the `strict-overflow` feature in debug builds the precondition is checked.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the sum fits into [Int24].
+    /// Otherwise, the result is unspecified.
+    #[inline(always)]
+    pub unsafe fn unchecked_add(self, other: Self) -> Self {
+        strict_overflow_check!(self.add_flagged(other));
+        Self::from_raw(add24_lo(self.0, other.0))
+    }
+
     /// Subtract and saturate two [Int24].
     #[inline(never)]
     pub fn sub(self, other: Self) -> Self {
+        strict_overflow_check!(self.sub_flagged(other));
         Self::from_raw(sub24(self.0, other.0))
     }
 
@@ -190,9 +474,89 @@ impl Int24 {
         Self::from_i32(self.to_i32() - other.to_i32())
     }
 
+    /// Add the unsigned offset `other` to `self` and saturate the result.
+    ///
+    /// Unlike converting the offset to [Int24] first, the offset is not saturated
+    /// to [Int24::MAX] before the addition.
+    /// Pass a `u16` offset with `.into()`.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::MIN.saturating_add_unsigned(0xFF_FFFF);
+    /// assert_eq!(a, Int24::MAX);
+    /// let b = Int24::from_i16(-1).saturating_add_unsigned(u16::MAX.into());
+    /// assert_eq!(b, Int24::from_i32(0xFFFE));
+    /// ```
+    pub const fn saturating_add_unsigned(self, other: u32) -> Self {
+        // Offsets of 24 bits and more saturate any value.
+        let other = if other > 0xFF_FFFF { 0xFF_FFFF } else { other };
+        Self::from_i32(self.to_i32() + other as i32)
+    }
+
+    /// Subtract the unsigned offset `other` from `self` and saturate the result.
+    ///
+    /// Unlike converting the offset to [Int24] first, the offset is not saturated
+    /// to [Int24::MAX] before the subtraction.
+    /// Pass a `u16` offset with `.into()`.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::MAX.saturating_sub_unsigned(0xFF_FFFF);
+    /// assert_eq!(a, Int24::MIN);
+    /// let b = Int24::zero().saturating_sub_unsigned(u32::MAX);
+    /// assert_eq!(b, Int24::MIN);
+    /// ```
+    pub const fn saturating_sub_unsigned(self, other: u32) -> Self {
+        // Offsets of 24 bits and more saturate any value.
+        let other = if other > 0xFF_FFFF { 0xFF_FFFF } else { other };
+        Self::from_i32(self.to_i32() - other as i32)
+    }
+
+    /// Add `other` to `self` in place and saturate.
+    ///
+    /// This operates directly on the memory of `self`.
+    /// For an accumulator that lives in RAM this saves the register moves
+    /// around the call of `self += other`.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let mut acc = Int24::from_i32(0x7F_FF00);
+    /// acc.add_in_place(Int24::from_i16(0x80));
+    /// assert_eq!(acc, Int24::from_i32(0x7F_FF80));
+    /// ```
+    ///
+    /// Saturation panics in debug builds with the `strict-overflow` feature:
+    ///
+    #[cfg_attr(feature = "strict-overflow", doc = "```no_run")]
+    #[cfg_attr(not(feature = "strict-overflow"), doc = "```")]
+    /// use avr_int24::Int24;
+    ///
+    /// let mut acc = Int24::from_i32(0x7F_FF80);
+    /// acc.add_in_place(Int24::from_i16(0x80));
+    /// assert_eq!(acc, Int24::MAX);
+    /// ```
+    #[inline(never)]
+    pub fn add_in_place(&mut self, other: Self) {
+        strict_overflow_check!(self.add_flagged(other));
+        add24_in_place(&mut self.0, other.0);
+    }
+
+    /// Subtract `other` from `self` in place and saturate.
+    ///
+    /// See [Int24::add_in_place].
+    #[inline(never)]
+    pub fn sub_in_place(&mut self, other: Self) {
+        strict_overflow_check!(self.sub_flagged(other));
+        sub24_in_place(&mut self.0, other.0);
+    }
+
     /// Multiply and saturate two [Int24].
     #[inline(never)]
     pub fn mul(self, other: Self) -> Self {
+        strict_overflow_check!(self.mul_flagged(other));
         Self::from_raw(mul24(self.0, other.0))
     }
 
@@ -205,12 +569,153 @@ impl Int24 {
         Self::from_i32(self.to_i32() * other.to_i32())
     }
 
+    /// Multiply two [Int24] without saturation.
+    ///
+    /// This omits the zero, sign and overflow handling of [Int24::mul]
+    /// and only runs the plain multiplication loop.
+    /// With the `strict-overflow` feature in debug builds the precondition is checked.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::from_i16(-1000);
+    /// // SAFETY: The product of two 11 bit values fits.
+    /// let b = unsafe { a.unchecked_mul(Int24::from_i16(1000)) };
+    /// assert_eq!(b, Int24::from_i32(-1_000_000));
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that the product fits into [Int24].
+    /// Otherwise, the result is unspecified.
+    #[inline(never)]
+    pub unsafe fn unchecked_mul(self, other: Self) -> Self {
+        strict_overflow_check!(self.mul_flagged(other));
+        Self::from_raw(mul24_lo(self.0, other.0))
+    }
+
+    /// Multiply two [Int24] and arithmetically right shift the product by `count` bits.
+    /// The result is saturated to signed 24 bit.
+    ///
+    /// The intermediate product is kept as 48 bits, so it doesn't have to be saturated.
+    /// The shifted product is rounded towards negative infinity.
+    #[inline(never)]
+    pub fn mul_shr(self, other: Self, count: u8) -> Self {
+        strict_overflow_check!(self.mul_shr_flagged(other, count));
+        Self::from_raw(mul24_shr(self.0, other.0, count))
+    }
+
+    /// Multiply two [Int24] and arithmetically right shift the product by `count` bits.
+    /// The result is saturated to signed 24 bit.
+    /// This is the `const` variant.
+    ///
+    /// Only call this from `const` context.
+    /// From non-`const` context call [Int24::mul_shr] instead to get optimized code.
+    pub const fn const_mul_shr(self, other: Self, count: u8) -> Self {
+        let count = if count > 47 { 47 } else { count };
+        let v = (self.to_i32() as i64 * other.to_i32() as i64) >> count;
+        Self(i64_to_i24raw_sat(v))
+    }
+
+    /// Multiply `self` by the constant `K` and saturate.
+    ///
+    /// The multiplication is expanded into a sequence of shifts and additions at compile time.
+    /// For small constants this is much faster than the generic [Int24::mul].
+    #[inline(always)]
+    pub fn mul_const<const K: i16>(self) -> Self {
+        strict_overflow_check!(self.mul_flagged(Self::from_i16(K)));
+        Self::from_raw(mul24_const::<K>(self.0))
+    }
+
+    /// Multiply `self` by the signed 16 bit `other` and saturate.
+    ///
+    /// This is faster than the generic [Int24::mul],
+    /// because the multiplication loop only needs 16 iterations.
+    #[inline(never)]
+    pub fn mul_i16(self, other: i16) -> Self {
+        strict_overflow_check!(self.mul_flagged(Self::from_i16(other)));
+        Self::from_raw(mul24_i16(self.0, other))
+    }
+
+    /// Multiply `self` by the unsigned 8 bit `other` and saturate.
+    ///
+    /// This is much faster than the generic [Int24::mul],
+    /// because the multiplication loop only needs 8 iterations.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i16(-1000).mul_u8(200), Int24::from_i32(-200_000));
+    /// ```
+    ///
+    /// Saturation panics in debug builds with the `strict-overflow` feature:
+    ///
+    #[cfg_attr(feature = "strict-overflow", doc = "```no_run")]
+    #[cfg_attr(not(feature = "strict-overflow"), doc = "```")]
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::MAX.mul_u8(2), Int24::MAX);
+    /// ```
+    #[inline(never)]
+    pub fn mul_u8(self, other: u8) -> Self {
+        strict_overflow_check!(self.mul_flagged(Self::from_i16(other as i16)));
+        Self::from_raw(mul24_u8(self.0, other))
+    }
+
+    /// Divide `self` by the unsigned 8 bit `other` and saturate.
+    ///
+    /// The quotient is rounded towards zero.
+    /// Division by zero saturates to [Int24::MAX] or [Int24::MIN], depending on the sign of `self`.
+    ///
+    /// This is faster than the generic [Int24::div],
+    /// because the division loop only needs an 8 bit remainder.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_i32(-100_000).div_u8(7), Int24::from_i32(-14_285));
+    /// ```
+    #[inline(never)]
+    pub fn div_u8(self, other: u8) -> Self {
+        strict_overflow_check!(self.div_flagged(Self::from_i16(other as i16)));
+        Self::from_raw(div24_u8(self.0, other))
+    }
+
     /// Divide and saturate two [Int24].
     #[inline(never)]
     pub fn div(self, other: Self) -> Self {
+        strict_overflow_check!(self.div_flagged(other));
         Self::from_raw(div24(self.0, other.0))
     }
 
+    /// Divide and saturate two [Int24].
+    ///
+    /// Returns `None`, if `other` is zero.
+    /// The overflow of `MIN / -1` is saturated.
+    #[inline(always)]
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.0 == raw_zero() {
+            None
+        } else {
+            Some(self.div(other))
+        }
+    }
+
+    /// Divide and saturate two [Int24].
+    ///
+    /// If `other` is zero, then the result is the return value of the `on_div_by_zero` hook.
+    /// The hook is called with `self` as argument.
+    #[inline(always)]
+    pub fn div_or_else<F>(self, other: Self, on_div_by_zero: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        match self.checked_div(other) {
+            Some(v) => v,
+            None => on_div_by_zero(self),
+        }
+    }
+
     /// Divide and saturate two [Int24].
     /// This is the `const` variant.
     ///
@@ -228,9 +733,37 @@ impl Int24 {
     /// so it doesn't have to be saturated.
     #[inline(never)]
     pub fn shl8div(self, other: Self) -> Self {
+        strict_overflow_check!(self.shl8div_flagged(other));
         Self::from_raw(shl24_by8_div24(self.0, other.0))
     }
 
+    /// Left shift `self` by `count` bits and then divide the shifted value by `other`.
+    /// The result is saturated to signed 24 bit.
+    /// The intermediate left shift is *not* saturated.
+    /// Shift counts bigger than 24 are limited to 24.
+    ///
+    /// The shifted intermediate value is kept as 64 bits,
+    /// so it doesn't have to be saturated.
+    /// For a shift count of 8 prefer [Int24::shl8div], which is faster.
+    #[inline(never)]
+    pub fn shl_div(self, other: Self, count: u8) -> Self {
+        strict_overflow_check!(self.shl_div_flagged(other, count));
+        Self::from_raw(shl24_div24(self.0, other.0, count))
+    }
+
+    /// Left shift `self` by 8 bits and then divide the shifted value by `other`.
+    /// The result is saturated to signed 24 bit.
+    ///
+    /// Returns `None`, if `other` is zero.
+    #[inline(always)]
+    pub fn checked_shl8div(self, other: Self) -> Option<Self> {
+        if other.0 == raw_zero() {
+            None
+        } else {
+            Some(self.shl8div(other))
+        }
+    }
+
     /// Left shift `self` by 8 bits and then divide the shifted value by `other`.
     /// The result is saturated to signed 24 bit.
     /// The intermediate left shift by 8 bits is *not* saturated.
@@ -242,9 +775,32 @@ impl Int24 {
         Self::from_i32((self.to_i32() << 8) / other.to_i32())
     }
 
+    /// Left shift `self` by `count` bits and then divide the shifted value by `other`.
+    /// The result is saturated to signed 24 bit.
+    /// This is the `const` variant.
+    ///
+    /// Only call this from `const` context.
+    /// From non-`const` context call [Int24::shl_div] instead to get optimized code.
+    pub const fn const_shl_div(self, other: Self, count: u8) -> Self {
+        let count = if count > 24 { 24 } else { count };
+        let b = other.to_i32();
+        if b == 0 {
+            if self.to_i32() < 0 {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        } else {
+            Self(i64_to_i24raw_sat(
+                ((self.to_i32() as i64) << count) / b as i64,
+            ))
+        }
+    }
+
     /// Two's complement negate and saturate `self`.
     #[inline(never)]
     pub fn neg(self) -> Self {
+        strict_overflow_check!(self.neg_flagged());
         Self(neg24(self.0))
     }
 
@@ -260,6 +816,7 @@ impl Int24 {
     /// Get the saturated absolute value of `self`.
     #[inline(never)]
     pub fn abs(self) -> Self {
+        strict_overflow_check!(self.abs_flagged());
         Self(abs24(self.0))
     }
 
@@ -276,6 +833,28 @@ impl Int24 {
         }
     }
 
+    /// Get the integer square root of `self`, rounded down.
+    /// Negative values result in zero.
+    #[inline(never)]
+    pub fn isqrt(self) -> Self {
+        Self::from_raw(isqrt24(self.0))
+    }
+
+    /// Get the integer square root of `self`, rounded down.
+    /// Negative values result in zero.
+    /// This is the `const` variant.
+    ///
+    /// Only call this from `const` context.
+    /// From non-`const` context call [Int24::isqrt] instead to get optimized code.
+    pub const fn const_isqrt(self) -> Self {
+        let v = self.to_i32();
+        if v < 0 {
+            Self::zero()
+        } else {
+            Self::from_i32((v as u32).isqrt() as i32)
+        }
+    }
+
     /// Left shift `self` by 8 bits.
     ///
     /// This operation does not saturate the result.
@@ -387,6 +966,20 @@ impl core::cmp::PartialOrd for Int24 {
     }
 }
 
+impl core::iter::Sum for Int24 {
+    /// Sum up all elements with a wide accumulator and saturate the result.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.map(Int48::from_int24).sum::<Int48>().to_int24()
+    }
+}
+
+impl<'a> core::iter::Sum<&'a Int24> for Int24 {
+    /// Sum up all elements with a wide accumulator and saturate the result.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl core::ops::Add for Int24 {
     type Output = Self;
 
@@ -479,11 +1072,212 @@ impl core::ops::ShrAssign<u8> for Int24 {
     }
 }
 
+/// Implement the binary operator trait `$imp::$method` and the assignment operator trait
+/// `$imp_assign::$method_assign` for all combinations of [Int24] references and values.
+macro_rules! impl_ref_ops {
+    ($imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident) => {
+        impl core::ops::$imp<&Int24> for Int24 {
+            type Output = Int24;
+
+            fn $method(self, other: &Int24) -> Int24 {
+                core::ops::$imp::$method(self, *other)
+            }
+        }
+
+        impl core::ops::$imp<Int24> for &Int24 {
+            type Output = Int24;
+
+            fn $method(self, other: Int24) -> Int24 {
+                core::ops::$imp::$method(*self, other)
+            }
+        }
+
+        impl core::ops::$imp<&Int24> for &Int24 {
+            type Output = Int24;
+
+            fn $method(self, other: &Int24) -> Int24 {
+                core::ops::$imp::$method(*self, *other)
+            }
+        }
+
+        impl core::ops::$imp_assign<&Int24> for Int24 {
+            fn $method_assign(&mut self, other: &Int24) {
+                core::ops::$imp_assign::$method_assign(self, *other);
+            }
+        }
+    };
+}
+
+impl_ref_ops!(Add, add, AddAssign, add_assign);
+impl_ref_ops!(Sub, sub, SubAssign, sub_assign);
+impl_ref_ops!(Mul, mul, MulAssign, mul_assign);
+impl_ref_ops!(Div, div, DivAssign, div_assign);
+
+impl core::ops::Neg for &Int24 {
+    type Output = Int24;
+
+    fn neg(self) -> Int24 {
+        -*self
+    }
+}
+
+/// Implement the shift operator traits for the shift count type `$t`.
+///
+/// Left shift counts of 24 and more shift out all bits and return zero.
+/// Right shift counts of 24 and more are treated as 23 and return the sign.
+/// Negative shift counts don't shift.
+///
+/// With several shift count types an unsuffixed literal count, e.g. in `a >> 2`,
+/// might need a type suffix like `a >> 2_u8`, if the result type has to be inferred.
+macro_rules! impl_shift_ops {
+    ($t:ty) => {
+        impl core::ops::Shl<$t> for Int24 {
+            type Output = Self;
+
+            fn shl(self, other: $t) -> Self {
+                let count = other.clamp(0, 24) as u8;
+                if count >= 24 {
+                    Self::zero()
+                } else {
+                    Self::shl(self, count)
+                }
+            }
+        }
+
+        impl core::ops::ShlAssign<$t> for Int24 {
+            fn shl_assign(&mut self, other: $t) {
+                self.0 = (*self << other).0;
+            }
+        }
+
+        impl core::ops::Shr<$t> for Int24 {
+            type Output = Self;
+
+            fn shr(self, other: $t) -> Self {
+                Self::shr(self, other.clamp(0, 23) as u8)
+            }
+        }
+
+        impl core::ops::ShrAssign<$t> for Int24 {
+            fn shr_assign(&mut self, other: $t) {
+                self.0 = (*self >> other).0;
+            }
+        }
+    };
+}
+
+impl_shift_ops!(u32);
+impl_shift_ops!(usize);
+impl_shift_ops!(i32);
+
+/// Implement the arithmetic operator traits between [Int24] and the primitive integer `$t`.
+///
+/// The primitive operand is converted to [Int24] with `$from` before the operation.
+/// The multiplication uses the faster [Int24::mul_i16].
+macro_rules! impl_scalar_ops {
+    ($t:ty, $from:ident) => {
+        impl core::ops::Add<$t> for Int24 {
+            type Output = Self;
+
+            fn add(self, other: $t) -> Self {
+                self + Self::$from(other)
+            }
+        }
+
+        impl core::ops::AddAssign<$t> for Int24 {
+            fn add_assign(&mut self, other: $t) {
+                *self = *self + other;
+            }
+        }
+
+        impl core::ops::Sub<$t> for Int24 {
+            type Output = Self;
+
+            fn sub(self, other: $t) -> Self {
+                self - Self::$from(other)
+            }
+        }
+
+        impl core::ops::SubAssign<$t> for Int24 {
+            fn sub_assign(&mut self, other: $t) {
+                *self = *self - other;
+            }
+        }
+
+        impl core::ops::Mul<$t> for Int24 {
+            type Output = Self;
+
+            fn mul(self, other: $t) -> Self {
+                self.mul_i16(other.into())
+            }
+        }
+
+        impl core::ops::MulAssign<$t> for Int24 {
+            fn mul_assign(&mut self, other: $t) {
+                *self = *self * other;
+            }
+        }
+
+        impl core::ops::Div<$t> for Int24 {
+            type Output = Self;
+
+            fn div(self, other: $t) -> Self {
+                self / Self::$from(other)
+            }
+        }
+
+        impl core::ops::DivAssign<$t> for Int24 {
+            fn div_assign(&mut self, other: $t) {
+                *self = *self / other;
+            }
+        }
+
+        impl core::ops::Add<Int24> for $t {
+            type Output = Int24;
+
+            fn add(self, other: Int24) -> Int24 {
+                Int24::$from(self) + other
+            }
+        }
+
+        impl core::ops::Sub<Int24> for $t {
+            type Output = Int24;
+
+            fn sub(self, other: Int24) -> Int24 {
+                Int24::$from(self) - other
+            }
+        }
+
+        impl core::ops::Mul<Int24> for $t {
+            type Output = Int24;
+
+            fn mul(self, other: Int24) -> Int24 {
+                other.mul_i16(self.into())
+            }
+        }
+
+        impl core::ops::Div<Int24> for $t {
+            type Output = Int24;
+
+            fn div(self, other: Int24) -> Int24 {
+                Int24::$from(self) / other
+            }
+        }
+    };
+}
+
+impl_scalar_ops!(i8, from_i8);
+impl_scalar_ops!(i16, from_i16);
+
 #[cfg(test)]
 mod test {
     use crate::unit_tests;
+    use std::{cell::Cell, time::Instant};
 
-    struct TestRunner {}
+    #[derive(Default)]
+    struct TestRunner {
+        start: Cell<Option<Instant>>,
+    }
 
     impl unit_tests::TestOps for TestRunner {
         fn print(&self, text: &str) {
@@ -502,15 +1296,73 @@ mod test {
             if ok {
                 println!("line {line}: Ok");
             } else {
-                panic!("line {line}: FAILED");
+                println!("line {line}: FAILED");
             }
         }
+
+        fn time_start(&self) {
+            self.start.set(Some(Instant::now()));
+        }
+
+        /// Get the duration of the test in microseconds.
+        fn time_end(&self) -> Option<u32> {
+            let start = self.start.take()?;
+            Some(start.elapsed().as_micros().try_into().unwrap_or(u32::MAX))
+        }
     }
 
+    #[cfg(not(all(feature = "strict-overflow", debug_assertions)))]
     #[test]
     fn test_int24() {
-        let t = TestRunner {};
-        unit_tests::run_tests(&t);
+        let t = TestRunner::default();
+        let s = unit_tests::run_tests(&t);
+        assert!(s.ok());
+        assert!(s.tests > 0 && s.passed > 0);
+    }
+
+    #[cfg(not(all(feature = "strict-overflow", debug_assertions)))]
+    #[test]
+    fn test_int24_filtered() {
+        let t = TestRunner::default();
+        let s = unit_tests::run_tests_filtered(&t, "base");
+        assert!(s.ok() && s.tests == 1);
+        let s = unit_tests::run_tests_filtered(&t, "conv_*");
+        assert!(s.ok() && s.tests > 1);
+        let s = unit_tests::run_tests_filtered(&t, "conv_");
+        assert!(s.ok() && s.tests == 0);
+    }
+
+    /// Run each unit test on its own and skip the tests that panic, because they saturate.
+    #[cfg(all(feature = "strict-overflow", debug_assertions))]
+    #[test]
+    fn test_int24_strict_overflow() {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+
+        let t = TestRunner::default();
+        let mut passed = 0;
+        for name in unit_tests::test_names() {
+            match catch_unwind(AssertUnwindSafe(|| {
+                unit_tests::run_tests_filtered(&t, name)
+            })) {
+                Ok(s) => {
+                    assert!(s.ok() && s.tests == 1, "test {name} failed");
+                    passed += 1;
+                }
+                Err(payload) => {
+                    let msg = payload.downcast_ref::<&str>().copied().unwrap_or_default();
+                    assert_eq!(msg, "Int24 operation saturated", "test {name} panicked");
+                }
+            }
+        }
+        assert!(passed > 0);
+    }
+
+    #[cfg(all(feature = "strict-overflow", debug_assertions))]
+    #[test]
+    #[should_panic(expected = "Int24 operation saturated")]
+    fn test_strict_overflow() {
+        let a = crate::Int24::from_i32(0x7F_FFFF);
+        let _ = a + crate::Int24::from_i32(1);
     }
 }
 
@@ -0,0 +1,58 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::Int24;
+
+/// Lookup table with linear interpolation between breakpoints.
+///
+/// The table consists of `(x, y)` breakpoints sorted by ascending `x`.
+/// Lookups between two breakpoints are linearly interpolated.
+/// Lookups outside of the table are clamped to the first or last `y`.
+///
+/// ```
+/// use avr_int24::{Int24, Lut24};
+///
+/// const TABLE: [(Int24, Int24); 3] = [
+///     (Int24::from_i16(0), Int24::from_i16(0)),
+///     (Int24::from_i16(100), Int24::from_i16(1000)),
+///     (Int24::from_i16(200), Int24::from_i16(1500)),
+/// ];
+/// let lut = Lut24::new(&TABLE);
+/// assert_eq!(lut.lookup(Int24::from_i16(50)), Int24::from_i16(500));
+/// assert_eq!(lut.lookup(Int24::from_i16(150)), Int24::from_i16(1250));
+/// assert_eq!(lut.lookup(Int24::from_i16(300)), Int24::from_i16(1500));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Lut24<'a> {
+    points: &'a [(Int24, Int24)],
+}
+
+impl<'a> Lut24<'a> {
+    /// Construct a new lookup table from `(x, y)` breakpoints.
+    ///
+    /// The breakpoints must be sorted by ascending `x`.
+    pub const fn new(points: &'a [(Int24, Int24)]) -> Self {
+        Self { points }
+    }
+
+    /// Get the breakpoints.
+    pub const fn points(&self) -> &'a [(Int24, Int24)] {
+        self.points
+    }
+
+    /// Look up `x` in the table and interpolate linearly.
+    ///
+    /// An empty table always returns zero.
+    pub fn lookup(&self, x: Int24) -> Int24 {
+        // Index of the first breakpoint with an x that is bigger than or equal to x.
+        let i = self.points.partition_point(|p| p.0 < x);
+        match (self.points.get(i.wrapping_sub(1)), self.points.get(i)) {
+            (Some(&(x0, y0)), Some(&(x1, y1))) => x.map_range(x0, x1, y0, y1),
+            (Some(&(_, y)), None) | (None, Some(&(_, y))) => y,
+            (None, None) => Int24::zero(),
+        }
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
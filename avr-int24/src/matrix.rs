@@ -0,0 +1,120 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Fix24, Int24, Int48, Vec3};
+
+/// 3x3 matrix of fixed-point [Fix24] values with `FRAC` fractional bits.
+///
+/// The elements are stored in row-major order.
+/// This is meant for applying soft-iron and misalignment calibration matrices
+/// to 24 bit sensor vectors.
+///
+/// The products are accumulated with 48 bits.
+/// The results are rounded towards negative infinity and saturated.
+///
+/// ```
+/// use avr_int24::{Fix24, Int24, Mat3x3, Vec3};
+///
+/// // Q2.21 matrix that scales X by 1.5 and swaps Y and Z.
+/// let one = Int24::from_i32(1 << 21);
+/// let z = Int24::zero();
+/// let m: Mat3x3<21> = Mat3x3::from_bits([
+///     [Int24::from_i32(3 << 20), z, z],
+///     [z, z, one],
+///     [z, one, z],
+/// ]);
+/// let v = Vec3::new(Int24::from_i16(100), Int24::from_i16(-200), Int24::from_i16(300));
+/// assert_eq!(m * v, Vec3::new(Int24::from_i16(150), Int24::from_i16(300), Int24::from_i16(-200)));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Mat3x3<const FRAC: u8> {
+    /// The elements in row-major order.
+    pub m: [[Fix24<FRAC>; 3]; 3],
+}
+
+impl<const FRAC: u8> Mat3x3<FRAC> {
+    /// Construct a new matrix from the elements in row-major order.
+    pub const fn new(m: [[Fix24<FRAC>; 3]; 3]) -> Self {
+        Self { m }
+    }
+
+    /// Construct a new matrix from the raw fixed-point bits of the elements in row-major order.
+    pub const fn from_bits(bits: [[Int24; 3]; 3]) -> Self {
+        let mut m = [[Fix24::ZERO; 3]; 3];
+        let mut r = 0;
+        while r < 3 {
+            let mut c = 0;
+            while c < 3 {
+                m[r][c] = Fix24::from_bits(bits[r][c]);
+                c += 1;
+            }
+            r += 1;
+        }
+        Self { m }
+    }
+
+    /// Construct a new identity matrix.
+    ///
+    /// For `FRAC` = 23 the diagonal elements are saturated to the largest value below 1.0.
+    pub const fn identity() -> Self {
+        let one = Fix24::from_int(Int24::from_i8(1));
+        let z = Fix24::ZERO;
+        Self::new([[one, z, z], [z, one, z], [z, z, one]])
+    }
+
+    /// Get the transposed matrix.
+    pub const fn transpose(self) -> Self {
+        let m = self.m;
+        Self::new([
+            [m[0][0], m[1][0], m[2][0]],
+            [m[0][1], m[1][1], m[2][1]],
+            [m[0][2], m[1][2], m[2][2]],
+        ])
+    }
+
+    /// Multiply row `r` by the elements `v` with a 48 bit accumulator.
+    fn mul_row(&self, r: usize, v: [Int24; 3]) -> Int24 {
+        let row = &self.m[r];
+        let acc = Int48::mul_int24(row[0].to_bits(), v[0])
+            + Int48::mul_int24(row[1].to_bits(), v[1])
+            + Int48::mul_int24(row[2].to_bits(), v[2]);
+        acc.to_int24_shr(FRAC)
+    }
+
+    /// Multiply this matrix by the column vector `v`.
+    pub fn mul_vec(&self, v: Vec3) -> Vec3 {
+        let v = [v.x, v.y, v.z];
+        Vec3::new(self.mul_row(0, v), self.mul_row(1, v), self.mul_row(2, v))
+    }
+
+    /// Multiply this matrix by the matrix `other`.
+    pub fn mul_mat(&self, other: &Self) -> Self {
+        let mut m = [[Fix24::ZERO; 3]; 3];
+        for (c, col) in other.transpose().m.iter().enumerate() {
+            let col = [col[0].to_bits(), col[1].to_bits(), col[2].to_bits()];
+            for (r, row) in m.iter_mut().enumerate() {
+                row[c] = Fix24::from_bits(self.mul_row(r, col));
+            }
+        }
+        Self::new(m)
+    }
+}
+
+impl<const FRAC: u8> core::ops::Mul<Vec3> for Mat3x3<FRAC> {
+    type Output = Vec3;
+
+    fn mul(self, v: Vec3) -> Vec3 {
+        self.mul_vec(v)
+    }
+}
+
+impl<const FRAC: u8> core::ops::Mul for Mat3x3<FRAC> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.mul_mat(&other)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
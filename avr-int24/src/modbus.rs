@@ -0,0 +1,68 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Helpers for transferring [Int24] values in Modbus holding registers.
+//!
+//! An [Int24] occupies two 16 bit registers as a sign extended 32 bit two's complement integer.
+//! So the upper byte of the high register is the sign padding byte.
+//! This is the common 32 bit signed integer format of Modbus devices.
+
+use crate::Int24;
+
+/// Order of the two 16 bit Modbus registers of one value.
+///
+/// The bytes within each register are always big endian, as specified by Modbus.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum WordOrder {
+    /// The high register comes first (big endian, "ABCD").
+    #[default]
+    HighFirst,
+    /// The low register comes first (word swapped, "CDAB").
+    LowFirst,
+}
+
+impl Int24 {
+    /// Split this [Int24] into two 16 bit Modbus registers in the word order `order`.
+    ///
+    /// The value is sign extended to 32 bits.
+    ///
+    /// ```
+    /// use avr_int24::{Int24, modbus::WordOrder};
+    ///
+    /// let v = Int24::from_i32(-0x12_3456);
+    /// assert_eq!(v.to_modbus_registers(WordOrder::HighFirst), [0xFFED, 0xCBAA]);
+    /// assert_eq!(v.to_modbus_registers(WordOrder::LowFirst), [0xCBAA, 0xFFED]);
+    /// ```
+    pub const fn to_modbus_registers(self, order: WordOrder) -> [u16; 2] {
+        let v = self.to_i32() as u32;
+        let hi = (v >> 16) as u16;
+        let lo = v as u16;
+        match order {
+            WordOrder::HighFirst => [hi, lo],
+            WordOrder::LowFirst => [lo, hi],
+        }
+    }
+
+    /// Construct a new [Int24] from two 16 bit Modbus registers in the word order `order`.
+    ///
+    /// The registers are interpreted as a 32 bit two's complement integer.
+    /// Values outside of the [Int24] range, e.g. because of an invalid sign padding byte, are saturated.
+    ///
+    /// ```
+    /// use avr_int24::{Int24, modbus::WordOrder};
+    ///
+    /// let regs = [0xFFED, 0xCBAA];
+    /// assert_eq!(Int24::from_modbus_registers(regs, WordOrder::HighFirst), Int24::from_i32(-0x12_3456));
+    /// assert_eq!(Int24::from_modbus_registers([0x0100, 0], WordOrder::HighFirst), Int24::MAX);
+    /// ```
+    pub const fn from_modbus_registers(regs: [u16; 2], order: WordOrder) -> Self {
+        let (hi, lo) = match order {
+            WordOrder::HighFirst => (regs[0], regs[1]),
+            WordOrder::LowFirst => (regs[1], regs[0]),
+        };
+        Self::from_i32((((hi as u32) << 16) | lo as u32) as i32)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
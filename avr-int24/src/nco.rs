@@ -0,0 +1,130 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Angle24, Frac24, Int24};
+
+/// Numerically controlled oscillator with a 24 bit phase accumulator.
+///
+/// Each step adds the phase increment to the wrapping phase accumulator.
+/// The frequency is `increment / 2^24 * step_rate`.
+/// With a step rate of 8 kHz the frequency resolution is about 0.5 mHz.
+///
+/// [Nco24::step] reports each completed turn of the phase,
+/// which is the pulse timing of a stepper motor driver running at the step rate.
+/// [Nco24::sample_sin] generates a sine tone.
+///
+/// ```
+/// use avr_int24::Nco24;
+///
+/// // 1 kHz at a step rate of 8 kHz.
+/// let mut nco = Nco24::from_frequency(1000, 8000);
+/// let pulses = (0..80).filter(|_| nco.step()).count();
+/// assert_eq!(pulses, 10);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Nco24 {
+    phase: Angle24,
+    increment: Int24,
+}
+
+impl Nco24 {
+    /// Construct a new oscillator with the phase `increment` per step
+    /// in units of `2^-24` turns and a phase of zero.
+    ///
+    /// Negative increments turn the phase backwards.
+    pub const fn new(increment: Int24) -> Self {
+        Self {
+            phase: Angle24::ZERO,
+            increment,
+        }
+    }
+
+    /// Construct a new oscillator for the frequency `freq` at the step rate `step_rate`.
+    ///
+    /// See [Nco24::increment_for].
+    pub const fn from_frequency(freq: u32, step_rate: u32) -> Self {
+        Self::new(Self::increment_for(freq, step_rate))
+    }
+
+    /// Calculate the phase increment for the frequency `freq` at the step rate `step_rate`.
+    ///
+    /// The frequency and the step rate can be in any unit, as long as it is the same unit.
+    /// The increment is rounded to the nearest value.
+    /// Frequencies of half the step rate and above saturate to [Int24::MAX].
+    /// A step rate of zero results in [Int24::MAX].
+    ///
+    /// This uses 64 bit arithmetic and is meant for `const` context.
+    pub const fn increment_for(freq: u32, step_rate: u32) -> Int24 {
+        if step_rate == 0 {
+            Int24::MAX
+        } else {
+            let rate = step_rate as u64;
+            let inc = ((freq as u64) << 24).saturating_add(rate / 2) / rate;
+            let inc = if inc > 0x7F_FFFF {
+                0x7F_FFFF
+            } else {
+                inc as i32
+            };
+            Int24::from_i32(inc)
+        }
+    }
+
+    /// Get the phase increment per step.
+    pub const fn increment(&self) -> Int24 {
+        self.increment
+    }
+
+    /// Set the phase increment per step.
+    ///
+    /// The phase continues from its current value, so the frequency changes without a phase jump.
+    pub fn set_increment(&mut self, increment: Int24) {
+        self.increment = increment;
+    }
+
+    /// Get the current phase.
+    pub const fn phase(&self) -> Angle24 {
+        self.phase
+    }
+
+    /// Set the current phase.
+    pub fn set_phase(&mut self, phase: Angle24) {
+        self.phase = phase;
+    }
+
+    /// Advance the phase by one step.
+    ///
+    /// Returns `true`, if the phase completed a turn in this step.
+    /// Negative increments complete a turn, when the phase wraps backwards through zero.
+    pub fn step(&mut self) -> bool {
+        let old = self.phase.to_bits().to_bits();
+        self.phase += Angle24::from_bits(self.increment);
+        let new = self.phase.to_bits().to_bits();
+        if self.increment.to_i32() < 0 {
+            new > old
+        } else {
+            new < old
+        }
+    }
+
+    /// Get the sine of the current phase and then advance the phase by one step.
+    ///
+    /// See [Frac24::sin_cos] for the accuracy.
+    pub fn sample_sin(&mut self) -> Frac24 {
+        let s = self.phase.sin();
+        self.step();
+        s
+    }
+
+    /// Get the sawtooth of the current phase and then advance the phase by one step.
+    ///
+    /// The sawtooth starts at zero, rises to just below 1.0 and jumps to -1.0 at half a turn.
+    /// It is the phase reinterpreted as [Frac24] and costs no computation.
+    pub fn sample_saw(&mut self) -> Frac24 {
+        let s = Frac24::from_bits(self.phase.to_bits());
+        self.step();
+        s
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
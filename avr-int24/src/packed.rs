@@ -0,0 +1,619 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{
+    Error, Fix24, Int24, Int48,
+    raw::{add24_buffers, neg24_buffer, sub24_buffers},
+};
+
+/// Read the little endian element at `index` from `bytes`.
+#[inline(always)]
+fn get_le(bytes: &[u8], index: usize) -> Option<Int24> {
+    let i = index.checked_mul(3)?;
+    match bytes.get(i..i.checked_add(3)?)? {
+        &[b0, b1, b2] => Some(Int24::from_raw((b0, b1, b2))),
+        _ => None,
+    }
+}
+
+/// Immutable view of packed little endian [Int24] elements in a byte buffer.
+///
+/// Each element occupies 3 bytes, instead of the 4 bytes of an `i32`.
+///
+/// ```
+/// use avr_int24::{Int24, Int24Slice};
+///
+/// let buf = [0x01, 0x00, 0x00, 0xFF, 0xFF, 0xFF];
+/// let s = Int24Slice::new(&buf).unwrap();
+/// assert_eq!(s.len(), 2);
+/// assert_eq!(s.get(0), Some(Int24::from_i16(1)));
+/// assert_eq!(s.get(1), Some(Int24::from_i16(-1)));
+/// assert_eq!(s.get(2), None);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Int24Slice<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Int24Slice<'a> {
+    /// Construct a new view of `bytes`.
+    ///
+    /// Returns `None`, if the length of `bytes` is not a multiple of 3.
+    pub const fn new(bytes: &'a [u8]) -> Option<Self> {
+        if bytes.len().is_multiple_of(3) {
+            Some(Self { bytes })
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of elements.
+    pub const fn len(&self) -> usize {
+        self.bytes.len() / 3
+    }
+
+    /// Returns `true`, if there are no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Get the underlying bytes.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get the element at `index`.
+    ///
+    /// Returns `None`, if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Int24> {
+        get_le(self.bytes, index)
+    }
+
+    /// Get an iterator over all elements.
+    pub fn iter(&self) -> Int24Iter<'a> {
+        Int24Iter::new(self.bytes, ByteOrder::LittleEndian)
+    }
+}
+
+impl<'a> IntoIterator for Int24Slice<'a> {
+    type Item = Int24;
+    type IntoIter = Int24Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &Int24Slice<'a> {
+    type Item = Int24;
+    type IntoIter = Int24Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Mutable view of packed little endian [Int24] elements in a byte buffer.
+///
+/// Each element occupies 3 bytes, instead of the 4 bytes of an `i32`.
+///
+/// ```
+/// use avr_int24::{Int24, Int24SliceMut};
+///
+/// let mut buf = [0; 6];
+/// let mut s = Int24SliceMut::new(&mut buf).unwrap();
+/// assert!(s.set(1, Int24::from_i16(-2)));
+/// assert!(!s.set(2, Int24::from_i16(-2)));
+/// assert_eq!(s.get(1), Some(Int24::from_i16(-2)));
+/// assert_eq!(buf, [0x00, 0x00, 0x00, 0xFE, 0xFF, 0xFF]);
+/// ```
+#[derive(Eq, PartialEq, Debug)]
+pub struct Int24SliceMut<'a> {
+    bytes: &'a mut [u8],
+}
+
+impl<'a> Int24SliceMut<'a> {
+    /// Construct a new view of `bytes`.
+    ///
+    /// Returns `None`, if the length of `bytes` is not a multiple of 3.
+    pub const fn new(bytes: &'a mut [u8]) -> Option<Self> {
+        if bytes.len().is_multiple_of(3) {
+            Some(Self { bytes })
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of elements.
+    pub const fn len(&self) -> usize {
+        self.bytes.len() / 3
+    }
+
+    /// Returns `true`, if there are no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Get the underlying bytes.
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.bytes
+    }
+
+    /// Get the underlying bytes mutably.
+    pub const fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+
+    /// Get an immutable view of the elements.
+    pub const fn as_slice(&self) -> Int24Slice<'_> {
+        Int24Slice { bytes: self.bytes }
+    }
+
+    /// Get the element at `index`.
+    ///
+    /// Returns `None`, if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<Int24> {
+        get_le(self.bytes, index)
+    }
+
+    /// Set the element at `index` to `value`.
+    ///
+    /// Returns `false` and modifies nothing, if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: Int24) -> bool {
+        if let Some(chunk) = self.bytes.as_chunks_mut::<3>().0.get_mut(index) {
+            *chunk = value.to_le_bytes();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Set all elements to `value`.
+    pub fn fill(&mut self, value: Int24) {
+        let bytes = value.to_le_bytes();
+        for chunk in self.bytes.as_chunks_mut::<3>().0 {
+            *chunk = bytes;
+        }
+    }
+
+    /// Get an iterator over all elements.
+    pub fn iter(&self) -> Int24Iter<'_> {
+        self.as_slice().iter()
+    }
+
+    /// Get the element at `index`. The caller must ensure that `index` is in bounds.
+    ///
+    /// Out of bounds indices return zero.
+    #[inline(always)]
+    fn load(&self, index: usize) -> Int24 {
+        self.get(index).unwrap_or(Int24::zero())
+    }
+
+    /// Swap the elements at the indices `a` and `b`.
+    ///
+    /// Returns `false` and modifies nothing, if an index is out of bounds.
+    #[inline(always)]
+    pub fn swap(&mut self, a: usize, b: usize) -> bool {
+        let chunks = self.bytes.as_chunks_mut::<3>().0;
+        if let (Some(&va), Some(&vb)) = (chunks.get(a), chunks.get(b)) {
+            chunks[a] = vb;
+            chunks[b] = va;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reorder the elements such that the element at `index` is at its sorted position.
+    ///
+    /// All elements before `index` are smaller than or equal to it
+    /// and all elements after `index` are bigger than or equal to it.
+    /// The elements are reordered in place with a quickselect and no allocation.
+    ///
+    /// Returns the element at `index` or `None`, if `index` is out of bounds.
+    ///
+    /// ```
+    /// use avr_int24::{Int24, Int24SliceMut};
+    ///
+    /// let mut buf = [5, 0, 0, 1, 0, 0, 4, 0, 0, 2, 0, 0, 3, 0, 0];
+    /// let mut s = Int24SliceMut::new(&mut buf).unwrap();
+    /// assert_eq!(s.select_nth(1), Some(Int24::from_i16(2)));
+    /// ```
+    pub fn select_nth(&mut self, index: usize) -> Option<Int24> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut lo = 0;
+        let mut hi = self.len() - 1;
+        while lo < hi {
+            // Median of three pivot.
+            let mid = lo + (hi - lo) / 2;
+            if self.load(mid) < self.load(lo) {
+                self.swap(mid, lo);
+            }
+            if self.load(hi) < self.load(lo) {
+                self.swap(hi, lo);
+            }
+            if self.load(hi) < self.load(mid) {
+                self.swap(hi, mid);
+            }
+            let pivot = self.load(mid);
+
+            // Three way partition: [lo, lt) < pivot, [lt, i) == pivot, (gt, hi] > pivot.
+            let mut lt = lo;
+            let mut gt = hi;
+            let mut i = lo;
+            while i <= gt {
+                let v = self.load(i);
+                if v < pivot {
+                    self.swap(i, lt);
+                    lt += 1;
+                    i += 1;
+                } else if v > pivot {
+                    self.swap(i, gt);
+                    gt -= 1;
+                } else {
+                    i += 1;
+                }
+            }
+
+            if index < lt {
+                hi = lt - 1;
+            } else if index > gt {
+                lo = gt + 1;
+            } else {
+                break;
+            }
+        }
+        Some(self.load(index))
+    }
+
+    /// Get the median of the elements.
+    ///
+    /// For an even number of elements the mean of the two middle elements is returned,
+    /// rounded towards negative infinity.
+    /// The elements are reordered in place as by [Int24SliceMut::select_nth].
+    ///
+    /// Returns `None`, if there are no elements.
+    ///
+    /// ```
+    /// use avr_int24::{Int24, Int24SliceMut};
+    ///
+    /// let mut buf = [5, 0, 0, 1, 0, 0, 100, 0, 0, 2, 0, 0, 3, 0, 0];
+    /// let mut s = Int24SliceMut::new(&mut buf).unwrap();
+    /// assert_eq!(s.median(), Some(Int24::from_i16(3)));
+    /// ```
+    pub fn median(&mut self) -> Option<Int24> {
+        let mid = self.len() / 2;
+        let upper = self.select_nth(mid)?;
+        if !self.len().is_multiple_of(2) {
+            return Some(upper);
+        }
+        // The lower middle element is the biggest element before the upper one.
+        let lower = Int24Slice::new(self.bytes.get(..mid * 3)?)?.iter().max()?;
+        Some(Int24::from_i32((lower.to_i32() + upper.to_i32()) >> 1))
+    }
+
+    /// Replace each element `x` by `f(x)`.
+    pub fn apply<F: FnMut(Int24) -> Int24>(&mut self, mut f: F) {
+        for c in self.bytes.as_chunks_mut::<3>().0 {
+            *c = f(Int24::from_le_bytes(*c)).to_le_bytes();
+        }
+    }
+
+    /// Replace each element `x` by `x * gain + offset`.
+    ///
+    /// The product is calculated with 48 bits and shifted right by `FRAC` bits
+    /// (rounding towards negative infinity).
+    /// The sum is only saturated once at the end.
+    ///
+    /// ```
+    /// use avr_int24::{Fix24, Int24, Int24SliceMut};
+    ///
+    /// let mut buf = [10, 0, 0, 0xFF, 0xFF, 0xFF];
+    /// let mut s = Int24SliceMut::new(&mut buf).unwrap();
+    /// let gain = Fix24::<8>::from_bits(Int24::from_i16(0x180)); // 1.5
+    /// s.scale_offset(gain, Int24::from_i16(100));
+    /// assert_eq!(s.get(0), Some(Int24::from_i16(115)));
+    /// assert_eq!(s.get(1), Some(Int24::from_i16(98)));
+    /// ```
+    #[inline(never)]
+    pub fn scale_offset<const FRAC: u8>(&mut self, gain: Fix24<FRAC>, offset: Int24) {
+        let gain = gain.to_bits();
+        let offset = Int48::from_int24(offset);
+        self.apply(|x| (Int48::mul_int24(x, gain).shr(FRAC) + offset).to_int24());
+    }
+
+    /// Multiply each element by the Q8.8 fixed-point `gain` as by [Int24::apply_gain].
+    #[inline(never)]
+    pub fn apply_gain(&mut self, gain_q8_8: i16) {
+        self.apply(|x| x.apply_gain(gain_q8_8));
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b Int24SliceMut<'a> {
+    type Item = Int24;
+    type IntoIter = Int24Iter<'b>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Byte order of the elements in a byte stream.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum ByteOrder {
+    /// Least significant byte first.
+    #[default]
+    LittleEndian,
+    /// Most significant byte first.
+    BigEndian,
+}
+
+impl ByteOrder {
+    /// Convert three bytes in this byte order to an [Int24].
+    #[inline(always)]
+    fn decode(self, c: &[u8]) -> Int24 {
+        match self {
+            ByteOrder::LittleEndian => Int24::from_raw((c[0], c[1], c[2])),
+            ByteOrder::BigEndian => Int24::from_raw((c[2], c[1], c[0])),
+        }
+    }
+
+    /// Convert an [Int24] to three bytes in this byte order.
+    #[inline(always)]
+    fn encode(self, value: Int24) -> [u8; 3] {
+        match self {
+            ByteOrder::LittleEndian => value.to_le_bytes(),
+            ByteOrder::BigEndian => value.to_be_bytes(),
+        }
+    }
+}
+
+/// Encode all elements of `data` into the start of `bytes` with the byte order `order`.
+fn encode_slice(data: &[Int24], bytes: &mut [u8], order: ByteOrder) -> Result<usize, Error> {
+    let needed = data.len().saturating_mul(3);
+    let len = bytes.len();
+    let Some(bytes) = bytes.get_mut(..needed) else {
+        return Err(Error::SliceTooShort { needed, len });
+    };
+    for (c, v) in bytes.as_chunks_mut::<3>().0.iter_mut().zip(data) {
+        *c = order.encode(*v);
+    }
+    Ok(data.len())
+}
+
+/// Decode all elements of `data` from the start of `bytes` with the byte order `order`.
+fn decode_slice(bytes: &[u8], data: &mut [Int24], order: ByteOrder) -> Result<usize, Error> {
+    let needed = data.len().saturating_mul(3);
+    let Some(bytes) = bytes.get(..needed) else {
+        return Err(Error::SliceTooShort {
+            needed,
+            len: bytes.len(),
+        });
+    };
+    for (v, c) in data.iter_mut().zip(bytes.as_chunks::<3>().0) {
+        *v = order.decode(c);
+    }
+    Ok(data.len())
+}
+
+/// Encode all elements of `data` as packed little endian bytes into the start of `bytes`.
+///
+/// `data.len() * 3` bytes are written.
+/// Returns the number of converted elements.
+/// Returns [Error::SliceTooShort] and writes nothing, if `bytes` is too short.
+///
+/// ```
+/// use avr_int24::{Int24, encode_le_slice};
+///
+/// let mut buf = [0; 6];
+/// assert_eq!(encode_le_slice(&[Int24::from_i16(1), Int24::from_i16(-2)], &mut buf), Ok(2));
+/// assert_eq!(buf, [0x01, 0x00, 0x00, 0xFE, 0xFF, 0xFF]);
+/// ```
+pub fn encode_le_slice(data: &[Int24], bytes: &mut [u8]) -> Result<usize, Error> {
+    encode_slice(data, bytes, ByteOrder::LittleEndian)
+}
+
+/// Encode all elements of `data` as packed big endian bytes into the start of `bytes`.
+///
+/// `data.len() * 3` bytes are written.
+/// Returns the number of converted elements.
+/// Returns [Error::SliceTooShort] and writes nothing, if `bytes` is too short.
+pub fn encode_be_slice(data: &[Int24], bytes: &mut [u8]) -> Result<usize, Error> {
+    encode_slice(data, bytes, ByteOrder::BigEndian)
+}
+
+/// Decode all elements of `data` from the packed little endian bytes at the start of `bytes`.
+///
+/// `data.len() * 3` bytes are read.
+/// Returns the number of converted elements.
+/// Returns [Error::SliceTooShort] and modifies nothing, if `bytes` is too short.
+///
+/// ```
+/// use avr_int24::{Error, Int24, decode_le_slice};
+///
+/// let mut data = [Int24::zero(); 2];
+/// assert_eq!(decode_le_slice(&[0x01, 0x00, 0x00, 0xFE, 0xFF, 0xFF], &mut data), Ok(2));
+/// assert_eq!(data, [Int24::from_i16(1), Int24::from_i16(-2)]);
+/// assert_eq!(
+///     decode_le_slice(&[0x01, 0x00, 0x00], &mut data),
+///     Err(Error::SliceTooShort { needed: 6, len: 3 })
+/// );
+/// ```
+pub fn decode_le_slice(bytes: &[u8], data: &mut [Int24]) -> Result<usize, Error> {
+    decode_slice(bytes, data, ByteOrder::LittleEndian)
+}
+
+/// Decode all elements of `data` from the packed big endian bytes at the start of `bytes`.
+///
+/// `data.len() * 3` bytes are read.
+/// Returns the number of converted elements.
+/// Returns [Error::SliceTooShort] and modifies nothing, if `bytes` is too short.
+pub fn decode_be_slice(bytes: &[u8], data: &mut [Int24]) -> Result<usize, Error> {
+    decode_slice(bytes, data, ByteOrder::BigEndian)
+}
+
+/// Add the packed little endian elements of `src` to the elements of `dst` and saturate.
+///
+/// The elements are processed in a single loop without converting them to [Int24].
+/// Only the complete elements that are present in both buffers are processed.
+/// Trailing bytes are not modified.
+///
+/// ```
+/// use avr_int24::add_buffers;
+///
+/// let mut dst = [0x01, 0x00, 0x00, 0xFE, 0xFF, 0x7F];
+/// add_buffers(&mut dst, &[0x02, 0x00, 0x00, 0x05, 0x00, 0x00]);
+/// assert_eq!(dst, [0x03, 0x00, 0x00, 0xFF, 0xFF, 0x7F]); // The second element saturated.
+/// ```
+pub fn add_buffers(dst: &mut [u8], src: &[u8]) {
+    add24_buffers(dst.as_chunks_mut().0, src.as_chunks().0);
+}
+
+/// Subtract the packed little endian elements of `src` from the elements of `dst` and saturate.
+///
+/// See [add_buffers].
+pub fn sub_buffers(dst: &mut [u8], src: &[u8]) {
+    sub24_buffers(dst.as_chunks_mut().0, src.as_chunks().0);
+}
+
+/// Negate all packed little endian elements of `buf` and saturate.
+///
+/// Trailing bytes that don't form a complete element are not modified.
+pub fn neg_buffer(buf: &mut [u8]) {
+    neg24_buffer(buf.as_chunks_mut().0);
+}
+
+/// Iterator over packed [Int24] elements in a byte stream.
+///
+/// Trailing bytes that don't form a complete element are not yielded.
+/// They can be retrieved with [Int24Iter::remainder].
+///
+/// ```
+/// use avr_int24::{ByteOrder, Int24, Int24Iter};
+///
+/// // Two samples of a 24 bit ADC, as received via SPI.
+/// let spi = [0x12, 0x34, 0x56, 0xFF, 0xFF, 0xFE];
+/// let mut it = Int24Iter::new(&spi, ByteOrder::BigEndian);
+/// assert_eq!(it.next(), Some(Int24::from_i32(0x12_3456)));
+/// assert_eq!(it.next(), Some(Int24::from_i16(-2)));
+/// assert_eq!(it.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Int24Iter<'a> {
+    chunks: core::slice::Iter<'a, [u8; 3]>,
+    remainder: &'a [u8],
+    order: ByteOrder,
+}
+
+impl<'a> Int24Iter<'a> {
+    /// Construct a new iterator over the elements in `bytes` with the byte order `order`.
+    pub fn new(bytes: &'a [u8], order: ByteOrder) -> Self {
+        let (chunks, remainder) = bytes.as_chunks();
+        Self {
+            chunks: chunks.iter(),
+            remainder,
+            order,
+        }
+    }
+
+    /// Get the trailing bytes that don't form a complete element.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.remainder
+    }
+}
+
+impl Iterator for Int24Iter<'_> {
+    type Item = Int24;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let c = self.chunks.next()?;
+        Some(self.order.decode(c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Int24Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let c = self.chunks.next_back()?;
+        Some(self.order.decode(c))
+    }
+}
+
+impl ExactSizeIterator for Int24Iter<'_> {}
+
+/// Writer of packed [Int24] elements into a byte buffer.
+///
+/// ```
+/// use avr_int24::{ByteOrder, Int24, Int24Writer};
+///
+/// let mut buf = [0; 8];
+/// let mut w = Int24Writer::new(&mut buf, ByteOrder::BigEndian);
+/// assert_eq!(w.write_all([Int24::from_i32(0x12_3456), Int24::from_i16(-2), Int24::zero()]), 2);
+/// assert_eq!(w.written(), &[0x12, 0x34, 0x56, 0xFF, 0xFF, 0xFE]);
+/// assert!(!w.push(Int24::zero()));
+/// ```
+#[derive(Eq, PartialEq, Debug)]
+pub struct Int24Writer<'a> {
+    bytes: &'a mut [u8],
+    pos: usize,
+    order: ByteOrder,
+}
+
+impl<'a> Int24Writer<'a> {
+    /// Construct a new writer into `bytes` with the byte order `order`.
+    ///
+    /// Writing starts at the beginning of `bytes`.
+    pub fn new(bytes: &'a mut [u8], order: ByteOrder) -> Self {
+        Self {
+            bytes,
+            pos: 0,
+            order,
+        }
+    }
+
+    /// Get the number of elements that still fit into the buffer.
+    pub fn remaining(&self) -> usize {
+        (self.bytes.len() - self.pos) / 3
+    }
+
+    /// Get the bytes that have been written so far.
+    pub fn written(&self) -> &[u8] {
+        &self.bytes[..self.pos]
+    }
+
+    /// Append `value` to the buffer.
+    ///
+    /// Returns `false` and writes nothing, if the buffer is full.
+    pub fn push(&mut self, value: Int24) -> bool {
+        match self.bytes.get_mut(self.pos..self.pos + 3) {
+            Some(c) => {
+                c.copy_from_slice(&self.order.encode(value));
+                self.pos += 3;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Append all elements of `values` to the buffer until it is full.
+    ///
+    /// Returns the number of elements written.
+    pub fn write_all<I: IntoIterator<Item = Int24>>(&mut self, values: I) -> usize {
+        let mut count = 0;
+        for v in values {
+            if !self.push(v) {
+                break;
+            }
+            count += 1;
+        }
+        count
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
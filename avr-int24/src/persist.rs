@@ -0,0 +1,93 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Error, Int24};
+
+/// Length of one persisted [Int24] record in bytes.
+///
+/// A record consists of the 3 little endian value bytes followed by one CRC-8 byte.
+pub const PERSIST_LEN: usize = 4;
+
+/// CRC-8 with the polynomial 0x07 and the initial value 0xFF.
+///
+/// The initial value makes erased (all 0xFF) and cleared (all 0x00) records invalid.
+const fn crc8(bytes: &[u8; 3]) -> u8 {
+    let mut crc = 0xFF_u8;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i];
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+            j += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+impl Int24 {
+    /// Write this [Int24] as a record with checksum to the start of `buf`.
+    ///
+    /// This can be used to persist tare values and calibration constants in EEPROM or flash.
+    /// The record is [PERSIST_LEN] bytes long.
+    ///
+    /// Returns [Error::SliceTooShort] and writes nothing, if `buf` is shorter than one record.
+    ///
+    /// ```
+    /// use avr_int24::{Int24, PERSIST_LEN};
+    ///
+    /// let mut eeprom = [0xFF; PERSIST_LEN];
+    /// Int24::from_i16(-2).write_to(&mut eeprom).unwrap();
+    /// assert_eq!(Int24::read_from(&eeprom), Ok(Int24::from_i16(-2)));
+    /// ```
+    pub fn write_to(self, buf: &mut [u8]) -> Result<(), Error> {
+        let len = buf.len();
+        let Some(buf) = buf.get_mut(..PERSIST_LEN) else {
+            return Err(Error::SliceTooShort {
+                needed: PERSIST_LEN,
+                len,
+            });
+        };
+        let bytes = self.to_le_bytes();
+        buf[..3].copy_from_slice(&bytes);
+        buf[3] = crc8(&bytes);
+        Ok(())
+    }
+
+    /// Read an [Int24] record with checksum that has been written by [Int24::write_to].
+    ///
+    /// Returns [Error::SliceTooShort], if `buf` is shorter than one record,
+    /// and [Error::Checksum], if the checksum doesn't match.
+    /// Erased (all 0xFF) and cleared (all 0x00) records are never valid.
+    ///
+    /// ```
+    /// use avr_int24::{Error, Int24, PERSIST_LEN};
+    ///
+    /// let erased = [0xFF; PERSIST_LEN];
+    /// assert_eq!(Int24::read_from(&erased), Err(Error::Checksum));
+    /// let tare = Int24::read_from(&erased).unwrap_or(Int24::zero());
+    /// assert_eq!(tare, Int24::zero());
+    /// ```
+    pub fn read_from(buf: &[u8]) -> Result<Self, Error> {
+        let Some(buf) = buf.get(..PERSIST_LEN) else {
+            return Err(Error::SliceTooShort {
+                needed: PERSIST_LEN,
+                len: buf.len(),
+            });
+        };
+        let bytes = [buf[0], buf[1], buf[2]];
+        if crc8(&bytes) == buf[3] {
+            Ok(Self::from_le_bytes(bytes))
+        } else {
+            Err(Error::Checksum)
+        }
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,137 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, Int48};
+
+/// PID controller built on saturating [Int24] and [Int48] arithmetic.
+///
+/// The gains `kp`, `ki` and `kd` are fixed-point values with `shift` fractional bits.
+/// All products are calculated with full 48 bit width and are only shifted
+/// down by `shift` bits at the very end.
+///
+/// The integrator is clamped to the output limits (anti-windup).
+/// The derivative is calculated on the measurement instead of the error, so that
+/// setpoint steps don't cause derivative kicks.
+/// The derivative is low pass filtered with an exponential moving average.
+///
+/// ```
+/// use avr_int24::{Int24, Pid24};
+///
+/// // kp = 0.5, ki = 0.25, kd = 0 with 8 fractional bits.
+/// let mut pid = Pid24::new(
+///     Int24::from_i16(128),
+///     Int24::from_i16(64),
+///     Int24::zero(),
+///     8,
+/// )
+/// .with_output_limits(Int24::from_i16(-100), Int24::from_i16(100));
+///
+/// let y = pid.update(Int24::from_i16(40), Int24::zero());
+/// assert_eq!(y, Int24::from_i16(30));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Pid24 {
+    kp: Int24,
+    ki: Int24,
+    kd: Int24,
+    shift: u8,
+    out_min: Int24,
+    out_max: Int24,
+    d_filter_shift: u8,
+    integral: Int48,
+    deriv: Int24,
+    prev_meas: Option<Int24>,
+}
+
+impl Pid24 {
+    /// Construct a new PID controller.
+    ///
+    /// The gains are fixed-point values with `shift` fractional bits.
+    /// Shift counts bigger than 47 are treated as 47.
+    ///
+    /// The output is not limited and the derivative is not filtered by default.
+    pub const fn new(kp: Int24, ki: Int24, kd: Int24, shift: u8) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            shift: if shift > 47 { 47 } else { shift },
+            out_min: Int24::MIN,
+            out_max: Int24::MAX,
+            d_filter_shift: 0,
+            integral: Int48::zero(),
+            deriv: Int24::zero(),
+            prev_meas: None,
+        }
+    }
+
+    /// Limit the controller output to the range `min..=max`.
+    ///
+    /// The integrator is clamped to the same range.
+    pub const fn with_output_limits(mut self, min: Int24, max: Int24) -> Self {
+        self.out_min = min;
+        self.out_max = max;
+        self
+    }
+
+    /// Low pass filter the derivative with an exponential moving average.
+    ///
+    /// The filter coefficient is `1 / 2^shift`.
+    /// A `shift` of 0 disables the filter.
+    /// Shift counts bigger than 23 are treated as 23.
+    pub const fn with_derivative_filter(mut self, shift: u8) -> Self {
+        self.d_filter_shift = if shift > 23 { 23 } else { shift };
+        self
+    }
+
+    /// Change the gains.
+    ///
+    /// The gains are fixed-point values with the `shift` fractional bits
+    /// that were given to [Pid24::new].
+    /// The integrator state is kept.
+    pub fn set_gains(&mut self, kp: Int24, ki: Int24, kd: Int24) {
+        self.kp = kp;
+        self.ki = ki;
+        self.kd = kd;
+    }
+
+    /// Reset the integrator and the derivative state.
+    pub fn reset(&mut self) {
+        self.integral = Int48::zero();
+        self.deriv = Int24::zero();
+        self.prev_meas = None;
+    }
+
+    /// Get the current integrator value, scaled to the output range.
+    pub fn integral(&self) -> Int24 {
+        self.integral.to_int24_shr(self.shift)
+    }
+
+    /// Run one controller step and return the new output.
+    pub fn update(&mut self, setpoint: Int24, measurement: Int24) -> Int24 {
+        let error = setpoint - measurement;
+
+        // Proportional part.
+        let p = Int48::mul_int24(self.kp, error);
+
+        // Integral part with anti-windup clamping.
+        let i_min = Int48::from_int24(self.out_min).shl(self.shift);
+        let i_max = Int48::from_int24(self.out_max).shl(self.shift);
+        self.integral = (self.integral + Int48::mul_int24(self.ki, error)).clamp(i_min, i_max);
+
+        // Filtered derivative part on the measurement.
+        let dmeas = match self.prev_meas {
+            Some(prev) => measurement - prev,
+            None => Int24::zero(),
+        };
+        self.prev_meas = Some(measurement);
+        self.deriv += (dmeas - self.deriv) >> self.d_filter_shift;
+        let d = Int48::mul_int24(self.kd, self.deriv);
+
+        let out = (p + self.integral - d).to_int24_shr(self.shift);
+        out.max(self.out_min).min(self.out_max)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,47 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Fix24, Int24, raw::conv::i64_to_i24raw_sat};
+
+/// The largest intermediate value of [poly_eval]: 40 bits.
+const ACC_MAX: i64 = (1 << 39) - 1;
+
+/// The smallest intermediate value of [poly_eval]: 40 bits.
+const ACC_MIN: i64 = -(1 << 39);
+
+/// Evaluate the polynomial `coeffs[0] + coeffs[1] * x + coeffs[2] * x^2 + ...`
+/// with Horner's method.
+///
+/// The argument `x` has `XFRAC` fractional bits.
+/// The coefficients and the result have `FRAC` fractional bits.
+/// The intermediate values have 40 bits, which is 16 bits of headroom above the 24 bit result.
+/// Only the result is saturated, unless an intermediate value exceeds the 40 bits.
+/// The products are rounded towards negative infinity.
+///
+/// An empty slice of coefficients evaluates to zero.
+///
+/// ```
+/// use avr_int24::{Fix24, Frac24, Int24, poly_eval};
+///
+/// // 1.0 + 2.0 x + 4.0 x^2 with Q7.16 coefficients
+/// let c = |v: i16| Fix24::<16>::from_int(Int24::from_i16(v));
+/// let coeffs = [c(1), c(2), c(4)];
+///
+/// let x: Fix24<23> = Frac24::HALF.into();
+/// assert_eq!(poly_eval(x, &coeffs), c(3));
+/// ```
+pub fn poly_eval<const XFRAC: u8, const FRAC: u8>(
+    x: Fix24<XFRAC>,
+    coeffs: &[Fix24<FRAC>],
+) -> Fix24<FRAC> {
+    let x = x.to_bits().to_i32() as i64;
+    let mut acc = 0_i64;
+    for c in coeffs.iter().rev() {
+        acc = ((acc * x) >> XFRAC) + c.to_bits().to_i32() as i64;
+        acc = acc.clamp(ACC_MIN, ACC_MAX);
+    }
+    Fix24::from_bits(Int24::from_raw(i64_to_i24raw_sat(acc)))
+}
+
+// vim: ts=4 sw=4 expandtab
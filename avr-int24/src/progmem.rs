@@ -0,0 +1,114 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, raw::progmem_read24};
+
+/// Table of `N` [Int24] values in AVR program memory (flash).
+///
+/// Large constant tables don't need to be copied to SRAM.
+/// The values are read with the `lpm` instruction.
+/// The table must be in the lower 64 KiB of program memory.
+///
+/// On non-AVR targets the table is a normal constant array.
+///
+/// A table is declared with the [int24_progmem](crate::int24_progmem) macro.
+/// The contents of the table can't be referenced directly, because references
+/// to program memory can't be dereferenced with normal loads on AVR.
+///
+/// ```
+/// use avr_int24::{Int24, int24_progmem};
+///
+/// int24_progmem! {
+///     static TABLE: [Int24; 3] = [Int24::MIN, Int24::zero(), Int24::MAX];
+/// }
+///
+/// assert_eq!(TABLE.len(), 3);
+/// assert_eq!(TABLE.get(2), Some(Int24::MAX));
+/// assert_eq!(TABLE.get(3), None);
+/// ```
+#[repr(transparent)]
+pub struct Int24ProgMem<const N: usize>([Int24; N]);
+
+impl<const N: usize> Int24ProgMem<N> {
+    /// Construct a new program memory table.
+    ///
+    /// # Safety
+    ///
+    /// On AVR the table must be placed in the `.progmem.data` section of a `static`.
+    /// Use the [int24_progmem](crate::int24_progmem) macro instead.
+    pub const unsafe fn new(table: [Int24; N]) -> Self {
+        Self(table)
+    }
+
+    /// Get the number of elements in the table.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true`, if the table has no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Read the element at `index` from program memory.
+    ///
+    /// Returns `None`, if `index` is out of bounds.
+    #[inline(never)]
+    pub fn get(&self, index: usize) -> Option<Int24> {
+        let v = self.0.get(index)?;
+        // SAFETY: The table is in program memory, as guaranteed by `new`.
+        Some(unsafe { Int24::read_progmem(v) })
+    }
+
+    /// Iterate over all elements of the table.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Int24> + ExactSizeIterator + '_ {
+        self.0.iter().map(|v| {
+            // SAFETY: The table is in program memory, as guaranteed by `new`.
+            unsafe { Int24::read_progmem(v) }
+        })
+    }
+}
+
+impl Int24 {
+    /// Read an [Int24] from program memory at `ptr`.
+    ///
+    /// On non-AVR targets this is a normal read.
+    ///
+    /// # Safety
+    ///
+    /// On AVR `ptr` must point to 3 bytes in the lower 64 KiB of program memory.
+    /// On non-AVR targets `ptr` must be valid for reads.
+    #[inline(always)]
+    pub unsafe fn read_progmem(ptr: *const Int24) -> Int24 {
+        // SAFETY: Guaranteed by the caller.
+        Self::from_raw(unsafe { progmem_read24(ptr.cast()) })
+    }
+}
+
+/// Declare a `static` [Int24ProgMem](crate::Int24ProgMem) table in AVR program memory.
+///
+/// ```
+/// use avr_int24::{Int24, int24_progmem};
+///
+/// int24_progmem! {
+///     /// Calibration table.
+///     pub static CAL: [Int24; 2] = [Int24::from_i16(-10), Int24::from_i16(10)];
+/// }
+///
+/// assert_eq!(CAL.get(0), Some(Int24::from_i16(-10)));
+/// ```
+#[macro_export]
+macro_rules! int24_progmem {
+    ($(#[$attr:meta])* $vis:vis static $name:ident: [Int24; $n:expr] = $init:expr;) => {
+        $(#[$attr])*
+        #[cfg_attr(target_arch = "avr", unsafe(link_section = ".progmem.data"))]
+        $vis static $name: $crate::Int24ProgMem<{ $n }> = {
+            let table: [$crate::Int24; $n] = $init;
+            // SAFETY: The table is placed in the program memory section.
+            unsafe { $crate::Int24ProgMem::new(table) }
+        };
+    };
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,183 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Proof harnesses for the [Kani](https://github.com/model-checking/kani) model checker.
+//!
+//! The harnesses prove for all operands that the operations of the generic backend
+//! don't panic and that they are equal to the saturated results of plain integer math.
+//! The AVR assembly implementations are not covered.
+//!
+//! These harnesses are only built by Kani. Run them with:
+//!
+//! ```sh
+//! cargo kani
+//! ```
+//!
+//! The proofs of the divisions take a long time.
+
+use crate::Int24;
+
+const MIN: i64 = -0x80_0000;
+const MAX: i64 = 0x7F_FFFF;
+
+/// Any [Int24] value.
+fn any_int24() -> Int24 {
+    Int24::from_raw((kani::any(), kani::any(), kani::any()))
+}
+
+/// Saturate `v` to the 24 bit range.
+fn sat(v: i64) -> i32 {
+    v.clamp(MIN, MAX) as i32
+}
+
+/// Saturated division with the division by zero semantics of [Int24].
+fn div_ref(a: i64, b: i64) -> i32 {
+    if b == 0 {
+        if a < 0 { MIN as i32 } else { MAX as i32 }
+    } else {
+        sat(a / b)
+    }
+}
+
+#[kani::proof]
+fn proof_conv() {
+    let v: i32 = kani::any();
+    assert_eq!(Int24::from_i32(v).to_i32(), sat(v as i64));
+    let a = any_int24();
+    assert_eq!(Int24::from_i32(a.to_i32()), a);
+}
+
+#[kani::proof]
+fn proof_add() {
+    let (a, b) = (any_int24(), any_int24());
+    let (x, y) = (a.to_i32() as i64, b.to_i32() as i64);
+    assert_eq!((a + b).to_i32(), sat(x + y));
+    assert_eq!((a - b).to_i32(), sat(x - y));
+}
+
+#[kani::proof]
+fn proof_mul() {
+    let (a, b) = (any_int24(), any_int24());
+    let (x, y) = (a.to_i32() as i64, b.to_i32() as i64);
+    assert_eq!((a * b).to_i32(), sat(x * y));
+}
+
+#[kani::proof]
+fn proof_unchecked() {
+    let (a, b) = (any_int24(), any_int24());
+    let (x, y) = (a.to_i32() as i64, b.to_i32() as i64);
+    if sat(x + y) as i64 == x + y {
+        // SAFETY: The sum fits.
+        assert_eq!(unsafe { a.unchecked_add(b) }.to_i32() as i64, x + y);
+    }
+    if sat(x * y) as i64 == x * y {
+        // SAFETY: The product fits.
+        assert_eq!(unsafe { a.unchecked_mul(b) }.to_i32() as i64, x * y);
+    }
+}
+
+#[kani::proof]
+fn proof_mul_shr() {
+    let (a, b) = (any_int24(), any_int24());
+    let count: u8 = kani::any();
+    let (x, y) = (a.to_i32() as i64, b.to_i32() as i64);
+    let res = a.mul_shr(b, count).to_i32();
+    assert_eq!(res, sat((x * y) >> count.min(47)));
+}
+
+#[kani::proof]
+fn proof_mul_small() {
+    let a = any_int24();
+    let (b16, b8): (i16, u8) = (kani::any(), kani::any());
+    let x = a.to_i32() as i64;
+    assert_eq!(a.mul_i16(b16).to_i32(), sat(x * b16 as i64));
+    assert_eq!(a.mul_u8(b8).to_i32(), sat(x * b8 as i64));
+    assert_eq!(a.mul_q15(b16).to_i32(), sat((x * b16 as i64) >> 15));
+}
+
+#[kani::proof]
+fn proof_div() {
+    let (a, b) = (any_int24(), any_int24());
+    let (x, y) = (a.to_i32() as i64, b.to_i32() as i64);
+    assert_eq!((a / b).to_i32(), div_ref(x, y));
+    assert_eq!(a.shl8div(b).to_i32(), div_ref(x << 8, y));
+}
+
+#[kani::proof]
+fn proof_shl_div() {
+    let (a, b) = (any_int24(), any_int24());
+    let count: u8 = kani::any();
+    let (x, y) = (a.to_i32() as i64, b.to_i32() as i64);
+    let res = a.shl_div(b, count).to_i32();
+    assert_eq!(res, div_ref(x << count.min(24), y));
+}
+
+#[kani::proof]
+fn proof_div_u8() {
+    let a = any_int24();
+    let b: u8 = kani::any();
+    let x = a.to_i32() as i64;
+    assert_eq!(a.div_u8(b).to_i32(), div_ref(x, b as i64));
+}
+
+#[kani::proof]
+fn proof_unary() {
+    let a = any_int24();
+    let x = a.to_i32() as i64;
+    assert_eq!((-a).to_i32(), sat(-x));
+    assert_eq!(a.abs().to_i32(), sat(x.abs()));
+}
+
+#[kani::proof]
+#[kani::unwind(33)]
+fn proof_isqrt() {
+    let a = any_int24();
+    assert_eq!(a.isqrt().to_i32(), a.to_i32().max(0).isqrt());
+}
+
+#[kani::proof]
+fn proof_shift() {
+    let a = any_int24();
+    let count: u8 = kani::any();
+    let x = a.to_i32() as i64;
+    // The left shift truncates to 24 bits.
+    let shl = if count < 24 { (x << count) as i32 } else { 0 };
+    assert_eq!(a.shl(count).to_i32(), (shl << 8) >> 8);
+    assert_eq!(a.shr(count).to_i32(), (x >> count.min(63)) as i32);
+}
+
+#[kani::proof]
+fn proof_next_multiple_of() {
+    let (a, b) = (any_int24(), any_int24());
+    let (x, y) = (a.to_i32() as i64, b.to_i32() as i64);
+    let reference = match y {
+        0 => x,
+        1.. => x + (y - x.rem_euclid(y)) % y,
+        _ => x - x.rem_euclid(-y),
+    };
+    assert_eq!(a.next_multiple_of(b).to_i32(), sat(reference));
+    let checked = a.checked_next_multiple_of(b).map(|v| v.to_i32() as i64);
+    let in_range = (MIN..=MAX).contains(&reference);
+    assert_eq!(checked, (y != 0 && in_range).then_some(reference));
+}
+
+#[kani::proof]
+fn proof_power_of_two() {
+    let a = any_int24();
+    let x = a.to_i32();
+    assert_eq!(a.is_power_of_two(), x > 0 && x.count_ones() == 1);
+    let next = (x.max(1) as u32).next_power_of_two() as i64;
+    assert_eq!(a.next_power_of_two().to_i32(), sat(next));
+    let checked = a.checked_next_power_of_two().map(|v| v.to_i32() as i64);
+    assert_eq!(checked, (next <= MAX).then_some(next));
+    assert_eq!(a.checked_ilog2(), x.checked_ilog2().map(|v| v as u8));
+}
+
+#[kani::proof]
+fn proof_cmp() {
+    let (a, b) = (any_int24(), any_int24());
+    assert_eq!(a.cmp(b), a.to_i32().cmp(&b.to_i32()));
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,106 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::Int24;
+
+/// The seed that replaces a seed of zero.
+const DEFAULT_SEED: u32 = 0x5A_C3E1;
+
+/// 24 bit xorshift pseudo random number generator.
+///
+/// The state of the generator is only 3 bytes and its period is `2^24 - 1`.
+/// This is meant for randomized tests, dither and jitter injection.
+/// It is *not* suitable for cryptography.
+///
+/// ```
+/// use avr_int24::{Int24, XorShift24};
+///
+/// let mut rng = XorShift24::new(1234);
+/// assert_ne!(rng.next_int24(), rng.next_int24());
+///
+/// // Jitter in the range -8..8.
+/// let jitter = rng.next_bits(4).to_i32();
+/// assert!((-8..8).contains(&jitter));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct XorShift24 {
+    state: Int24,
+}
+
+impl XorShift24 {
+    /// Construct a new generator from the lower 24 bits of `seed`.
+    ///
+    /// A seed of zero is replaced by a fixed seed, because the generator never leaves the zero state.
+    pub const fn new(seed: u32) -> Self {
+        let seed = seed & 0xFF_FFFF;
+        let seed = if seed == 0 { DEFAULT_SEED } else { seed };
+        Self {
+            state: Int24::from_bits(seed),
+        }
+    }
+
+    /// Get the 24 bit state of the generator.
+    ///
+    /// Pass the state to [XorShift24::new] to continue the sequence later.
+    pub const fn state(&self) -> u32 {
+        self.state.to_bits()
+    }
+
+    /// Get the next pseudo random number.
+    ///
+    /// Every 24 bit value except zero is returned exactly once per period.
+    pub fn next_int24(&mut self) -> Int24 {
+        let mut x = self.state.to_bits();
+        x ^= (x << 8) & 0xFF_FFFF;
+        x ^= x >> 1;
+        x ^= (x << 11) & 0xFF_FFFF;
+        self.state = Int24::from_bits(x);
+        self.state
+    }
+
+    /// Get the next pseudo random number uniformly distributed in the range
+    /// `-2^(bits - 1)..2^(bits - 1)`.
+    ///
+    /// `bits` is limited to the range `1..=24`.
+    pub fn next_bits(&mut self, bits: u8) -> Int24 {
+        let bits = bits.clamp(1, 24);
+        self.next_int24().shr(24 - bits)
+    }
+
+    /// Get the next pseudo random number with a triangular probability density function.
+    ///
+    /// This is the sum of two numbers of [XorShift24::next_bits] with `bits`.
+    /// The result is in the range `-2^bits..=2^bits - 2` with the peak at zero.
+    /// This is TPDF dither with an amplitude of `2^(bits - 1)`.
+    /// For 24 bits the sum saturates.
+    ///
+    /// ```
+    /// use avr_int24::XorShift24;
+    ///
+    /// let mut rng = XorShift24::new(1);
+    /// for _ in 0..100 {
+    ///     assert!((-256..=254).contains(&rng.next_tpdf(8).to_i32()));
+    /// }
+    /// ```
+    pub fn next_tpdf(&mut self, bits: u8) -> Int24 {
+        self.next_bits(bits) + self.next_bits(bits)
+    }
+}
+
+impl Default for XorShift24 {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+/// Endless sequence of [XorShift24::next_int24].
+impl Iterator for XorShift24 {
+    type Item = Int24;
+
+    fn next(&mut self) -> Option<Int24> {
+        Some(self.next_int24())
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,266 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Fix24, Int24, raw::conv::i64_to_i24raw_sat};
+
+const MAX: i64 = 0x7F_FFFF;
+const MIN: i64 = -0x80_0000;
+
+/// Greatest common divisor with the binary GCD algorithm.
+///
+/// This only needs shifts and subtractions, which is cheap on AVR.
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 {
+        return b;
+    }
+    if b == 0 {
+        return a;
+    }
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            break;
+        }
+    }
+    a << shift
+}
+
+/// Divide `n` by the positive `d` and round to the nearest integer.
+const fn div_round(n: i64, d: i64) -> i64 {
+    // The denominator is positive, so the division never fails.
+    match (2 * n + d).checked_div_euclid(2 * d) {
+        Some(q) => q,
+        None => 0,
+    }
+}
+
+/// Divide `num` and `den` by their non-zero greatest common divisor.
+const fn div_gcd(num: i64, den: i64) -> (i64, i64) {
+    let g = gcd(num.unsigned_abs(), den as u64) as i64;
+    // The denominator is not zero, so the gcd is not zero and the division never fails.
+    match (num.checked_div(g), den.checked_div(g)) {
+        (Some(num), Some(den)) => (num, den),
+        _ => (num, den),
+    }
+}
+
+/// Saturating fraction with an [Int24] numerator and denominator.
+///
+/// The fraction is always reduced to lowest terms and the denominator is always positive.
+/// Therefore, equal fractions compare equal.
+///
+/// The arithmetic operations are calculated with 64 bit intermediate values.
+/// If the reduced result doesn't fit into 24 bits, then it is approximated
+/// by dropping low bits of the numerator and the denominator.
+/// If the result is an integer outside of the [Int24] range, then it is saturated.
+///
+/// ```
+/// use avr_int24::{Int24, Ratio24};
+///
+/// let r = |n, d| Ratio24::new(Int24::from_i32(n), Int24::from_i32(d)).unwrap();
+///
+/// // Resample 44.1 kHz to 48 kHz.
+/// let rate = r(48000, 44100);
+/// assert_eq!(rate, r(160, 147));
+/// assert_eq!(rate.mul_int(Int24::from_i16(14700)), Int24::from_i16(16000));
+///
+/// // Gear ratios multiply exactly.
+/// assert_eq!(r(3, 4) * r(8, 9), r(2, 3));
+/// assert_eq!(r(1, 3) + r(1, 6), r(1, 2));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Ratio24 {
+    num: Int24,
+    den: Int24,
+}
+
+#[allow(clippy::should_implement_trait)]
+impl Ratio24 {
+    /// Zero.
+    pub const ZERO: Self = Self::from_int(Int24::zero());
+
+    /// One.
+    pub const ONE: Self = Self::from_int(Int24::from_i16(1));
+
+    /// Construct a new fraction `num / den`.
+    ///
+    /// The fraction is reduced and the sign is moved to the numerator.
+    ///
+    /// Returns `None`, if `den` is zero.
+    pub const fn new(num: Int24, den: Int24) -> Option<Self> {
+        if den.to_i32() == 0 {
+            None
+        } else {
+            Some(Self::reduce(num.to_i32() as i64, den.to_i32() as i64))
+        }
+    }
+
+    /// Construct a new fraction from the integer `v`.
+    pub const fn from_int(v: Int24) -> Self {
+        Self {
+            num: v,
+            den: Int24::from_i16(1),
+        }
+    }
+
+    /// Get the reduced numerator.
+    pub const fn num(self) -> Int24 {
+        self.num
+    }
+
+    /// Get the reduced and always positive denominator.
+    pub const fn den(self) -> Int24 {
+        self.den
+    }
+
+    /// Reduce the fraction `num / den` with a non-zero `den` and approximate or saturate it.
+    const fn reduce(num: i64, den: i64) -> Self {
+        let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+        let (mut num, mut den) = div_gcd(num, den);
+        if num > MAX || num < MIN || den > MAX {
+            while (num > MAX || num < MIN || den > MAX) && den > 1 {
+                num >>= 1;
+                den >>= 1;
+            }
+            num = if num > MAX {
+                MAX
+            } else if num < MIN {
+                MIN
+            } else {
+                num
+            };
+            (num, den) = div_gcd(num, den);
+        }
+        Self {
+            num: Int24::from_i32(num as i32),
+            den: Int24::from_i32(den as i32),
+        }
+    }
+
+    /// Add and saturate two fractions.
+    pub const fn add(self, other: Self) -> Self {
+        let (a, b) = (self.num.to_i32() as i64, self.den.to_i32() as i64);
+        let (c, d) = (other.num.to_i32() as i64, other.den.to_i32() as i64);
+        Self::reduce(a * d + c * b, b * d)
+    }
+
+    /// Subtract and saturate two fractions.
+    pub const fn sub(self, other: Self) -> Self {
+        let (a, b) = (self.num.to_i32() as i64, self.den.to_i32() as i64);
+        let (c, d) = (other.num.to_i32() as i64, other.den.to_i32() as i64);
+        Self::reduce(a * d - c * b, b * d)
+    }
+
+    /// Multiply and saturate two fractions.
+    pub const fn mul(self, other: Self) -> Self {
+        let (a, b) = (self.num.to_i32() as i64, self.den.to_i32() as i64);
+        let (c, d) = (other.num.to_i32() as i64, other.den.to_i32() as i64);
+        Self::reduce(a * c, b * d)
+    }
+
+    /// Negate and saturate the fraction.
+    pub const fn neg(self) -> Self {
+        Self::reduce(-(self.num.to_i32() as i64), self.den.to_i32() as i64)
+    }
+
+    /// Get the reciprocal `den / num` of the fraction.
+    ///
+    /// Returns `None`, if the fraction is zero.
+    pub const fn recip(self) -> Option<Self> {
+        Self::new(self.den, self.num)
+    }
+
+    /// Multiply the integer `v` by the fraction.
+    ///
+    /// The result is rounded to the nearest integer and saturated.
+    /// The wide intermediate product is only divided once, so no precision is lost.
+    ///
+    /// This uses 64 bit division.
+    pub const fn mul_int(self, v: Int24) -> Int24 {
+        let n = v.to_i32() as i64 * self.num.to_i32() as i64;
+        Int24::from_raw(i64_to_i24raw_sat(div_round(n, self.den.to_i32() as i64)))
+    }
+
+    /// Convert the fraction to a fixed-point number with `FRAC` fractional bits.
+    ///
+    /// The result is rounded to the nearest value and saturated.
+    ///
+    /// This uses 64 bit division.
+    ///
+    /// ```
+    /// use avr_int24::{Fix24, Int24, Ratio24};
+    ///
+    /// let r = Ratio24::new(Int24::from_i16(1), Int24::from_i16(3)).unwrap();
+    /// assert_eq!(r.to_fix::<16>(), Fix24::from_bits(Int24::from_i32(0x5555)));
+    /// ```
+    pub const fn to_fix<const FRAC: u8>(self) -> Fix24<FRAC> {
+        let n = (self.num.to_i32() as i64) << FRAC;
+        let bits = div_round(n, self.den.to_i32() as i64);
+        Fix24::from_bits(Int24::from_raw(i64_to_i24raw_sat(bits)))
+    }
+}
+
+impl From<Int24> for Ratio24 {
+    fn from(v: Int24) -> Self {
+        Self::from_int(v)
+    }
+}
+
+impl core::ops::Add for Ratio24 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::add(self, other)
+    }
+}
+
+impl core::ops::AddAssign for Ratio24 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub for Ratio24 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::sub(self, other)
+    }
+}
+
+impl core::ops::SubAssign for Ratio24 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Mul for Ratio24 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::mul(self, other)
+    }
+}
+
+impl core::ops::MulAssign for Ratio24 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl core::ops::Neg for Ratio24 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -3,8 +3,15 @@
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
 use crate::{
-    asm::{asm_divsat24, asm_ge24, asm_mulsat24, asm_negsat24, asm_shl24, asm_shr24},
-    raw::conv::{i24raw_to_i32, i32_to_i24raw_sat},
+    asm::{
+        asm_addsat24_buffers, asm_addsat24_in_place, asm_ct_abssat24, asm_ct_addsat24,
+        asm_ct_divsat24, asm_ct_mulsat24, asm_ct_negsat24, asm_ct_subsat24, asm_div24x8u,
+        asm_divsat24, asm_ge24, asm_irq_disable, asm_irq_restore, asm_isqrt24, asm_lpm24,
+        asm_mac24, asm_mul24_lo, asm_mul24_wide, asm_mul24x8u, asm_mul24x16, asm_mul24x16_shr8,
+        asm_mulsat24, asm_negsat24, asm_negsat24_buffer, asm_shl24, asm_shr24,
+        asm_subsat24_buffers, asm_subsat24_in_place, asm_sum24,
+    },
+    raw::conv::{i24raw_to_i32, i32_to_i24raw_sat, i48raw_to_i64, i64_to_i24raw_sat},
 };
 
 /// Raw bytes tuple; Little Endian.
@@ -34,17 +41,222 @@ pub fn mul24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
     asm_mulsat24(a, b)
 }
 
+/// 24 bit multiplication without saturation.
+/// Returns the low 24 bits of the product.
+#[inline(always)]
+pub fn mul24_lo(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    asm_mul24_lo(a, b)
+}
+
+/// Input range of the 24 bit multiplication by the constant `k` that doesn't saturate.
+const fn mul24_const_range(k: i16) -> (i32, i32) {
+    let k = k as i32;
+    if k > 0 {
+        (-0x80_0000 / k, 0x7F_FFFF / k)
+    } else if k < 0 {
+        (0x7F_FFFF / k, -0x80_0000 / k)
+    } else {
+        (-0x80_0000, 0x7F_FFFF)
+    }
+}
+
+/// 24 bit saturating multiplication by the constant `K`.
+/// The multiplication is expanded into shifts and additions at compile time.
+#[inline(always)]
+pub fn mul24_const<const K: i16>(a: Int24Raw) -> Int24Raw {
+    let (lo, hi) = const { mul24_const_range(K) };
+    let x = i24raw_to_i32(a);
+    if x < lo {
+        if K > 0 { raw_min() } else { raw_max() }
+    } else if x > hi {
+        if K > 0 { raw_max() } else { raw_min() }
+    } else {
+        let mut k = K.unsigned_abs();
+        let mut s = x;
+        let mut p = 0;
+        while k != 0 {
+            if k & 1 != 0 {
+                p += s;
+            }
+            s <<= 1;
+            k >>= 1;
+        }
+        if K < 0 {
+            p = -p;
+        }
+        let p = p.to_le_bytes();
+        (p[0], p[1], p[2])
+    }
+}
+
+/// 24 bit multiplication with full 48 bit result.
+/// Returns the low and the high 24 bits of the product.
+#[inline(always)]
+pub fn mul24_wide(a: Int24Raw, b: Int24Raw) -> (Int24Raw, Int24Raw) {
+    asm_mul24_wide(a, b)
+}
+
+/// Run `f` with interrupts disabled.
+/// The previous interrupt state is restored afterwards.
+#[inline(always)]
+pub fn with_irq_disabled<R, F: FnOnce() -> R>(f: F) -> R {
+    let sreg = asm_irq_disable();
+    let ret = f();
+    asm_irq_restore(sreg);
+    ret
+}
+
+/// Read a raw value from program memory.
+///
+/// # Safety
+///
+/// `ptr` must point to 3 bytes in the lower 64 KiB of program memory.
+/// On non-AVR targets it must be valid for reads.
+#[inline(always)]
+pub unsafe fn progmem_read24(ptr: *const Int24Raw) -> Int24Raw {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { asm_lpm24(ptr) }
+}
+
+/// Saturate the little endian two's complement product `p` of at least 3 bytes to 24 bit.
+#[inline(always)]
+fn prod_to_i24raw_sat(p: &[u8]) -> Int24Raw {
+    let ext = if p[2] & 0x80 == 0 { 0x00 } else { 0xFF };
+    if p[3..].iter().all(|b| *b == ext) {
+        (p[0], p[1], p[2])
+    } else if p[p.len() - 1] & 0x80 == 0 {
+        raw_max()
+    } else {
+        raw_min()
+    }
+}
+
+/// 24 bit multiplication by a signed 16 bit factor, then saturation.
+#[inline(always)]
+pub fn mul24_i16(a: Int24Raw, b: i16) -> Int24Raw {
+    prod_to_i24raw_sat(&asm_mul24x16(a, b))
+}
+
+/// 24 bit multiplication by an unsigned 8 bit factor, then saturation.
+#[inline(always)]
+pub fn mul24_u8(a: Int24Raw, b: u8) -> Int24Raw {
+    prod_to_i24raw_sat(&asm_mul24x8u(a, b))
+}
+
+/// 24 bit division by an unsigned 8 bit divisor.
+/// Division by zero saturates.
+#[inline(always)]
+pub fn div24_u8(a: Int24Raw, b: u8) -> Int24Raw {
+    if b == 0 {
+        if a.2 & 0x80 == 0 {
+            raw_max()
+        } else {
+            raw_min()
+        }
+    } else {
+        asm_div24x8u(a, b)
+    }
+}
+
+/// 24 bit multiplication by a 16 bit Q8.8 factor with an arithmetic right shift by 8, then saturation.
+#[inline(always)]
+pub fn mul24_q8_8(a: Int24Raw, b: i16) -> Int24Raw {
+    i32_to_i24raw_sat(asm_mul24x16_shr8(a, b))
+}
+
+/// 24 bit multiplication by a signed Q15 factor, then saturation.
+#[inline(always)]
+pub fn mul24_q15(a: Int24Raw, b: i16) -> Int24Raw {
+    i32_to_i24raw_sat(asm_mul24x16_shr8(a, b) >> 7)
+}
+
+/// Sum up all elements of `data` onto the 48 bit `acc`.
+/// The accumulator wraps on overflow.
+#[inline(always)]
+pub fn sum24(mut acc: i64, data: &[Int24Raw]) -> i64 {
+    for chunk in data.chunks(0xFFFF) {
+        acc = asm_sum24(acc, chunk);
+    }
+    acc
+}
+
+/// Multiply-accumulate the products of the elements of `a` and `b` onto the 56 bit `acc`.
+/// The accumulator wraps on overflow.
+#[inline(always)]
+pub fn mac24(mut acc: i64, a: &[Int24Raw], b: &[Int24Raw]) -> i64 {
+    for (a, b) in a.chunks(255).zip(b.chunks(255)) {
+        acc = asm_mac24(acc, a, b);
+    }
+    acc
+}
+
+/// 24 bit multiplication with an arithmetic right shift of the 48 bit product, then saturation.
+#[inline(always)]
+pub fn mul24_shr(a: Int24Raw, b: Int24Raw, count: u8) -> Int24Raw {
+    let count = if count > 47 { 47 } else { count };
+    i64_to_i24raw_sat(i48raw_to_i64(mul24_wide(a, b)) >> count)
+}
+
+/// Get the shift count that corresponds to the divisor `b`,
+/// if `b` is a positive power of two.
+#[inline(always)]
+fn pow2_shift(b: Int24Raw) -> Option<u8> {
+    let b = i24raw_to_i32(b);
+    if b > 0 && b & (b - 1) == 0 {
+        Some(b.trailing_zeros() as u8)
+    } else {
+        None
+    }
+}
+
+/// Arithmetic right shift of `a` by `count` bits that rounds towards zero like a division.
+#[inline(always)]
+fn shr_div(a: i32, count: u8) -> i32 {
+    let bias = if a < 0 { (1 << count) - 1 } else { 0 };
+    (a + bias) >> count
+}
+
 /// 24 bit saturating division.
+///
+/// Positive power of two divisors take a fast path with a shift instead of the division loop.
 #[inline(always)]
 pub fn div24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
-    asm_divsat24(a, b, false)
+    match pow2_shift(b) {
+        Some(count) => i32_to_i24raw_sat(shr_div(i24raw_to_i32(a), count)),
+        None => asm_divsat24(a, b, false),
+    }
 }
 
 /// 24 bit multiplication by 256 (l-shift 8) followed by a division, then saturation.
 /// Internal 32 bit temporary storage.
+///
+/// Positive power of two divisors take a fast path with a shift instead of the division loop.
 #[inline(always)]
 pub fn shl24_by8_div24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
-    asm_divsat24(a, b, true)
+    match pow2_shift(b) {
+        Some(count) => i32_to_i24raw_sat(shr_div(i24raw_to_i32(a) << 8, count)),
+        None => asm_divsat24(a, b, true),
+    }
+}
+
+/// 24 bit left shift by an arbitrary number of bits followed by a division, then saturation.
+/// Internal 64 bit temporary storage.
+#[inline(always)]
+pub fn shl24_div24(a: Int24Raw, b: Int24Raw, count: u8) -> Int24Raw {
+    match count {
+        0 => div24(a, b),
+        8 => shl24_by8_div24(a, b),
+        _ => {
+            if b == raw_zero() {
+                if is_neg24(a) { raw_min() } else { raw_max() }
+            } else {
+                let count = if count > 24 { 24 } else { count };
+                let a = (i24raw_to_i32(a) as i64) << count;
+                // The divisor is not zero, so the division never fails.
+                i64_to_i24raw_sat(a.checked_div(i24raw_to_i32(b) as i64).unwrap_or(0))
+            }
+        }
+    }
 }
 
 /// 24 bit saturating addition.
@@ -65,12 +277,61 @@ pub fn sub24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
     i32_to_i24raw_sat(i24raw_to_i32(a) - i24raw_to_i32(b))
 }
 
+/// 24 bit addition without saturation.
+/// Returns the low 24 bits of the sum.
+#[inline(always)]
+pub fn add24_lo(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    let r = (i24raw_to_i32(a) + i24raw_to_i32(b)).to_le_bytes();
+    (r[0], r[1], r[2])
+}
+
+/// 24 bit saturating addition of `b` to `a` in memory.
+#[inline(always)]
+pub fn add24_in_place(a: &mut Int24Raw, b: Int24Raw) {
+    asm_addsat24_in_place(a, b)
+}
+
+/// 24 bit saturating subtraction of `b` from `a` in memory.
+#[inline(always)]
+pub fn sub24_in_place(a: &mut Int24Raw, b: Int24Raw) {
+    asm_subsat24_in_place(a, b)
+}
+
+/// Saturating 24 bit addition of the packed elements of `src` to the packed elements of `dst`.
+#[inline(always)]
+pub fn add24_buffers(dst: &mut [[u8; 3]], src: &[[u8; 3]]) {
+    asm_addsat24_buffers(dst, src)
+}
+
+/// Saturating 24 bit subtraction of the packed elements of `src` from the packed elements of `dst`.
+#[inline(always)]
+pub fn sub24_buffers(dst: &mut [[u8; 3]], src: &[[u8; 3]]) {
+    asm_subsat24_buffers(dst, src)
+}
+
+/// Saturating 24 bit negation of the packed elements of `buf`.
+#[inline(always)]
+pub fn neg24_buffer(buf: &mut [[u8; 3]]) {
+    asm_negsat24_buffer(buf)
+}
+
 /// Check if a 24 bit number is two's complement negative.
 #[inline(always)]
 pub const fn is_neg24(a: Int24Raw) -> bool {
     a.2 & 0x80 != 0
 }
 
+/// 24 bit integer square root. Negative values result in zero.
+#[inline(always)]
+pub fn isqrt24(a: Int24Raw) -> Int24Raw {
+    if is_neg24(a) {
+        raw_zero()
+    } else {
+        let q = asm_isqrt24(a);
+        (q.0, q.1, 0)
+    }
+}
+
 /// Negate and saturate a 24 bit number.
 #[inline(always)]
 pub fn neg24(a: Int24Raw) -> Int24Raw {
@@ -83,6 +344,42 @@ pub fn abs24(a: Int24Raw) -> Int24Raw {
     if is_neg24(a) { asm_negsat24(a) } else { a }
 }
 
+/// Constant time 24 bit saturating addition.
+#[inline(always)]
+pub fn ct_add24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    asm_ct_addsat24(a, b)
+}
+
+/// Constant time 24 bit saturating subtraction.
+#[inline(always)]
+pub fn ct_sub24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    asm_ct_subsat24(a, b)
+}
+
+/// Constant time 24 bit saturating multiplication.
+#[inline(always)]
+pub fn ct_mul24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    asm_ct_mulsat24(a, b)
+}
+
+/// Constant time 24 bit saturating division.
+#[inline(always)]
+pub fn ct_div24(a: Int24Raw, b: Int24Raw) -> Int24Raw {
+    asm_ct_divsat24(a, b)
+}
+
+/// Constant time negation of a 24 bit number. The result is saturated.
+#[inline(always)]
+pub fn ct_neg24(a: Int24Raw) -> Int24Raw {
+    asm_ct_negsat24(a)
+}
+
+/// Constant time absolute value of a 24 bit number. The result is saturated.
+#[inline(always)]
+pub fn ct_abs24(a: Int24Raw) -> Int24Raw {
+    asm_ct_abssat24(a)
+}
+
 /// Left shift a 24 bit number by 8 bits.
 #[inline(always)]
 pub const fn shl24_by8(a: Int24Raw) -> Int24Raw {
@@ -164,6 +461,14 @@ pub mod conv {
         }
     }
 
+    /// Convert a raw 48 bit (low, high) two's complement value to [i64].
+    #[inline(always)]
+    pub const fn i48raw_to_i64(v: (Int24Raw, Int24Raw)) -> i64 {
+        let (lo, hi) = v;
+        let ext = if is_neg24(hi) { 0xFF } else { 0x00 };
+        i64::from_le_bytes([lo.0, lo.1, lo.2, hi.0, hi.1, hi.2, ext, ext])
+    }
+
     /// Convert and saturate a raw 24 bit two's complement value to [i16].
     #[inline(never)]
     pub const fn i24raw_to_i16_sat(v: Int24Raw) -> i16 {
@@ -203,6 +508,19 @@ pub mod conv {
         }
     }
 
+    /// Convert and saturate an [i64] value to a raw 24 bit two's complement value.
+    #[inline(never)]
+    pub const fn i64_to_i24raw_sat(v: i64) -> Int24Raw {
+        if v > 0x7F_FFFF {
+            raw_max()
+        } else if v < -0x80_0000 {
+            raw_min()
+        } else {
+            let v = v.to_le_bytes();
+            (v[0], v[1], v[2])
+        }
+    }
+
     /// Convert an [i16] value to a raw 24 bit two's complement value.
     #[inline(never)]
     pub const fn i16_to_i24raw(v: i16) -> Int24Raw {
@@ -0,0 +1,296 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, Int48, raw::conv::i64_to_i24raw_sat};
+
+/// Linear interpolation between `a` and `b` with the fraction `t` of `frac_bits` bits.
+#[inline(always)]
+fn lerp(a: Int24, b: Int24, t: i32, frac_bits: u8) -> Int24 {
+    let one = 1 << frac_bits;
+    let pa = Int48::mul_int24(a, Int24::from_i32(one - t));
+    let pb = Int48::mul_int24(b, Int24::from_i32(t));
+    let half = Int48::from_i32(one >> 1);
+    (pa + pb + half).to_int24_shr(frac_bits)
+}
+
+/// Round `a` to a multiple of the non-zero `b` towards the sign of `b`.
+///
+/// The result is not saturated.
+const fn next_multiple(a: i32, b: i32) -> i32 {
+    let r = a % b;
+    let r = if (r > 0 && b < 0) || (r < 0 && b > 0) {
+        r + b
+    } else {
+        r
+    };
+    if r == 0 { a } else { a + (b - r) }
+}
+
+/// Scale `a` by `1 + num / den` and saturate.
+///
+/// The correction `a * num / den` is rounded to the nearest integer with ties away from zero.
+const fn scale_by(a: Int24, num: i32, den: i64) -> Int24 {
+    let a = a.to_i32() as i64;
+    let c = a * num as i64;
+    let c = if c < 0 {
+        -((-2 * c + den) / (2 * den))
+    } else {
+        (2 * c + den) / (2 * den)
+    };
+    Int24::from_raw(i64_to_i24raw_sat(a + c))
+}
+
+impl Int24 {
+    /// Linear interpolation between `self` and `other`.
+    ///
+    /// `t` is a Q0.8 fraction in the range `0.0..1.0`.
+    /// A `t` of 0 returns `self`.
+    /// The result is calculated with a wide intermediate product and is rounded to the nearest integer.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::from_i16(100);
+    /// let b = Int24::from_i16(200);
+    /// assert_eq!(a.lerp_q8(b, 64), Int24::from_i16(125));
+    /// ```
+    #[inline(never)]
+    pub fn lerp_q8(self, other: Self, t: u8) -> Self {
+        lerp(self, other, t as i32, 8)
+    }
+
+    /// Linear interpolation between `self` and `other`.
+    ///
+    /// `t` is a Q0.16 fraction in the range `0.0..1.0`.
+    /// A `t` of 0 returns `self`.
+    /// The result is calculated with a wide intermediate product and is rounded to the nearest integer.
+    #[inline(never)]
+    pub fn lerp_q16(self, other: Self, t: u16) -> Self {
+        lerp(self, other, t as i32, 16)
+    }
+
+    /// Linearly map `self` from the range `in_min..=in_max` to the range `out_min..=out_max`.
+    ///
+    /// This is equivalent to the Arduino `map()` function,
+    /// but the calculation is done with a wide intermediate, so that it never overflows.
+    /// The result is rounded towards zero like in Arduino `map()`.
+    /// Values outside of the input range are extrapolated and the result is saturated.
+    ///
+    /// If `in_min` equals `in_max`, then `out_min` is returned.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let x = Int24::from_i16(512);
+    /// let y = x.map_range(
+    ///     Int24::zero(),
+    ///     Int24::from_i16(1023),
+    ///     Int24::zero(),
+    ///     Int24::from_i16(255),
+    /// );
+    /// assert_eq!(y, Int24::from_i16(127));
+    /// ```
+    #[inline(never)]
+    pub fn map_range(self, in_min: Self, in_max: Self, out_min: Self, out_max: Self) -> Self {
+        let in_span = in_max.to_i32() as i64 - in_min.to_i32() as i64;
+        if in_span == 0 {
+            return out_min;
+        }
+        let out_span = out_max.to_i32() as i64 - out_min.to_i32() as i64;
+        let x = self.to_i32() as i64 - in_min.to_i32() as i64;
+        let y = x * out_span / in_span + out_min.to_i32() as i64;
+        Self::from_raw(i64_to_i24raw_sat(y))
+    }
+
+    /// Map the unsigned range `0..=MAX` onto the timer compare range `0..=top`.
+    ///
+    /// The result is rounded to the nearest value and [Int24::MAX] maps to `top`.
+    /// Negative values are clamped to zero.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// // Half duty cycle for an 8 bit timer.
+    /// assert_eq!(Int24::from_i32(0x40_0000).to_duty(255), 128);
+    /// assert_eq!(Int24::MAX.to_duty(255), 255);
+    /// assert_eq!(Int24::from_i16(-1).to_duty(255), 0);
+    /// ```
+    #[inline(never)]
+    pub fn to_duty(self, top: u16) -> u16 {
+        if self.to_i32() <= 0 {
+            return 0;
+        }
+        let d = Int48::mul_int24(self, Int24::from_i32(top as i32)) + Int48::from_i32(1 << 22);
+        (d >> 23).to_i64() as u16
+    }
+
+    /// Map the signed range `MIN..=MAX` onto the timer compare range `0..=top`.
+    ///
+    /// The result is rounded to the nearest value.
+    /// [Int24::MIN] maps to zero, zero maps to the middle and [Int24::MAX] maps to `top`.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// // Bipolar motor drive with an H-bridge.
+    /// assert_eq!(Int24::MIN.to_duty_signed(1000), 0);
+    /// assert_eq!(Int24::zero().to_duty_signed(1000), 500);
+    /// assert_eq!(Int24::MAX.to_duty_signed(1000), 1000);
+    /// ```
+    #[inline(never)]
+    pub fn to_duty_signed(self, top: u16) -> u16 {
+        let d = Int48::mul_int24(self, Int24::from_i32(top as i32))
+            + Int48::from_i64(((top as i64) << 23) + (1 << 23));
+        (d >> 24).to_i64() as u16
+    }
+
+    /// Map the timer compare value `duty` in the range `0..=top` back
+    /// onto the unsigned range `0..=MAX`.
+    ///
+    /// This is the inverse of [Int24::to_duty].
+    /// The result is rounded to the nearest value.
+    /// Values of `duty` above `top` are clamped to `top`.
+    /// A `top` of zero returns zero.
+    ///
+    /// This uses 64 bit division.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_duty(255, 255), Int24::MAX);
+    /// assert_eq!(Int24::from_duty(128, 255).to_duty(255), 128);
+    /// ```
+    pub const fn from_duty(duty: u16, top: u16) -> Self {
+        if top == 0 {
+            return Self::zero();
+        }
+        let duty = if duty > top { top } else { duty };
+        let (duty, top) = (duty as i64, top as i64);
+        Self::from_raw(i64_to_i24raw_sat((duty * 0x7F_FFFF + top / 2) / top))
+    }
+
+    /// Map the timer compare value `duty` in the range `0..=top` back
+    /// onto the signed range `MIN..=MAX`.
+    ///
+    /// This is the inverse of [Int24::to_duty_signed].
+    /// The result is rounded to the nearest value.
+    /// Values of `duty` above `top` are clamped to `top`.
+    /// A `top` of zero returns zero.
+    ///
+    /// This uses 64 bit division.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// assert_eq!(Int24::from_duty_signed(0, 1000), Int24::MIN);
+    /// assert_eq!(Int24::from_duty_signed(500, 1000), Int24::zero());
+    /// assert_eq!(Int24::from_duty_signed(1000, 1000), Int24::MAX);
+    /// ```
+    pub const fn from_duty_signed(duty: u16, top: u16) -> Self {
+        if top == 0 {
+            return Self::zero();
+        }
+        let duty = if duty > top { top } else { duty };
+        let (duty, top) = (duty as i64, top as i64);
+        let v = (duty * 0xFF_FFFF + top / 2) / top - 0x80_0000;
+        Self::from_raw(i64_to_i24raw_sat(v))
+    }
+
+    /// Scale `self` by `1 + pct / 100`.
+    ///
+    /// This applies a correction of `pct` percent.
+    /// See [Int24::scale_ppm].
+    pub const fn scale_percent(self, pct: i16) -> Self {
+        scale_by(self, pct as i32, 100)
+    }
+
+    /// Scale `self` by `1 + permille / 1000`.
+    ///
+    /// This applies a correction of `permille` per mille.
+    /// See [Int24::scale_ppm].
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// // +1.5 %
+    /// assert_eq!(Int24::from_i16(2000).scale_permille(15), Int24::from_i16(2030));
+    /// ```
+    pub const fn scale_permille(self, permille: i16) -> Self {
+        scale_by(self, permille as i32, 1000)
+    }
+
+    /// Scale `self` by `1 + ppm / 1_000_000`.
+    ///
+    /// This applies a correction of `ppm` parts per million, e.g. for the frequency error of a crystal.
+    /// The correction is calculated with a wide intermediate, so it never overflows.
+    /// It is rounded to the nearest integer with ties away from zero
+    /// and the result is saturated.
+    ///
+    /// This uses 64 bit division.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// // -350 ppm
+    /// assert_eq!(Int24::from_i32(8_000_000).scale_ppm(-350), Int24::from_i32(7_997_200));
+    /// assert_eq!(Int24::MAX.scale_ppm(1), Int24::MAX);
+    /// ```
+    pub const fn scale_ppm(self, ppm: i32) -> Self {
+        scale_by(self, ppm, 1_000_000)
+    }
+
+    /// Round `self` to the next multiple of `rhs`.
+    ///
+    /// If `rhs` is positive, this is the smallest multiple of `rhs` that is greater than or equal to `self`.
+    /// If `rhs` is negative, this is the largest multiple of `rhs` that is less than or equal to `self`.
+    /// A `rhs` of zero returns `self`.
+    ///
+    /// The result is saturated.
+    /// Note that a saturated result is not a multiple of `rhs`.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// // Align a sample count to a block size of 32.
+    /// let n = Int24::from_i16(1000);
+    /// assert_eq!(n.next_multiple_of(Int24::from_i16(32)), Int24::from_i16(1024));
+    /// assert_eq!(n.next_multiple_of(Int24::from_i16(-32)), Int24::from_i16(992));
+    /// assert_eq!(Int24::MAX.next_multiple_of(Int24::from_i16(32)), Int24::MAX);
+    /// ```
+    pub const fn next_multiple_of(self, rhs: Self) -> Self {
+        let b = rhs.to_i32();
+        if b == 0 {
+            self
+        } else {
+            Self::from_i32(next_multiple(self.to_i32(), b))
+        }
+    }
+
+    /// Round `self` to the next multiple of `rhs` like [Int24::next_multiple_of].
+    ///
+    /// Returns `None`, if `rhs` is zero or if the result doesn't fit into 24 bits.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let n = Int24::from_i16(-1000);
+    /// assert_eq!(n.checked_next_multiple_of(Int24::from_i16(32)), Some(Int24::from_i16(-992)));
+    /// assert_eq!(n.checked_next_multiple_of(Int24::zero()), None);
+    /// assert_eq!(Int24::MAX.checked_next_multiple_of(Int24::from_i16(32)), None);
+    /// ```
+    pub const fn checked_next_multiple_of(self, rhs: Self) -> Option<Self> {
+        let b = rhs.to_i32();
+        if b == 0 {
+            return None;
+        }
+        let v = next_multiple(self.to_i32(), b);
+        if v < Self::MIN.to_i32() || v > Self::MAX.to_i32() {
+            None
+        } else {
+            Some(Self::from_i32(v))
+        }
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,50 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::Int24;
+use core::cmp::Ordering;
+
+/// Binary search the range `lo..hi` for a value of a monotone function.
+///
+/// This is the equivalent of `slice::binary_search_by` on the range of [Int24] values.
+/// `f` compares the function value at its argument to the target
+/// and returns [Ordering::Less], if the argument is too small.
+///
+/// Returns `Ok(x)`, if `f(x)` is [Ordering::Equal].
+/// If there are multiple matches, then any one of them may be returned.
+/// Returns `Err(x)` with the smallest `x` for which `f(x)` is [Ordering::Greater],
+/// if there is no match.
+/// That is `hi`, if `f` returns [Ordering::Less] for the whole range.
+/// An empty range returns `Err(lo)`.
+///
+/// The midpoint is calculated without overflow, so the whole 24 bit range can be searched.
+/// `f` is called at most 25 times.
+///
+/// ```
+/// use avr_int24::{Int24, bisect};
+///
+/// // The DAC code for a target ADC reading of 1000 with a gain of 1.25.
+/// let adc = |code: Int24| code.to_i32() * 5 / 4;
+/// let code = bisect(Int24::zero(), Int24::from_i16(4096), |c| adc(c).cmp(&1000));
+/// assert_eq!(code, Ok(Int24::from_i16(800)));
+///
+/// // There is no code for 1004. 804 is the first code that exceeds it.
+/// let code = bisect(Int24::zero(), Int24::from_i16(4096), |c| adc(c).cmp(&1004));
+/// assert_eq!(code, Err(Int24::from_i16(804)));
+/// ```
+pub fn bisect(lo: Int24, hi: Int24, mut f: impl FnMut(Int24) -> Ordering) -> Result<Int24, Int24> {
+    let mut lo = lo.to_i32();
+    let mut hi = hi.to_i32();
+    while lo < hi {
+        let mid = lo + ((hi - lo) >> 1);
+        match f(Int24::from_i32(mid)) {
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+            Ordering::Equal => return Ok(Int24::from_i32(mid)),
+        }
+    }
+    Err(Int24::from_i32(lo))
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,489 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, Int48, add_buffers, int24_progmem, neg_buffer, stats, sub_buffers};
+
+/// Operation that is checked by [Int24::self_test].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum SelfTestOp {
+    /// [Int24::add]
+    Add,
+    /// [Int24::sub]
+    Sub,
+    /// [Int24::mul]
+    Mul,
+    /// [Int24::mul_shr] by 23 bits.
+    MulShr,
+    /// [Int24::mul_i16]
+    MulI16,
+    /// [Int24::mul_u8]
+    MulU8,
+    /// [Int24::mul_const] by 10.
+    MulConst,
+    /// [Int24::unchecked_mul]
+    UncheckedMul,
+    /// High 24 bits of [Int48::mul_int24].
+    MulWideHi,
+    /// Low 24 bits of [Int48::mul_int24].
+    MulWideLo,
+    /// [Int24::apply_gain]
+    ApplyGain,
+    /// [Int24::mul_q15]
+    MulQ15,
+    /// [Int24::div]
+    Div,
+    /// [Int24::div_u8]
+    DivU8,
+    /// [Int24::shl8div]
+    Shl8Div,
+    /// [Int24::shl_div] by 12 bits.
+    ShlDiv,
+    /// [Int24::neg]
+    Neg,
+    /// [Int24::abs]
+    Abs,
+    /// [Int24::isqrt]
+    Isqrt,
+    /// [Int24::shl]
+    Shl,
+    /// [Int24::shl8]
+    Shl8,
+    /// [Int24::shl16]
+    Shl16,
+    /// [Int24::shr]
+    Shr,
+    /// [Int24::cmp]
+    Cmp,
+    /// [Int24::add_in_place]
+    AddInPlace,
+    /// [Int24::sub_in_place]
+    SubInPlace,
+    /// [add_buffers](crate::add_buffers) of one element.
+    AddBuffers,
+    /// [sub_buffers](crate::sub_buffers) of one element.
+    SubBuffers,
+    /// [neg_buffer](crate::neg_buffer) of one element.
+    NegBuffer,
+    /// [stats::sum_wide] of `a` and `b`, arithmetically right shifted by 1 bit.
+    Sum,
+    /// [stats::dot] of `[a, b]` and `[b, a]`, arithmetically right shifted by 24 bits.
+    Mac,
+    /// [Int24::ct_add]
+    CtAdd,
+    /// [Int24::ct_sub]
+    CtSub,
+    /// [Int24::ct_mul]
+    CtMul,
+    /// [Int24::ct_div]
+    CtDiv,
+    /// [Int24::ct_neg]
+    CtNeg,
+    /// [Int24::ct_abs]
+    CtAbs,
+}
+
+impl SelfTestOp {
+    /// Calculate the operation.
+    ///
+    /// The second operand of the unary operations is ignored.
+    fn eval(self, a: Int24, b: Int24) -> Int24 {
+        match self {
+            Self::Add => a + b,
+            Self::Sub => a - b,
+            Self::Mul => a * b,
+            Self::MulShr => a.mul_shr(b, 23),
+            Self::MulI16 => a.mul_i16(b.cast_to_i16()),
+            Self::MulU8 => a.mul_u8(b.cast_to_i8() as u8),
+            Self::MulConst => a.mul_const::<10>(),
+            // SAFETY: The products of the vectors fit into Int24.
+            Self::UncheckedMul => unsafe { a.unchecked_mul(b) },
+            Self::MulWideHi => Int48::mul_int24(a, b).to_int24_shr(24),
+            Self::MulWideLo => Int24::from_bits(Int48::mul_int24(a, b).to_i64() as u32),
+            Self::ApplyGain => a.apply_gain(b.cast_to_i16()),
+            Self::MulQ15 => a.mul_q15(b.cast_to_i16()),
+            Self::Div => a / b,
+            Self::DivU8 => a.div_u8(b.cast_to_i8() as u8),
+            Self::Shl8Div => a.shl8div(b),
+            Self::ShlDiv => a.shl_div(b, 12),
+            Self::Neg => -a,
+            Self::Abs => a.abs(),
+            Self::Isqrt => a.isqrt(),
+            Self::Shl => a.shl(b.cast_to_i8() as u8),
+            Self::Shl8 => a.shl8(),
+            Self::Shl16 => a.shl16(),
+            Self::Shr => a.shr(b.cast_to_i8() as u8),
+            Self::Cmp => Int24::from_i8(a.cmp(b) as i8),
+            Self::AddInPlace => {
+                let mut a = a;
+                a.add_in_place(b);
+                a
+            }
+            Self::SubInPlace => {
+                let mut a = a;
+                a.sub_in_place(b);
+                a
+            }
+            Self::AddBuffers => {
+                let mut dst = a.to_le_bytes();
+                add_buffers(&mut dst, &b.to_le_bytes());
+                Int24::from_le_bytes(dst)
+            }
+            Self::SubBuffers => {
+                let mut dst = a.to_le_bytes();
+                sub_buffers(&mut dst, &b.to_le_bytes());
+                Int24::from_le_bytes(dst)
+            }
+            Self::NegBuffer => {
+                let mut buf = a.to_le_bytes();
+                neg_buffer(&mut buf);
+                Int24::from_le_bytes(buf)
+            }
+            Self::Sum => stats::sum_wide(&[a, b]).to_int24_shr(1),
+            Self::Mac => stats::dot(&[a, b], &[b, a]).to_int24_shr(24),
+            Self::CtAdd => a.ct_add(b),
+            Self::CtSub => a.ct_sub(b),
+            Self::CtMul => a.ct_mul(b),
+            Self::CtDiv => a.ct_div(b),
+            Self::CtNeg => a.ct_neg(),
+            Self::CtAbs => a.ct_abs(),
+        }
+    }
+}
+
+/// Failed known-answer vector of [Int24::self_test].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SelfTestFailure {
+    /// The failed operation.
+    pub op: SelfTestOp,
+    /// The first operand.
+    pub a: Int24,
+    /// The second operand.
+    pub b: Int24,
+    /// The expected result.
+    pub expected: Int24,
+    /// The actual result.
+    pub result: Int24,
+}
+
+impl core::fmt::Display for SelfTestFailure {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "self-test of {:?}({}, {}) failed: expected {}, got {}",
+            self.op,
+            self.a.to_i32(),
+            self.b.to_i32(),
+            self.expected.to_i32(),
+            self.result.to_i32()
+        )
+    }
+}
+
+impl core::error::Error for SelfTestFailure {}
+
+const MAX: i32 = 0x7F_FFFF;
+const MIN: i32 = -0x80_0000;
+
+/// Number of known-answer vectors of each operation.
+const N: usize = 4;
+
+/// Known-answer vectors `(a, b, expected)`.
+type Vectors = [(i32, i32, i32); N];
+
+const ADD: Vectors = [
+    (MAX, 1, MAX),
+    (MIN, -1, MIN),
+    (-0x40_0000, -0x40_0000, MIN),
+    (0x12_3456, -0x65, 0x12_33F1),
+];
+
+const SUB: Vectors = [
+    (MIN, 1, MIN),
+    (MAX, -1, MAX),
+    (0, MIN, MAX),
+    (100, 300, -200),
+];
+
+const MUL: Vectors = [
+    (MAX, 2, MAX),
+    (MIN, -1, MAX),
+    (0x1000, -0x800, MIN),
+    (-3, 0x2A_AAAA, -0x7F_FFFE),
+];
+
+const DIV: Vectors = [
+    (MIN, -1, MAX),
+    (-5, 0, MIN),
+    (MAX, 3, 0x2A_AAAA),
+    (0x12_3456, -0x65, -11_812),
+];
+
+const NEG: Vectors = [(MIN, 0, MAX), (MAX, 0, -MAX), (-1, 0, 1), (0, 0, 0)];
+
+const ABS: Vectors = [(MIN, 0, MAX), (-MAX, 0, MAX), (5, 0, 5), (0, 0, 0)];
+
+/// The operations and their known-answer vectors.
+///
+/// The vectors exercise the saturation corners with `MIN`, `MAX`, zero and -1
+/// and a normal value of each operation.
+/// The divisors are not powers of two, so that the division routines don't take the shift path.
+const VECTORS: [(SelfTestOp, Vectors); 37] = [
+    (SelfTestOp::Add, ADD),
+    (SelfTestOp::Sub, SUB),
+    (SelfTestOp::Mul, MUL),
+    (
+        SelfTestOp::MulShr,
+        [
+            (MAX, MAX, 0x7F_FFFE),
+            (MIN, MIN, MAX),
+            (MIN, MAX, -MAX),
+            (-1, 1, -1),
+        ],
+    ),
+    (
+        SelfTestOp::MulI16,
+        [
+            (MIN, -1, MAX),
+            (0x1_0000, 0x100, MAX),
+            (-0x1234, 0x7FFF, MIN),
+            (-1000, -1000, 1_000_000),
+        ],
+    ),
+    (
+        SelfTestOp::MulU8,
+        [
+            (MAX, 2, MAX),
+            (MIN, 255, MIN),
+            (-0x1_0000, 0x80, MIN),
+            (1000, 200, 200_000),
+        ],
+    ),
+    (
+        SelfTestOp::MulConst,
+        [
+            (MAX, 0, MAX),
+            (MIN, 0, MIN),
+            (-1, 0, -10),
+            (0x0C_CCCC, 0, 0x7F_FFF8),
+        ],
+    ),
+    (
+        SelfTestOp::UncheckedMul,
+        [(MAX, 1, MAX), (MIN, 1, MIN), (-1, -1, 1), (0, MIN, 0)],
+    ),
+    (
+        SelfTestOp::MulWideHi,
+        [
+            (MAX, MAX, 0x3F_FFFF),
+            (MIN, MIN, 0x40_0000),
+            (MIN, MAX, -0x40_0000),
+            (-1, -1, 0),
+        ],
+    ),
+    (
+        SelfTestOp::MulWideLo,
+        [(MAX, MAX, 1), (MIN, MIN, 0), (MIN, MAX, MIN), (-1, -1, 1)],
+    ),
+    (
+        SelfTestOp::ApplyGain,
+        [
+            (MAX, 0x100, MAX),
+            (MIN, -0x100, MAX),
+            (-1, 0x80, -1),
+            (0x1234, -0x8000, -0x9_1A00),
+        ],
+    ),
+    (
+        SelfTestOp::MulQ15,
+        [
+            (MAX, 0x7FFF, 0x7F_FEFF),
+            (MIN, -0x8000, MAX),
+            (-1, 0x4000, -1),
+            (0, -0x8000, 0),
+        ],
+    ),
+    (SelfTestOp::Div, DIV),
+    (
+        SelfTestOp::DivU8,
+        [
+            (MAX, 0, MAX),
+            (MIN, 0, MIN),
+            (MIN, 255, -32_896),
+            (-100_000, 7, -14_285),
+        ],
+    ),
+    (
+        SelfTestOp::Shl8Div,
+        [
+            (MAX, 3, MAX),
+            (MIN, 0, MIN),
+            (1, 3, 85),
+            (-0x1234, 7, -170_422),
+        ],
+    ),
+    (
+        SelfTestOp::ShlDiv,
+        [(MAX, 1, MAX), (MIN, -1, MAX), (-1, 0, MIN), (1, 3, 1365)],
+    ),
+    (SelfTestOp::Neg, NEG),
+    (SelfTestOp::Abs, ABS),
+    (
+        SelfTestOp::Isqrt,
+        [(MAX, 0, 2896), (-1, 0, 0), (0x40_0000, 0, 2048), (2, 0, 1)],
+    ),
+    (
+        SelfTestOp::Shl,
+        [
+            (MIN, 1, 0),
+            (MAX, 1, -2),
+            (-1, 23, MIN),
+            (0x12_3456, 4, 0x23_4560),
+        ],
+    ),
+    (
+        SelfTestOp::Shl8,
+        [
+            (MAX, 0, -0x100),
+            (MIN, 0, 0),
+            (-1, 0, -0x100),
+            (0x12_3456, 0, 0x34_5600),
+        ],
+    ),
+    (
+        SelfTestOp::Shl16,
+        [
+            (MAX, 0, -0x1_0000),
+            (MIN, 0, 0),
+            (-1, 0, -0x1_0000),
+            (0x12_3456, 0, 0x56_0000),
+        ],
+    ),
+    (
+        SelfTestOp::Shr,
+        [
+            (MIN, 23, -1),
+            (MAX, 4, 0x7_FFFF),
+            (-1, 1, -1),
+            (0x12_3456, 8, 0x1234),
+        ],
+    ),
+    (
+        SelfTestOp::Cmp,
+        [(MIN, MAX, -1), (MAX, MIN, 1), (5, 5, 0), (-1, 0, -1)],
+    ),
+    (SelfTestOp::AddInPlace, ADD),
+    (SelfTestOp::SubInPlace, SUB),
+    (SelfTestOp::AddBuffers, ADD),
+    (SelfTestOp::SubBuffers, SUB),
+    (SelfTestOp::NegBuffer, NEG),
+    (
+        SelfTestOp::Sum,
+        [
+            (MAX, MAX, MAX),
+            (MIN, MIN, MIN),
+            (MAX, MIN, -1),
+            (-1, 0, -1),
+        ],
+    ),
+    (
+        SelfTestOp::Mac,
+        [
+            (MAX, MAX, 0x7F_FFFE),
+            (MIN, MIN, MAX),
+            (MIN, MAX, -MAX),
+            (-1, MAX, -1),
+        ],
+    ),
+    (SelfTestOp::CtAdd, ADD),
+    (SelfTestOp::CtSub, SUB),
+    (SelfTestOp::CtMul, MUL),
+    (SelfTestOp::CtDiv, DIV),
+    (SelfTestOp::CtNeg, NEG),
+    (SelfTestOp::CtAbs, ABS),
+];
+
+/// Get the operations of [VECTORS].
+const fn ops() -> [SelfTestOp; VECTORS.len()] {
+    let mut ops = [SelfTestOp::Add; VECTORS.len()];
+    let mut i = 0;
+    while i < VECTORS.len() {
+        ops[i] = VECTORS[i].0;
+        i += 1;
+    }
+    ops
+}
+
+/// Get the vectors of [VECTORS] as a flat table of `a, b, expected`.
+const fn table() -> [Int24; VECTORS.len() * N * 3] {
+    let mut table = [Int24::zero(); VECTORS.len() * N * 3];
+    let mut i = 0;
+    while i < VECTORS.len() * N {
+        let (a, b, expected) = VECTORS[i / N].1[i % N];
+        table[i * 3] = Int24::from_i32(a);
+        table[i * 3 + 1] = Int24::from_i32(b);
+        table[i * 3 + 2] = Int24::from_i32(expected);
+        i += 1;
+    }
+    table
+}
+
+/// The operations in the order of the [TABLE].
+const OPS: [SelfTestOp; VECTORS.len()] = ops();
+
+int24_progmem! {
+    /// The known-answer vectors in program memory, so that they don't occupy SRAM.
+    static TABLE: [Int24; VECTORS.len() * N * 3] = table();
+}
+
+impl Int24 {
+    /// Run the built-in power-on self-test.
+    ///
+    /// A handful of known-answer vectors checks the saturation corners of the operations in [SelfTestOp].
+    /// Together they call every arithmetic assembly routine of the crate,
+    /// including the wide products, the accumulators, the buffer operations
+    /// and the constant time operations.
+    /// This is much smaller and faster than the full unit tests
+    /// and is meant to be run by the firmware at boot.
+    ///
+    /// Returns the first failed vector.
+    ///
+    /// With the `strict-overflow` feature the saturating vectors panic in debug builds.
+    ///
+    #[cfg_attr(feature = "strict-overflow", doc = "```no_run")]
+    #[cfg_attr(not(feature = "strict-overflow"), doc = "```")]
+    /// use avr_int24::Int24;
+    ///
+    /// if let Err(failure) = Int24::self_test() {
+    ///     panic!("{failure}");
+    /// }
+    /// ```
+    #[inline(never)]
+    pub fn self_test() -> Result<(), SelfTestFailure> {
+        let mut i = 0;
+        for op in OPS {
+            for _ in 0..N {
+                let (Some(a), Some(b), Some(expected)) =
+                    (TABLE.get(i), TABLE.get(i + 1), TABLE.get(i + 2))
+                else {
+                    break;
+                };
+                let result = op.eval(a, b);
+                if result != expected {
+                    return Err(SelfTestFailure {
+                        op,
+                        a,
+                        b,
+                        expected,
+                        result,
+                    });
+                }
+                i += 3;
+            }
+        }
+        Ok(())
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,139 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Statistics over slices of [Int24].
+//!
+//! All sums are accumulated with a wide accumulator, so that large buffers don't saturate.
+
+use crate::{
+    Int24, Int48,
+    raw::{mac24, sum24},
+};
+
+/// Get the sum of all elements of `data` with a wide accumulator.
+///
+/// The sum doesn't saturate for slices with less than 2^24 elements.
+///
+/// ```
+/// use avr_int24::{Int24, Int48, stats};
+///
+/// let data = [Int24::MAX, Int24::MAX];
+/// assert_eq!(stats::sum_wide(&data), Int48::from_i32(0xFF_FFFE));
+/// ```
+pub fn sum_wide(data: &[Int24]) -> Int48 {
+    Int48::from_i64(sum24(0, Int24::as_raw_slice(data)))
+}
+
+/// Get the sum of all elements of `data`, saturated to [Int24].
+///
+/// The sum is calculated with a wide accumulator and is only saturated at the end.
+/// Therefore, the result does not depend on the order of the elements.
+///
+/// ```
+/// use avr_int24::{Int24, stats};
+///
+/// let data = [Int24::MAX, Int24::MAX, Int24::MIN];
+/// assert_eq!(stats::sum_saturating(&data), Int24::from_i32(0x7F_FFFE));
+/// ```
+pub fn sum_saturating(data: &[Int24]) -> Int24 {
+    sum_wide(data).to_int24()
+}
+
+/// Get the dot product of `a` and `b`.
+///
+/// The full 48 bit products are accumulated in one fused multiply-accumulate loop.
+/// There is no intermediate saturation. Only the final sum is saturated to [Int48].
+///
+/// If the slices have different lengths, then the surplus elements of the longer slice are ignored.
+///
+/// ```
+/// use avr_int24::{Int24, Int48, stats};
+///
+/// let a = [Int24::from_i16(1), Int24::from_i16(2), Int24::from_i16(3)];
+/// let b = [Int24::from_i16(4), Int24::from_i16(-5), Int24::from_i16(6)];
+/// assert_eq!(stats::dot(&a, &b), Int48::from_i32(12));
+/// ```
+pub fn dot(a: &[Int24], b: &[Int24]) -> Int48 {
+    let a = Int24::as_raw_slice(a);
+    let b = Int24::as_raw_slice(b);
+    // Each chunk fits into the 56 bit accumulator of mac24.
+    let mut acc = 0_i64;
+    for (a, b) in a.chunks(255).zip(b.chunks(255)) {
+        acc = acc.saturating_add(mac24(0, a, b));
+    }
+    Int48::from_i64(acc)
+}
+
+/// Divide `a` by `b` and round to the nearest integer. Ties are rounded away from zero.
+///
+/// `b` must be positive. Otherwise the result is zero.
+fn div_round(a: i64, b: i64) -> i64 {
+    let a = if a < 0 { a - b / 2 } else { a + b / 2 };
+    a.checked_div(b).unwrap_or(0)
+}
+
+/// Get the arithmetic mean of `data`, rounded to the nearest integer.
+///
+/// Returns `None`, if `data` is empty.
+///
+/// ```
+/// use avr_int24::{Int24, stats};
+///
+/// let data = [Int24::from_i16(1), Int24::from_i16(2), Int24::from_i16(4)];
+/// assert_eq!(stats::mean(&data), Some(Int24::from_i16(2)));
+/// ```
+pub fn mean(data: &[Int24]) -> Option<Int24> {
+    if data.is_empty() {
+        None
+    } else {
+        let m = div_round(sum_wide(data).to_i64(), data.len() as i64);
+        Some(Int24::from_i32(m as i32))
+    }
+}
+
+/// Get the smallest element of `data`.
+///
+/// Returns `None`, if `data` is empty.
+pub fn min(data: &[Int24]) -> Option<Int24> {
+    data.iter().copied().min()
+}
+
+/// Get the biggest element of `data`.
+///
+/// Returns `None`, if `data` is empty.
+pub fn max(data: &[Int24]) -> Option<Int24> {
+    data.iter().copied().max()
+}
+
+/// Get the population variance of `data`, rounded to the nearest integer.
+///
+/// The squared deviations are accumulated with 64 bits.
+///
+/// Returns `None`, if `data` is empty.
+pub fn variance(data: &[Int24]) -> Option<Int48> {
+    if data.is_empty() {
+        return None;
+    }
+    let n = data.len() as i64;
+    let sum = sum_wide(data).to_i64();
+    let m = div_round(sum, n);
+    let mut acc = 0_u64;
+    for v in data {
+        let d = (v.to_i32() as i64 - m).unsigned_abs();
+        acc = acc.saturating_add(d * d);
+    }
+    // Correct the error of the rounded mean:
+    // variance = acc / n - (r / n)^2
+    let r = sum - m * n;
+    // `data` is not empty, so the divisions never fail.
+    let (q, f) = (
+        acc.checked_div(n as u64).unwrap_or(0),
+        acc.checked_rem(n as u64).unwrap_or(0),
+    );
+    let q = q.min(i64::MAX as u64) as i64;
+    let f = f as i64 * n - r * r;
+    Some(Int48::from_i64(q + div_round(f, n * n)))
+}
+
+// vim: ts=4 sw=4 expandtab
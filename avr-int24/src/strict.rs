@@ -0,0 +1,102 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::Int24;
+
+/// Panic with `msg`, if the flagged operation saturated.
+#[track_caller]
+fn strict(result: (Int24, bool), msg: &str) -> Int24 {
+    let (r, saturated) = result;
+    assert!(!saturated, "{}", msg);
+    r
+}
+
+/// Panicking arithmetic for host side tooling and tests.
+///
+/// These operations are only available with the `strict-ops` feature.
+/// Unlike all other operations of this crate, they panic,
+/// if the mathematical result does not fit into 24 bits.
+/// The panics are independent of `debug_assertions`.
+impl Int24 {
+    /// Add two [Int24].
+    ///
+    /// Panics, if the sum does not fit.
+    ///
+    /// ```should_panic
+    /// use avr_int24::Int24;
+    ///
+    /// Int24::MAX.strict_add(Int24::from_i8(1));
+    /// ```
+    #[track_caller]
+    pub fn strict_add(self, other: Self) -> Self {
+        strict(self.add_flagged(other), "Int24 addition overflowed")
+    }
+
+    /// Subtract two [Int24].
+    ///
+    /// Panics, if the difference does not fit.
+    #[track_caller]
+    pub fn strict_sub(self, other: Self) -> Self {
+        strict(self.sub_flagged(other), "Int24 subtraction overflowed")
+    }
+
+    /// Multiply two [Int24].
+    ///
+    /// Panics, if the product does not fit.
+    ///
+    /// ```should_panic
+    /// use avr_int24::Int24;
+    ///
+    /// Int24::from_i16(30_000).strict_mul(Int24::from_i16(1_000));
+    /// ```
+    #[track_caller]
+    pub fn strict_mul(self, other: Self) -> Self {
+        strict(self.mul_flagged(other), "Int24 multiplication overflowed")
+    }
+
+    /// Multiply two [Int24] and arithmetically right shift the product by `count` bits.
+    ///
+    /// Panics, if the shifted product does not fit.
+    #[track_caller]
+    pub fn strict_mul_shr(self, other: Self, count: u8) -> Self {
+        strict(
+            self.mul_shr_flagged(other, count),
+            "Int24 multiplication overflowed",
+        )
+    }
+
+    /// Divide two [Int24].
+    ///
+    /// Panics on division by zero and on `MIN / -1`.
+    #[track_caller]
+    pub fn strict_div(self, other: Self) -> Self {
+        strict(self.div_flagged(other), "Int24 division overflowed")
+    }
+
+    /// Left shift `self` by 8 bits and then divide the shifted value by `other`.
+    ///
+    /// Panics on division by zero and if the quotient does not fit.
+    #[track_caller]
+    pub fn strict_shl8div(self, other: Self) -> Self {
+        strict(self.shl8div_flagged(other), "Int24 division overflowed")
+    }
+
+    /// Two's complement negate `self`.
+    ///
+    /// Panics, if `self` is [Int24::MIN].
+    #[track_caller]
+    pub fn strict_neg(self) -> Self {
+        strict(self.neg_flagged(), "Int24 negation overflowed")
+    }
+
+    /// Get the absolute value of `self`.
+    ///
+    /// Panics, if `self` is [Int24::MIN].
+    #[track_caller]
+    pub fn strict_abs(self) -> Self {
+        strict(self.abs_flagged(), "Int24 absolute value overflowed")
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
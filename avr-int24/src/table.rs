@@ -0,0 +1,73 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, float::Rounding};
+
+impl Int24 {
+    /// Convert a table value of [int24_table](crate::int24_table) to [Int24].
+    ///
+    /// The value is rounded to the nearest integer with ties away from zero and saturated.
+    /// NaN is converted to zero.
+    #[doc(hidden)]
+    pub const fn __from_table_f64(v: f64) -> Self {
+        Self::round_f64(v, Rounding::NearestAway)
+    }
+}
+
+/// Generate a `[Int24; N]` table at compile time from a floating point expression.
+///
+/// The expression is evaluated as `f64` for each index `i` from `0` to `len - 1` of type `usize`.
+/// The values are rounded to the nearest integer with ties away from zero
+/// and saturated to the [Int24](crate::Int24) range.
+///
+/// The whole table is evaluated in `const` context, so no floating point code ends up
+/// in the firmware.
+/// Therefore, the expression can only use `const` operations.
+/// Those include the arithmetic operators and `abs`,
+/// but no transcendental functions like `sin`.
+///
+/// ```
+/// use avr_int24::{Int24, int24_table};
+///
+/// // Linear ramp from 0.0 to 1.0 in Q0.23.
+/// const RAMP: [Int24; 256] = int24_table!(len = 256, |i| i as f64 / 255.0 * 8_388_607.0);
+/// assert_eq!(RAMP[0], Int24::zero());
+/// assert_eq!(RAMP[255], Int24::MAX);
+///
+/// // Gamma 2.0 curve in Q0.23.
+/// const GAMMA: [Int24; 5] = int24_table!(len = 5, |i| {
+///     let x = i as f64 / 4.0;
+///     x * x * 8_388_607.0
+/// });
+/// assert_eq!(GAMMA[2], Int24::from_i32(2_097_152));
+/// ```
+///
+/// The table can be placed in program memory with [int24_progmem](crate::int24_progmem):
+///
+/// ```
+/// use avr_int24::{Int24, int24_progmem, int24_table};
+///
+/// int24_progmem! {
+///     static SQUARES: [Int24; 16] = int24_table!(len = 16, |i| (i * i) as f64);
+/// }
+///
+/// assert_eq!(SQUARES.get(15), Some(Int24::from_i16(225)));
+/// ```
+#[macro_export]
+macro_rules! int24_table {
+    (len = $n:expr, |$i:pat_param| $value:expr $(,)?) => {
+        const {
+            let mut table = [$crate::Int24::zero(); $n];
+            let mut index: usize = 0;
+            while index < $n {
+                let $i: usize = index;
+                table[index] = $crate::Int24::__from_table_f64($value);
+                index += 1;
+            }
+            table
+        }
+    };
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,166 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, Int48};
+
+/// Saturating signed integer operations shared by the integer types of this crate.
+///
+/// This allows writing code once generically over [Int24] and [Int48].
+/// All operations saturate the result to the range of the type,
+/// except for the right shift, which can't overflow.
+///
+/// ```
+/// use avr_int24::{Int24, Int48, SaturatingInt};
+///
+/// fn mean<T: SaturatingInt>(a: T, b: T) -> T {
+///     a.saturating_add(b).shr(1)
+/// }
+///
+/// assert_eq!(mean(Int24::from_i16(10), Int24::from_i16(20)), Int24::from_i16(15));
+/// assert_eq!(mean(Int48::MAX, Int48::MAX), Int48::MAX.shr(1));
+/// ```
+pub trait SaturatingInt: Copy + Eq + Ord + core::fmt::Debug {
+    /// The smallest value of the type.
+    const MIN: Self;
+
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// Construct a new zero value.
+    fn zero() -> Self;
+
+    /// Construct and saturate a new value from a signed 32 bit integer.
+    fn from_i32(v: i32) -> Self;
+
+    /// Convert to a signed 64 bit integer.
+    fn to_i64(self) -> i64;
+
+    /// Add and saturate.
+    fn saturating_add(self, other: Self) -> Self;
+
+    /// Subtract and saturate.
+    fn saturating_sub(self, other: Self) -> Self;
+
+    /// Multiply and saturate.
+    fn saturating_mul(self, other: Self) -> Self;
+
+    /// Divide and saturate. Division by zero saturates.
+    fn saturating_div(self, other: Self) -> Self;
+
+    /// Negate and saturate.
+    fn saturating_neg(self) -> Self;
+
+    /// Get the saturated absolute value.
+    fn saturating_abs(self) -> Self;
+
+    /// Left shift and saturate by `count` bits.
+    fn saturating_shl(self, count: u8) -> Self;
+
+    /// Arithmetic right shift by `count` bits.
+    ///
+    /// Shift counts bigger than the number of bits minus one are treated as the number of bits minus one.
+    fn shr(self, count: u8) -> Self;
+}
+
+impl SaturatingInt for Int24 {
+    const MIN: Self = Int24::MIN;
+    const MAX: Self = Int24::MAX;
+
+    fn zero() -> Self {
+        Int24::zero()
+    }
+
+    fn from_i32(v: i32) -> Self {
+        Int24::from_i32(v)
+    }
+
+    fn to_i64(self) -> i64 {
+        self.to_i32() as i64
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        self.add(other)
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        self.sub(other)
+    }
+
+    fn saturating_mul(self, other: Self) -> Self {
+        self.mul(other)
+    }
+
+    fn saturating_div(self, other: Self) -> Self {
+        self.div(other)
+    }
+
+    fn saturating_neg(self) -> Self {
+        self.neg()
+    }
+
+    fn saturating_abs(self) -> Self {
+        self.abs()
+    }
+
+    /// The left shift of [Int24] doesn't saturate.
+    /// Therefore, the shift is done with [Int48] and the result is saturated.
+    fn saturating_shl(self, count: u8) -> Self {
+        Int48::from_int24(self).shl(count.min(24)).to_int24()
+    }
+
+    fn shr(self, count: u8) -> Self {
+        Int24::shr(self, count.min(23))
+    }
+}
+
+impl SaturatingInt for Int48 {
+    const MIN: Self = Int48::MIN;
+    const MAX: Self = Int48::MAX;
+
+    fn zero() -> Self {
+        Int48::zero()
+    }
+
+    fn from_i32(v: i32) -> Self {
+        Int48::from_i32(v)
+    }
+
+    fn to_i64(self) -> i64 {
+        Int48::to_i64(self)
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        self.add(other)
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        self.sub(other)
+    }
+
+    fn saturating_mul(self, other: Self) -> Self {
+        self.mul(other)
+    }
+
+    fn saturating_div(self, other: Self) -> Self {
+        self.div(other)
+    }
+
+    fn saturating_neg(self) -> Self {
+        self.neg()
+    }
+
+    fn saturating_abs(self) -> Self {
+        self.abs()
+    }
+
+    fn saturating_shl(self, count: u8) -> Self {
+        self.shl(count)
+    }
+
+    fn shr(self, count: u8) -> Self {
+        Int48::shr(self, count)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
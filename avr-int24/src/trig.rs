@@ -0,0 +1,145 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Frac24, Int24};
+
+/// Number of CORDIC iterations.
+const CORDIC_ITER: usize = 24;
+
+/// `atan(2^-i)` in units of `2^-32` turns.
+const CORDIC_ATAN: [i32; CORDIC_ITER] = [
+    536870912, 316933406, 167458907, 85004756, 42667331, 21354465, 10679838, 5340245, 2670163,
+    1335087, 667544, 333772, 166886, 83443, 41722, 20861, 10430, 5215, 2608, 1304, 652, 326, 163,
+    81,
+];
+
+/// Inverse CORDIC gain `1 / K` as Q2.30.
+const CORDIC_INV_GAIN: i32 = 0x26DD_3B6A;
+
+/// Convert a Q2.30 CORDIC result to a rounded and saturated [Frac24].
+fn q30_to_frac24(v: i32) -> Frac24 {
+    Frac24::from_bits(Int24::from_i32((v + (1 << 6)) >> 7))
+}
+
+impl Frac24 {
+    /// Calculate the sine and cosine of `angle`.
+    ///
+    /// `angle` is a fraction of a full turn: The full [Int24] range corresponds to one turn.
+    /// For example `0x40_0000` is a quarter turn (90 degrees) and `-0x40_0000` is -90 degrees.
+    ///
+    /// The results are calculated with CORDIC and are accurate to 2 LSBs.
+    /// A result of 1.0 is saturated to [Frac24::MAX].
+    ///
+    /// ```
+    /// use avr_int24::{Frac24, Int24};
+    ///
+    /// let (sin, cos) = Frac24::sin_cos(Int24::from_i32(0x40_0000));
+    /// assert_eq!(sin, Frac24::MAX);
+    /// assert!(cos.to_bits().to_i32().abs() <= 2);
+    /// ```
+    #[inline(never)]
+    pub fn sin_cos(angle: Int24) -> (Self, Self) {
+        // Angle in units of 2^-32 turns.
+        let mut z = angle.to_i32() << 8;
+
+        // Rotate by half a turn, if the angle is outside of -90..90 degrees.
+        let flip = !(-(1 << 30)..=(1 << 30)).contains(&z);
+        if flip {
+            z = z.wrapping_add(i32::MIN);
+        }
+
+        let mut x = CORDIC_INV_GAIN;
+        let mut y = 0_i32;
+        for (i, atan) in CORDIC_ATAN.iter().enumerate() {
+            let dx = y >> i;
+            let dy = x >> i;
+            if z >= 0 {
+                x -= dx;
+                y += dy;
+                z -= atan;
+            } else {
+                x += dx;
+                y -= dy;
+                z += atan;
+            }
+        }
+        if flip {
+            x = -x;
+            y = -y;
+        }
+        (q30_to_frac24(y), q30_to_frac24(x))
+    }
+
+    /// Calculate the sine of `angle`.
+    ///
+    /// See [Frac24::sin_cos].
+    pub fn sin(angle: Int24) -> Self {
+        Self::sin_cos(angle).0
+    }
+
+    /// Calculate the cosine of `angle`.
+    ///
+    /// See [Frac24::sin_cos].
+    pub fn cos(angle: Int24) -> Self {
+        Self::sin_cos(angle).1
+    }
+}
+
+impl Int24 {
+    /// Calculate the four quadrant arctangent of `self` (y) and `x`.
+    ///
+    /// The returned angle is a fraction of a full turn: The full [Int24] range corresponds to one turn.
+    /// For example `0x40_0000` is a quarter turn (90 degrees) and [Int24::MIN] is -180 degrees.
+    ///
+    /// The result is calculated with CORDIC and is accurate to 1 LSB.
+    /// `atan2(0, 0)` returns 0.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let a = Int24::from_i16(100).atan2(Int24::zero());
+    /// assert_eq!(a, Int24::from_i32(0x40_0000));
+    /// ```
+    #[inline(never)]
+    pub fn atan2(self, x: Self) -> Self {
+        // Normalize the vector to 29 bits for best precision.
+        // The CORDIC gain of 1.65 still fits.
+        let mut x = x.to_i32();
+        let mut y = self.to_i32();
+        if x == 0 && y == 0 {
+            return Self::zero();
+        }
+        let shift = (x.unsigned_abs() | y.unsigned_abs())
+            .leading_zeros()
+            .saturating_sub(3);
+        x <<= shift;
+        y <<= shift;
+
+        // Angle in units of 2^-32 turns.
+        // Rotate by half a turn, if the vector is in the left half plane.
+        let mut z = 0_i32;
+        if x < 0 {
+            x = -x;
+            y = -y;
+            z = i32::MIN;
+        }
+
+        for (i, atan) in CORDIC_ATAN.iter().enumerate() {
+            let dx = y >> i;
+            let dy = x >> i;
+            if y > 0 {
+                x += dx;
+                y -= dy;
+                z = z.wrapping_add(*atan);
+            } else {
+                x -= dx;
+                y += dy;
+                z = z.wrapping_sub(*atan);
+            }
+        }
+        Self::from_i32(z.wrapping_add(1 << 7) >> 8)
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -2,13 +2,167 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 // Copyright (C) 2025 Michael Büsch <m@bues.ch>
 
-use crate::Int24;
+use core::cell::Cell;
+
+use crate::{
+    Angle24, Biquad24, ByteOrder, Calibration, Complex24, DivisorInt24, Ema24, Error,
+    FORMAT_BUF_LEN, Fir24, Fix24, Frac24, Hysteresis, I8F16, I16F8, Int24, Int24Cell, Int24Flags,
+    Int24Iter, Int24Slice, Int24SliceMut, Int24Writer, Int48, Lut24, Mat3x3, Nco24, PERSIST_LEN,
+    Pid24, Ratio24, SaturatingInt, SaturatingInt24, SlewLimiter, Vec2, Vec3, VolatileInt24,
+    WrappingInt24, XorShift24,
+    adc::{self, FrameFormat, Hx711Gain},
+    add_buffers, bisect, decode_be_slice, decode_le_slice, encode_be_slice, encode_le_slice,
+    int24_progmem, int24_table,
+    modbus::WordOrder,
+    neg_buffer, poly_eval, stats, sub_buffers, volatile,
+};
+
+/// Behavior of the test run after a failed assertion.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum FailPolicy {
+    /// Panic at the first failed assertion.
+    #[default]
+    Halt,
+    /// Continue the test run and count the failure.
+    Continue,
+}
+
+/// Summary of a test run.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct TestSummary {
+    /// Number of tests run.
+    pub tests: u16,
+    /// Number of passed assertions.
+    pub passed: u32,
+    /// Number of failed assertions.
+    pub failed: u32,
+}
+
+impl TestSummary {
+    /// Returns `true`, if no assertion failed.
+    pub fn ok(&self) -> bool {
+        self.failed == 0
+    }
+}
 
 pub trait TestOps {
+    /// Compile time selection of the tests with the syntax of the filter of [run_tests_filtered].
+    ///
+    /// The code of the tests that are not selected is not linked into the program.
+    const FILTER: &'static str = "";
+    /// Number of parts the tests are split into at compile time.
+    ///
+    /// The whole test suite doesn't fit into the program memory of the AVR devices.
+    /// The tests are distributed round-robin over the parts.
+    const PARTS: u8 = 1;
+    /// The part of the tests to run, in the range `0..PARTS`.
+    const PART: u8 = 0;
+
     fn print(&self, text: &str);
     fn print_num(&self, value: u32);
     fn begin(&self, name: &str);
+    /// Report the result of the assertion in `line`.
     fn assert(&self, line: u16, ok: bool);
+    /// Get the behavior after a failed assertion.
+    fn fail_policy(&self) -> FailPolicy {
+        FailPolicy::Halt
+    }
+    /// Get the seed of the randomized tests.
+    ///
+    /// Return a different seed for each run to cover more operands over time.
+    /// The seed is printed at the beginning of the randomized tests,
+    /// so that a failing run can be reproduced.
+    fn random_seed(&self) -> u32 {
+        0x2545_F491
+    }
+    /// Start the time measurement of a test.
+    ///
+    /// This is called right before each test.
+    fn time_start(&self) {}
+    /// Stop the time measurement of a test and get the duration of the test.
+    ///
+    /// The unit of the duration is defined by the implementation, e.g. timer ticks.
+    /// Return `None`, if time measurement is not supported.
+    /// Then no durations are reported.
+    fn time_end(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// Wrapper around the [TestOps] of the caller that counts the tests and assertions.
+struct Counter<'a, T> {
+    t: &'a T,
+    summary: Cell<TestSummary>,
+}
+
+impl<'a, T: TestOps> Counter<'a, T> {
+    fn new(t: &'a T) -> Self {
+        Self {
+            t,
+            summary: Cell::new(TestSummary::default()),
+        }
+    }
+}
+
+impl<T: TestOps> TestOps for Counter<'_, T> {
+    #[inline(never)]
+    fn print(&self, text: &str) {
+        self.t.print(text);
+    }
+
+    #[inline(never)]
+    fn print_num(&self, value: u32) {
+        self.t.print_num(value);
+    }
+
+    #[inline(never)]
+    fn begin(&self, name: &str) {
+        let mut s = self.summary.get();
+        s.tests = s.tests.saturating_add(1);
+        self.summary.set(s);
+        self.t.begin(name);
+    }
+
+    #[inline(never)]
+    fn assert(&self, line: u16, ok: bool) {
+        let mut s = self.summary.get();
+        if ok {
+            s.passed = s.passed.saturating_add(1);
+        } else {
+            s.failed = s.failed.saturating_add(1);
+        }
+        self.summary.set(s);
+        self.t.assert(line, ok);
+        if !ok && self.t.fail_policy() == FailPolicy::Halt {
+            panic!("unit test assertion failed");
+        }
+    }
+
+    #[inline(never)]
+    fn fail_policy(&self) -> FailPolicy {
+        self.t.fail_policy()
+    }
+
+    #[inline(never)]
+    fn random_seed(&self) -> u32 {
+        self.t.random_seed()
+    }
+
+    #[inline(never)]
+    fn time_start(&self) {
+        self.t.time_start();
+    }
+
+    #[inline(never)]
+    fn time_end(&self) -> Option<u32> {
+        let duration = self.t.time_end();
+        if let Some(duration) = duration {
+            self.print("Time: ");
+            self.print_num(duration);
+            self.print("\n");
+        }
+        duration
+    }
 }
 
 macro_rules! test_assert {
@@ -39,6 +193,14 @@ fn test_base(t: &impl TestOps) {
     let a = [1, 2, 3];
     let b = Int24::from_le_bytes([1, 2, 3]).to_le_bytes();
     test_assert!(t, a == b);
+
+    let a = 0x010203;
+    let b = Int24::from_be_bytes([1, 2, 3]).to_i32();
+    test_assert!(t, a == b);
+
+    let a = [3, 2, 1];
+    let b = Int24::from_le_bytes([1, 2, 3]).to_be_bytes();
+    test_assert!(t, a == b);
 }
 
 fn test_conv_i8(t: &impl TestOps) {
@@ -276,6 +438,85 @@ fn test_mul(t: &impl TestOps) {
     test_assert!(t, a.const_mul(b) == c);
 }
 
+fn test_mul_shr(t: &impl TestOps) {
+    t.begin("mul_shr");
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(1000);
+    let c = Int24::from_i32(1562500);
+    test_assert!(t, a.mul_shr(b, 6) == c);
+    test_assert!(t, a.const_mul_shr(b, 6) == c);
+
+    let a = Int24::from_i32(-100000);
+    let b = Int24::from_i32(1000);
+    let c = Int24::from_i32(-1562500);
+    test_assert!(t, a.mul_shr(b, 6) == c);
+    test_assert!(t, a.const_mul_shr(b, 6) == c);
+
+    let a = Int24::from_i32(-3);
+    let b = Int24::from_i32(1);
+    let c = Int24::from_i32(-2); // rounded towards -inf
+    test_assert!(t, a.mul_shr(b, 1) == c);
+    test_assert!(t, a.const_mul_shr(b, 1) == c);
+
+    let a = Int24::from_i32(0x7F_FFFF);
+    let b = Int24::from_i32(0x7F_FFFF);
+    let c = Int24::from_i32(0x7F_FFFF); // sat
+    test_assert!(t, a.mul_shr(b, 8) == c);
+    test_assert!(t, a.const_mul_shr(b, 8) == c);
+    let c = Int24::from_i32(0x3F_FFFF);
+    test_assert!(t, a.mul_shr(b, 24) == c);
+    test_assert!(t, a.const_mul_shr(b, 24) == c);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = Int24::from_i32(-0x80_0000);
+    let c = Int24::from_i32(0x40_0000);
+    test_assert!(t, a.mul_shr(b, 24) == c);
+    test_assert!(t, a.const_mul_shr(b, 24) == c);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = Int24::from_i32(0x7F_FFFF);
+    let c = Int24::from_i32(-0x80_0000); // sat
+    test_assert!(t, a.mul_shr(b, 22) == c);
+    test_assert!(t, a.const_mul_shr(b, 22) == c);
+    let c = Int24::from_i32(-1);
+    test_assert!(t, a.mul_shr(b, 47) == c);
+    test_assert!(t, a.mul_shr(b, 200) == c);
+    test_assert!(t, a.const_mul_shr(b, 200) == c);
+}
+
+fn test_mul_const(t: &impl TestOps) {
+    t.begin("mul_const");
+
+    let a = Int24::from_i32(1000);
+    test_assert!(t, a.mul_const::<10>() == Int24::from_i32(10000));
+    test_assert!(t, a.mul_const::<100>() == Int24::from_i32(100000));
+    test_assert!(t, a.mul_const::<256>() == Int24::from_i32(256000));
+    test_assert!(t, a.mul_const::<-3>() == Int24::from_i32(-3000));
+    test_assert!(t, a.mul_const::<0>() == Int24::zero());
+    test_assert!(t, a.mul_const::<1>() == a);
+
+    let a = Int24::from_i32(-1000);
+    test_assert!(t, a.mul_const::<100>() == Int24::from_i32(-100000));
+    test_assert!(t, a.mul_const::<-100>() == Int24::from_i32(100000));
+
+    let a = Int24::from_i32(0x7F_FFFF / 10);
+    let b = Int24::from_i32(0x7F_FFFF / 10 + 1);
+    test_assert!(t, a.mul_const::<10>() == a.const_mul(Int24::from_i32(10)));
+    test_assert!(t, b.mul_const::<10>() == Int24::from_i32(0x7F_FFFF)); // sat
+    test_assert!(t, b.mul_const::<-10>() == Int24::from_i32(-0x80_0000)); // sat
+
+    let a = Int24::from_i32(-0x80_0000 / 10);
+    let b = Int24::from_i32(-0x80_0000 / 10 - 1);
+    test_assert!(t, a.mul_const::<10>() == a.const_mul(Int24::from_i32(10)));
+    test_assert!(t, b.mul_const::<10>() == Int24::from_i32(-0x80_0000)); // sat
+    test_assert!(t, b.mul_const::<-10>() == Int24::from_i32(0x7F_FFFF)); // sat
+
+    let a = Int24::from_i32(-0x80_0000);
+    test_assert!(t, a.mul_const::<-1>() == Int24::from_i32(0x7F_FFFF)); // sat
+    test_assert!(t, a.mul_const::<1>() == a);
+}
+
 fn test_div(t: &impl TestOps) {
     t.begin("div");
 
@@ -302,6 +543,124 @@ fn test_div(t: &impl TestOps) {
     let c = Int24::from_i32(0x7F_FFFF); // sat
     test_assert!(t, a / b == c);
     test_assert!(t, a.const_div(b) == c);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = Int24::from_i32(1);
+    let c = Int24::from_i32(-0x80_0000);
+    test_assert!(t, a / b == c);
+    test_assert!(t, a.const_div(b) == c);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = Int24::from_i32(3);
+    let c = Int24::from_i32(-2796202);
+    test_assert!(t, a / b == c);
+    test_assert!(t, a.const_div(b) == c);
+}
+
+fn test_checked_div(t: &impl TestOps) {
+    t.begin("checked_div");
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(1010);
+    test_assert!(t, a.checked_div(b) == Some(Int24::from_i32(99)));
+    test_assert!(t, a.checked_div(Int24::zero()).is_none());
+    test_assert!(t, a.checked_shl8div(b) == Some(Int24::from_i32(25346)));
+    test_assert!(t, a.checked_shl8div(Int24::zero()).is_none());
+    test_assert!(
+        t,
+        Int24::MIN.checked_div(Int24::from_i32(-1)) == Some(Int24::MAX)
+    ); // sat
+
+    let c = a.div_or_else(b, |_| Int24::from_i32(42));
+    test_assert!(t, c == Int24::from_i32(99));
+    let c = a.div_or_else(Int24::zero(), |_| Int24::from_i32(42));
+    test_assert!(t, c == Int24::from_i32(42));
+    let c = a.div_or_else(Int24::zero(), |x| x);
+    test_assert!(t, c == a);
+}
+
+fn test_shl_div(t: &impl TestOps) {
+    t.begin("shl_div");
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(1010);
+    let c = Int24::from_i32(25346);
+    test_assert!(t, a.shl_div(b, 8) == c);
+    test_assert!(t, a.const_shl_div(b, 8) == c);
+    let c = Int24::from_i32(99);
+    test_assert!(t, a.shl_div(b, 0) == c);
+    test_assert!(t, a.const_shl_div(b, 0) == c);
+
+    let a = Int24::from_i32(-1000);
+    let b = Int24::from_i32(3);
+    test_assert!(t, a.shl_div(b, 16) == Int24::MIN); // sat
+    test_assert!(t, a.const_shl_div(b, 16) == Int24::MIN); // sat
+    let b = Int24::from_i32(30);
+    let c = Int24::from_i32(-2184533);
+    test_assert!(t, a.shl_div(b, 16) == c);
+    test_assert!(t, a.const_shl_div(b, 16) == c);
+
+    let a = Int24::from_i32(1);
+    let b = Int24::from_i32(3);
+    let c = Int24::from_i32(5592405);
+    test_assert!(t, a.shl_div(b, 24) == c);
+    test_assert!(t, a.shl_div(b, 100) == c);
+    test_assert!(t, a.const_shl_div(b, 100) == c);
+    test_assert!(t, a.shl_div(Int24::zero(), 16) == Int24::MAX); // sat
+    test_assert!(t, (-a).const_shl_div(Int24::zero(), 16) == Int24::MIN); // sat
+}
+
+fn test_divisor(t: &impl TestOps) {
+    t.begin("divisor");
+
+    let a = Int24::from_i32(100000);
+    let b = DivisorInt24::new(Int24::from_i32(1010));
+    let c = Int24::from_i32(99);
+    test_assert!(t, b.divide(a) == c);
+    test_assert!(t, a / b == c);
+
+    let a = Int24::from_i32(100000);
+    let b = DivisorInt24::new(Int24::from_i32(-1010));
+    let c = Int24::from_i32(-99);
+    test_assert!(t, b.divide(a) == c);
+
+    let a = Int24::from_i32(-100000);
+    let b = DivisorInt24::new(Int24::from_i32(7));
+    let c = Int24::from_i32(-14285);
+    test_assert!(t, b.divide(a) == c);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = DivisorInt24::new(Int24::from_i32(3));
+    let c = Int24::from_i32(-2796202);
+    test_assert!(t, b.divide(a) == c);
+
+    let a = Int24::from_i32(0x7F_FFFF);
+    let b = DivisorInt24::new(Int24::from_i32(0x40_0000));
+    let c = Int24::from_i32(1);
+    test_assert!(t, b.divide(a) == c);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = DivisorInt24::new(Int24::from_i32(-0x80_0000));
+    let c = Int24::from_i32(1);
+    test_assert!(t, b.divide(a) == c);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = DivisorInt24::new(Int24::from_i32(-1));
+    let c = Int24::from_i32(0x7F_FFFF); // sat
+    test_assert!(t, b.divide(a) == c);
+
+    let a = Int24::from_i32(-5);
+    let b = DivisorInt24::new(Int24::from_i32(0));
+    let c = Int24::from_i32(-0x80_0000); // sat
+    test_assert!(t, b.divide(a) == c);
+
+    let mut a = -0x80_0000;
+    while a < 0x7F_0000 {
+        let b = DivisorInt24::new(Int24::from_i32(a / 0x1_0000 + 1));
+        let c = Int24::from_i32(a);
+        test_assert!(t, b.divide(c) == c / b.divisor());
+        a += 0x0F_1234;
+    }
 }
 
 fn test_shl8div(t: &impl TestOps) {
@@ -330,164 +689,3685 @@ fn test_shl8div(t: &impl TestOps) {
     let c = Int24::from_i32(0x7FFFFF);
     test_assert!(t, a.shl8div(b) == c);
     test_assert!(t, a.const_shl8div(b) == c);
+
+    let a = Int24::from_i32(88846);
+    let b = Int24::from_i32(2);
+    let c = Int24::from_i32(0x7FFFFF); // sat
+    test_assert!(t, a.shl8div(b) == c);
+    test_assert!(t, a.const_shl8div(b) == c);
+
+    let a = Int24::from_i32(88846);
+    let b = Int24::from_i32(-2);
+    let c = Int24::from_i32(-0x800000); // sat
+    test_assert!(t, a.shl8div(b) == c);
+    test_assert!(t, a.const_shl8div(b) == c);
+
+    let a = Int24::from_i32(-0x800000);
+    let b = Int24::from_i32(256);
+    let c = Int24::from_i32(-0x800000);
+    test_assert!(t, a.shl8div(b) == c);
+    test_assert!(t, a.const_shl8div(b) == c);
 }
 
-fn test_neg(t: &impl TestOps) {
-    t.begin("neg");
+fn test_i16f8(t: &impl TestOps) {
+    t.begin("i16f8");
 
-    let a = Int24::from_i32(100000);
-    let b = Int24::from_i32(-100000);
-    test_assert!(t, -a == b);
-    test_assert!(t, a.const_neg() == b);
+    let a = I16F8::from_int(3);
+    let b = I16F8::from_bits(Int24::from_i32(0x80)); // 0.5
+    test_assert!(t, a.to_int() == 3 && a.to_bits() == Int24::from_i32(0x300));
+    test_assert!(t, a + b == I16F8::from_bits(Int24::from_i32(0x380)));
+    test_assert!(t, a - b == I16F8::from_bits(Int24::from_i32(0x280)));
+    test_assert!(t, a * b == I16F8::from_bits(Int24::from_i32(0x180)));
+    test_assert!(t, a / b == I16F8::from_int(6));
+    test_assert!(t, -a == I16F8::from_int(-3));
+    test_assert!(t, (-a).abs() == a);
+    test_assert!(t, (-b).to_int() == -1);
+    test_assert!(t, b.to_int() == 0);
 
-    let a = Int24::from_i32(-100000);
-    let b = Int24::from_i32(100000);
-    test_assert!(t, -a == b);
-    test_assert!(t, a.const_neg() == b);
+    let a = I16F8::from_int(-300);
+    let b = I16F8::from_int(200);
+    test_assert!(t, a * b == I16F8::MIN); // sat
+    test_assert!(t, b * b == I16F8::MAX); // sat
+    test_assert!(t, a / I16F8::ZERO == I16F8::MIN); // sat
+    test_assert!(t, a * I16F8::ONE == a);
+    test_assert!(t, I16F8::MAX / I16F8::ONE == I16F8::MAX);
+    test_assert!(t, I16F8::MAX.to_int() == i16::MAX);
+    test_assert!(t, I16F8::MIN.to_int() == i16::MIN);
+    test_assert!(t, I16F8::from(-5_i16) < I16F8::from(4_i16));
+}
 
-    let a = Int24::from_i32(0x7F_FFFF);
-    let b = Int24::from_i32(-0x7F_FFFF);
-    test_assert!(t, -a == b);
-    test_assert!(t, a.const_neg() == b);
+fn test_i8f16(t: &impl TestOps) {
+    t.begin("i8f16");
 
-    let a = Int24::from_i32(-0x7F_FFFF);
-    let b = Int24::from_i32(0x7F_FFFF);
-    test_assert!(t, -a == b);
-    test_assert!(t, a.const_neg() == b);
+    let a = I8F16::from_int(3);
+    let b = I8F16::from_bits(Int24::from_i32(0x4000)); // 0.25
+    test_assert!(
+        t,
+        a.to_int() == 3 && a.to_bits() == Int24::from_i32(0x3_0000)
+    );
+    test_assert!(t, a + b == I8F16::from_bits(Int24::from_i32(0x3_4000)));
+    test_assert!(t, a - b == I8F16::from_bits(Int24::from_i32(0x2_C000)));
+    test_assert!(t, a * b == I8F16::from_bits(Int24::from_i32(0xC000)));
+    test_assert!(t, a / b == I8F16::from_int(12));
+    test_assert!(t, -a == I8F16::from_int(-3));
+    test_assert!(t, (-b).to_i16() == -1);
 
-    let a = Int24::from_i32(-0x80_0000);
-    let b = Int24::from_i32(0x7F_FFFF); // saturated
-    test_assert!(t, -a == b);
-    test_assert!(t, a.const_neg() == b);
+    let a = I8F16::from_int(-100);
+    let b = I8F16::from_int(100);
+    test_assert!(t, a * b == I8F16::MIN); // sat
+    test_assert!(
+        t,
+        b / I8F16::from_bits(Int24::from_i32(0x8000)) == I8F16::MAX
+    ); // sat
+    test_assert!(t, a / I8F16::ZERO == I8F16::MIN); // sat
+    test_assert!(t, a * I8F16::ONE == a);
+    test_assert!(t, a / I8F16::ONE == a);
+
+    test_assert!(t, I8F16::from_i16(1000) == I8F16::MAX); // sat
+    test_assert!(t, I8F16::from_i16(-1000) == I8F16::MIN); // sat
+    test_assert!(t, I8F16::from_i16(-5).to_i16() == -5);
+
+    let a = I16F8::from_bits(Int24::from_i32(0x1_80)); // 1.5
+    test_assert!(
+        t,
+        I8F16::from(a) == I8F16::from_bits(Int24::from_i32(0x1_8000))
+    );
+    test_assert!(t, I16F8::from(I8F16::from(a)) == a);
+    test_assert!(t, I8F16::from(I16F8::from_int(1000)) == I8F16::MAX); // sat
+    test_assert!(t, I8F16::from(I16F8::from_int(-1000)) == I8F16::MIN); // sat
 }
 
-fn test_abs(t: &impl TestOps) {
-    t.begin("abs");
+fn test_frac24(t: &impl TestOps) {
+    t.begin("frac24");
 
-    let a = Int24::from_i32(100000);
-    let b = Int24::from_i32(100000);
-    test_assert!(t, a.abs() == b);
-    test_assert!(t, a.const_abs() == b);
+    let a = Frac24::HALF;
+    let b = Frac24::from_bits(Int24::from_i32(0x20_0000)); // 0.25
+    test_assert!(t, a * a == b);
+    test_assert!(t, a * -a == -b);
+    test_assert!(t, b / a == a);
+    test_assert!(t, a / b == Frac24::MAX); // sat
+    test_assert!(t, a + b == Frac24::from_bits(Int24::from_i32(0x60_0000)));
+    test_assert!(t, b - a == -b);
+    test_assert!(t, a + a == Frac24::MAX); // sat
+    test_assert!(t, Frac24::MIN * Frac24::MIN == Frac24::MAX); // sat
+    test_assert!(t, Frac24::MIN * a == -a);
+    test_assert!(t, Frac24::MIN.abs() == Frac24::MAX); // sat
 
-    let a = Int24::from_i32(-100000);
-    let b = Int24::from_i32(100000);
-    test_assert!(t, a.abs() == b);
-    test_assert!(t, a.const_abs() == b);
+    let c = Int24::from_i32(1_000_000);
+    test_assert!(t, a * c == Int24::from_i32(500_000));
+    test_assert!(t, (-b).mul_int(c) == Int24::from_i32(-250_000));
+    test_assert!(t, Frac24::MIN * Int24::MIN == Int24::MAX); // sat
+    test_assert!(t, Frac24::MAX * Int24::MAX == Int24::from_i32(0x7F_FFFE));
+}
 
-    let a = Int24::from_i32(0x7F_FFFF);
-    let b = Int24::from_i32(0x7F_FFFF);
-    test_assert!(t, a.abs() == b);
-    test_assert!(t, a.const_abs() == b);
+fn test_fix24(t: &impl TestOps) {
+    t.begin("fix24");
 
-    let a = Int24::from_i32(-0x7F_FFFF);
-    let b = Int24::from_i32(0x7F_FFFF);
-    test_assert!(t, a.abs() == b);
-    test_assert!(t, a.const_abs() == b);
+    let a: Fix24<4> = Fix24::from_int(Int24::from_i32(3));
+    let b: Fix24<12> = Fix24::from_bits(Int24::from_i32(0x800)); // 0.5
+    test_assert!(t, a.to_bits() == Int24::from_i32(0x30));
+    test_assert!(t, (a * b).to_bits() == Int24::from_i32(0x18));
+    test_assert!(t, (a / b).to_bits() == Int24::from_i32(0x60));
+    test_assert!(t, (b * a).to_bits() == Int24::from_i32(0x1800));
+    test_assert!(t, (a + a).to_int() == Int24::from_i32(6));
+    test_assert!(t, (Fix24::ZERO - a).to_int() == Int24::from_i32(-3));
+    test_assert!(t, (-b).to_int() == Int24::from_i32(-1));
+    test_assert!(t, (-a).abs() == a);
 
-    let a = Int24::from_i32(-0x80_0000);
-    let b = Int24::from_i32(0x7F_FFFF); // saturated
-    test_assert!(t, a.abs() == b);
-    test_assert!(t, a.const_abs() == b);
+    let c: Fix24<12> = a.rescale();
+    test_assert!(t, c.to_bits() == Int24::from_i32(0x3000));
+    let c: Fix24<0> = b.rescale();
+    test_assert!(t, c.to_bits() == Int24::zero());
+    let c: Fix24<23> = a.rescale();
+    test_assert!(t, c == Fix24::MAX); // sat
+    let c: Fix24<23> = (-a).rescale();
+    test_assert!(t, c == Fix24::MIN); // sat
+
+    let a: Fix24<20> = Fix24::from_int(Int24::from_i32(100));
+    test_assert!(t, a == Fix24::MAX); // sat
+    let a: Fix24<20> = Fix24::from_int(Int24::from_i32(-100));
+    test_assert!(t, a == Fix24::MIN); // sat
+    test_assert!(t, a / Fix24::<4>::ZERO == Fix24::MIN); // sat
+
+    let a = I16F8::from_int(5);
+    let b: Fix24<8> = a.into();
+    test_assert!(t, b.to_int() == Int24::from_i32(5) && I16F8::from(b) == a);
+    let a = Frac24::HALF;
+    let b: Fix24<23> = a.into();
+    test_assert!(t, b.to_bits() == a.to_bits() && Frac24::from(b) == a);
 }
 
-fn test_shl(t: &impl TestOps) {
-    t.begin("shl");
+fn test_format(t: &impl TestOps) {
+    t.begin("format");
 
-    let a = Int24::from_i32(100000);
-    let b = Int24::from_i32(400000);
-    test_assert!(t, a << 2 == b);
-    test_assert!(t, a.const_shl(2) == b);
+    let mut buf = [0; FORMAT_BUF_LEN];
+    let a = Int24::from_i32(12345).format_decimal(3, &mut buf);
+    test_assert!(t, a == Ok("12.345"));
+    let a = Int24::from_i32(-5).format_decimal(3, &mut buf);
+    test_assert!(t, a == Ok("-0.005"));
+    let a = Int24::from_i32(-42).format_decimal(0, &mut buf);
+    test_assert!(t, a == Ok("-42"));
+    let a = Int24::zero().format_decimal(2, &mut buf);
+    test_assert!(t, a == Ok("0.00"));
+    let a = Int24::MIN.format_decimal(9, &mut buf);
+    test_assert!(t, a == Ok("-0.008388608"));
+    let a = Int24::MAX.format_decimal(10, &mut buf);
+    test_assert!(t, a == Err(Error::InvalidScale));
 
-    let a = Int24::from_i32(1000);
-    let b = Int24::from_i32(256000);
-    test_assert!(t, a.shl8() == b);
+    let a = Int24::from_i32(0x1_40).format_fixed(8, 2, &mut buf);
+    test_assert!(t, a == Ok("1.25"));
+    let a = Int24::from_i32(-0x1_40).format_fixed(8, 1, &mut buf);
+    test_assert!(t, a == Ok("-1.3"));
+    let a = Int24::from_i32(-1).format_fixed(8, 2, &mut buf);
+    test_assert!(t, a == Ok("0.00"));
+    let a = Int24::from_i32(0xFF).format_fixed(8, 2, &mut buf);
+    test_assert!(t, a == Ok("1.00"));
+    let a = Int24::MIN.format_fixed(0, 0, &mut buf);
+    test_assert!(t, a == Ok("-8388608"));
+    let a = Int24::MIN.format_fixed(0, 9, &mut buf);
+    test_assert!(t, a == Ok("-8388608.000000000"));
+    let a = Int24::MAX.format_fixed(23, 9, &mut buf);
+    test_assert!(t, a == Ok("0.999999881"));
+    let a = Int24::MAX.format_fixed(24, 2, &mut buf);
+    test_assert!(t, a == Err(Error::InvalidScale));
+    let a = Int24::MAX.format_fixed(8, 10, &mut buf);
+    test_assert!(t, a == Err(Error::InvalidScale));
 
-    let a = Int24::from_i32(10);
-    let b = Int24::from_i32(655360);
-    test_assert!(t, a.shl16() == b);
+    let mut small = [0; 4];
+    let a = Int24::from_i32(1234).format_decimal(0, &mut small);
+    test_assert!(t, a == Ok("1234"));
+    let a = Int24::from_i32(-1234).format_decimal(0, &mut small);
+    test_assert!(t, a == Err(Error::SliceTooShort { needed: 5, len: 4 }));
+
+    let a: Fix24<16> = Fix24::from_bits(Int24::from_i32(-0x3_2000));
+    test_assert!(t, a.format(3, &mut buf) == Ok("-3.125"));
 }
 
-fn test_shr(t: &impl TestOps) {
-    t.begin("shr");
+fn test_parse(t: &impl TestOps) {
+    t.begin("parse");
 
-    let a = Int24::from_i32(400000);
-    let b = Int24::from_i32(100000);
-    test_assert!(t, a >> 2 == b);
-    test_assert!(t, a.const_shr(2) == b);
+    let a = Int24::parse_decimal("12.345", 3);
+    test_assert!(t, a == Ok(Int24::from_i32(12345)));
+    let a = Int24::parse_decimal("-0.005", 3);
+    test_assert!(t, a == Ok(Int24::from_i32(-5)));
+    let a = Int24::parse_decimal("+42", 0);
+    test_assert!(t, a == Ok(Int24::from_i32(42)));
+    let a = Int24::parse_decimal("1.2", 3);
+    test_assert!(t, a == Ok(Int24::from_i32(1200)));
+    let a = Int24::parse_decimal("1.2345", 3);
+    test_assert!(t, a == Ok(Int24::from_i32(1235)));
+    let a = Int24::parse_decimal("-1.2345", 3);
+    test_assert!(t, a == Ok(Int24::from_i32(-1235)));
+    let a = Int24::parse_decimal(".5", 1);
+    test_assert!(t, a == Ok(Int24::from_i32(5)));
+    let a = Int24::parse_decimal("7.", 1);
+    test_assert!(t, a == Ok(Int24::from_i32(70)));
+    let a = Int24::parse_decimal("-8388608", 0);
+    test_assert!(t, a == Ok(Int24::MIN));
+    let a = Int24::parse_decimal("8388608", 0);
+    test_assert!(t, a == Ok(Int24::MAX)); // sat
+    let a = Int24::parse_decimal("-99999999999999999999", 0);
+    test_assert!(t, a == Ok(Int24::MIN)); // sat
+    let a = Int24::parse_decimal("10000", 3);
+    test_assert!(t, a == Ok(Int24::MAX)); // sat
+    test_assert!(t, Int24::parse_decimal("", 0) == Err(Error::Parse));
+    test_assert!(t, Int24::parse_decimal("-", 0) == Err(Error::Parse));
+    test_assert!(t, Int24::parse_decimal(".", 0) == Err(Error::Parse));
+    test_assert!(t, Int24::parse_decimal("1.2.3", 0) == Err(Error::Parse));
+    test_assert!(t, Int24::parse_decimal("1a", 0) == Err(Error::Parse));
+    test_assert!(t, Int24::parse_decimal(" 1", 0) == Err(Error::Parse));
+    test_assert!(t, Int24::parse_decimal("1", 10) == Err(Error::InvalidScale));
 
-    let a = Int24::from_i32(-400000);
-    let b = Int24::from_i32(-100000);
-    test_assert!(t, a >> 2 == b);
-    test_assert!(t, a.const_shr(2) == b);
+    let a = Int24::parse_fixed("1.25", 8);
+    test_assert!(t, a == Ok(Int24::from_i32(0x1_40)));
+    let a = Int24::parse_fixed("-3.1416", 16);
+    test_assert!(t, a == Ok(Int24::from_i32(-205888)));
+    let a = Int24::parse_fixed("0.5", 23);
+    test_assert!(t, a == Ok(Int24::from_i32(0x40_0000)));
+    let a = Int24::parse_fixed("1.0", 23);
+    test_assert!(t, a == Ok(Int24::MAX)); // sat
+    let a = Int24::parse_fixed("-1.0", 23);
+    test_assert!(t, a == Ok(Int24::MIN));
+    let a = Int24::parse_fixed("0.001953125", 8); // 0.5 LSB
+    test_assert!(t, a == Ok(Int24::from_i32(1)));
+    let a = Int24::parse_fixed("-0.001953125", 8); // -0.5 LSB
+    test_assert!(t, a == Ok(Int24::from_i32(-1)));
+    test_assert!(t, Int24::parse_fixed("1", 24) == Err(Error::InvalidScale));
 
-    let a = Int24::from_i32(256000);
-    let b = Int24::from_i32(1000);
-    test_assert!(t, a.shr8() == b);
+    let a: Result<Fix24<4>, _> = Fix24::parse("-2.5");
+    test_assert!(t, a == Ok(Fix24::from_bits(Int24::from_i32(-40))));
 
-    let a = Int24::from_i32(-256000);
-    let b = Int24::from_i32(-1000);
-    test_assert!(t, a.shr8() == b);
+    let mut buf = [0; FORMAT_BUF_LEN];
+    let a = Int24::from_i32(-1234567);
+    let s = a.format_fixed(12, 6, &mut buf).unwrap();
+    test_assert!(t, Int24::parse_fixed(s, 12) == Ok(a));
+}
 
-    let a = Int24::from_i32(655360);
-    let b = Int24::from_i32(10);
-    test_assert!(t, a.shr16() == b);
+fn test_int48(t: &impl TestOps) {
+    t.begin("int48");
 
-    let a = Int24::from_i32(-655360);
-    let b = Int24::from_i32(-10);
-    test_assert!(t, a.shr16() == b);
+    let a = Int48::mul_int24(Int24::MAX, Int24::MAX);
+    test_assert!(t, a.to_i64() == 0x7F_FFFF * 0x7F_FFFF);
+    let a = Int48::mul_int24(Int24::MIN, Int24::MIN);
+    test_assert!(t, a.to_i64() == 0x80_0000 * 0x80_0000);
+    let a = Int48::mul_int24(Int24::MIN, Int24::MAX);
+    test_assert!(t, a.to_i64() == -0x80_0000 * 0x7F_FFFF);
+    test_assert!(t, a.to_int24() == Int24::MIN); // sat
+    test_assert!(t, a.to_int24_shr(24) == Int24::from_i32(-0x40_0000));
+
+    let a = Int48::from_i32(1000);
+    test_assert!(t, (a + a).to_i64() == 2000);
+    test_assert!(t, (Int48::zero() - a).to_i64() == -1000);
+    test_assert!(t, (-a).abs() == a);
+    test_assert!(t, Int48::MAX + a == Int48::MAX); // sat
+    test_assert!(t, Int48::MIN - a == Int48::MIN); // sat
+    test_assert!(t, -Int48::MIN == Int48::MAX); // sat
+    test_assert!(t, (a << 4).to_i64() == 16000);
+    test_assert!(t, (a << 40) == Int48::MAX); // sat
+    test_assert!(t, (-a << 40) == Int48::MIN); // sat
+    test_assert!(t, (-a >> 4).to_i64() == -63);
+    test_assert!(t, Int48::from_i64(i64::MIN) == Int48::MIN); // sat
+    test_assert!(t, Int48::from(Int24::from_i32(-5)).to_i64() == -5);
+    test_assert!(
+        t,
+        a.clamp(Int48::zero(), Int48::from_i32(10)).to_i64() == 10
+    );
 }
 
-fn test_cmp(t: &impl TestOps) {
-    t.begin("cmp");
+fn test_pid(t: &impl TestOps) {
+    t.begin("pid");
 
-    let a = Int24::from_i32(100000);
-    let b = Int24::from_i32(100000);
-    test_assert!(t, a == b);
-    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Equal);
+    let kp = Int24::from_i16(128); // 0.5
+    let ki = Int24::from_i16(64); // 0.25
+    let kd = Int24::from_i16(512); // 2.0
+    let min = Int24::from_i16(-100);
+    let max = Int24::from_i16(100);
 
-    let a = Int24::from_i32(100000);
-    let b = Int24::from_i32(100001);
-    test_assert!(t, a != b);
-    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Less);
+    let mut pid = Pid24::new(kp, Int24::zero(), Int24::zero(), 8);
+    test_assert!(
+        t,
+        pid.update(Int24::from_i16(40), Int24::zero()) == Int24::from_i16(20)
+    );
+    test_assert!(
+        t,
+        pid.update(Int24::from_i16(-41), Int24::zero()) == Int24::from_i16(-21)
+    );
 
-    let a = Int24::from_i32(100000);
-    let b = Int24::from_i32(100000);
-    test_assert!(t, a <= b);
-    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Equal);
+    // Integrator with anti-windup.
+    let mut pid = Pid24::new(Int24::zero(), ki, Int24::zero(), 8).with_output_limits(min, max);
+    test_assert!(
+        t,
+        pid.update(Int24::from_i16(40), Int24::zero()) == Int24::from_i16(10)
+    );
+    test_assert!(
+        t,
+        pid.update(Int24::from_i16(40), Int24::zero()) == Int24::from_i16(20)
+    );
+    for _ in 0..20 {
+        pid.update(Int24::from_i16(40), Int24::zero());
+    }
+    test_assert!(t, pid.integral() == max);
+    // The integrator unwinds immediately.
+    test_assert!(
+        t,
+        pid.update(Int24::from_i16(-40), Int24::zero()) == Int24::from_i16(90)
+    );
+    pid.reset();
+    test_assert!(t, pid.integral() == Int24::zero());
 
-    let a = Int24::from_i32(100000);
-    let b = Int24::from_i32(100001);
-    test_assert!(t, a < b);
-    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Less);
+    // Derivative on measurement without and with filter.
+    let mut pid = Pid24::new(Int24::zero(), Int24::zero(), kd, 8);
+    test_assert!(
+        t,
+        pid.update(Int24::zero(), Int24::from_i16(10)) == Int24::zero()
+    );
+    test_assert!(
+        t,
+        pid.update(Int24::zero(), Int24::from_i16(14)) == Int24::from_i16(-8)
+    );
+    test_assert!(
+        t,
+        pid.update(Int24::zero(), Int24::from_i16(14)) == Int24::zero()
+    );
+    let mut pid = Pid24::new(Int24::zero(), Int24::zero(), kd, 8).with_derivative_filter(1);
+    test_assert!(
+        t,
+        pid.update(Int24::zero(), Int24::from_i16(10)) == Int24::zero()
+    );
+    test_assert!(
+        t,
+        pid.update(Int24::zero(), Int24::from_i16(14)) == Int24::from_i16(-4)
+    );
+    test_assert!(
+        t,
+        pid.update(Int24::zero(), Int24::from_i16(14)) == Int24::from_i16(-2)
+    );
 
-    let a = Int24::from_i32(100000);
-    let b = Int24::from_i32(100000);
-    test_assert!(t, a >= b);
-    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Equal);
+    // Output limits and saturation.
+    let mut pid = Pid24::new(Int24::MAX, Int24::MAX, Int24::zero(), 0).with_output_limits(min, max);
+    test_assert!(t, pid.update(Int24::MAX, Int24::MIN) == max); // sat
+    test_assert!(t, pid.update(Int24::MIN, Int24::MAX) == min); // sat
+    pid.set_gains(Int24::from_i16(1), Int24::zero(), Int24::zero());
+    test_assert!(
+        t,
+        pid.update(Int24::from_i16(3), Int24::zero()) == Int24::from_i16(-97)
+    );
+}
 
-    let a = Int24::from_i32(100001);
-    let b = Int24::from_i32(100000);
-    test_assert!(t, a > b);
-    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Greater);
+fn test_ema(t: &impl TestOps) {
+    t.begin("ema");
+
+    let mut ema = Ema24::new(2);
+    test_assert!(t, ema.update(Int24::from_i16(100)) == Int24::from_i16(25));
+    test_assert!(t, ema.update(Int24::from_i16(100)) == Int24::from_i16(44));
+    for _ in 0..100 {
+        ema.update(Int24::from_i16(100));
+    }
+    test_assert!(t, ema.value() == Int24::from_i16(100));
+    for _ in 0..100 {
+        ema.update(Int24::from_i16(-3));
+    }
+    test_assert!(t, ema.value() == Int24::from_i16(-3));
+
+    let mut ema = Ema24::new(0);
+    test_assert!(t, ema.update(Int24::from_i16(-7)) == Int24::from_i16(-7));
+
+    let mut ema = Ema24::new(8);
+    ema.reset(Int24::MAX);
+    test_assert!(t, ema.value() == Int24::MAX);
+    test_assert!(t, ema.update(Int24::MAX) == Int24::MAX);
+    for _ in 0..5000 {
+        ema.update(Int24::MIN);
+    }
+    test_assert!(t, ema.value() == Int24::MIN);
+    test_assert!(t, ema.update(Int24::MIN) == Int24::MIN);
+
+    let mut ema = Ema24::new(100);
+    ema.reset(Int24::from_i16(1000));
+    test_assert!(t, ema.update(Int24::zero()) == Int24::from_i16(1000));
 }
 
-pub fn run_tests(t: &impl TestOps) {
-    t.print("\n\nBegin tests\n");
-    test_base(t);
-    test_conv_i8(t);
-    test_conv_i16(t);
-    test_conv_i32(t);
-    test_add(t);
-    test_sub(t);
-    test_mul(t);
-    test_div(t);
-    test_shl8div(t);
-    test_neg(t);
-    test_abs(t);
-    test_shl(t);
-    test_shr(t);
-    test_cmp(t);
-    t.print("Done!\n");
+fn test_fir(t: &impl TestOps) {
+    t.begin("fir");
+
+    let mut fir = Fir24::new([Frac24::HALF, Frac24::HALF]);
+    test_assert!(t, fir.update(Int24::from_i16(100)) == Int24::from_i16(50));
+    test_assert!(t, fir.update(Int24::from_i16(200)) == Int24::from_i16(150));
+    test_assert!(t, fir.update(Int24::from_i16(-201)) == Int24::zero());
+    test_assert!(t, fir.update(Int24::from_i16(-2)) == Int24::from_i16(-101));
+
+    // Impulse response.
+    let c = [
+        Frac24::from_bits(Int24::from_i32(1000)),
+        Frac24::from_bits(Int24::from_i32(-2000)),
+        Frac24::from_bits(Int24::from_i32(3000)),
+    ];
+    let mut fir = Fir24::new(c);
+    let one = Int24::from_i32(1 << 23);
+    test_assert!(t, fir.update(one) == Int24::from_i32(1000)); // 1 << 23 saturates the input
+    test_assert!(t, fir.update(Int24::zero()) == Int24::from_i32(-2000));
+    test_assert!(t, fir.update(Int24::zero()) == Int24::from_i32(3000));
+    test_assert!(t, fir.update(Int24::zero()) == Int24::zero());
+    fir.update(Int24::MIN);
+    fir.reset();
+    test_assert!(t, fir.update(Int24::zero()) == Int24::zero());
+
+    // No intermediate saturation.
+    let mut fir = Fir24::new([Frac24::MAX, Frac24::MAX, Frac24::MIN, Frac24::MIN]);
+    fir.update(Int24::MAX);
+    fir.update(Int24::MAX);
+    test_assert!(t, fir.update(Int24::MAX) == Int24::from_i32(0x7F_FFFD));
+    test_assert!(t, fir.update(Int24::MAX) == Int24::from_i16(-2));
+    let mut fir = Fir24::new([Frac24::MAX; 3]);
+    fir.update(Int24::MIN);
+    test_assert!(t, fir.update(Int24::MIN) == Int24::MIN); // sat
+
+    // Long filter.
+    let mut fir = Fir24::<40>::new([Frac24::from_bits(Int24::from_i32(1 << 17)); 40]);
+    let mut y = Int24::zero();
+    for _ in 0..40 {
+        y = fir.update(Int24::from_i16(512));
+    }
+    test_assert!(t, y == Int24::from_i16(320));
+}
+
+fn test_biquad(t: &impl TestOps) {
+    t.begin("biquad");
+
+    let half = Fix24::<22>::from_bits(Int24::from_i32(1 << 21));
+    let mut iir = Biquad24::new(half, Fix24::ZERO, Fix24::ZERO, -half, Fix24::ZERO);
+    test_assert!(t, iir.update(Int24::from_i16(100)) == Int24::from_i16(50));
+    test_assert!(t, iir.update(Int24::from_i16(100)) == Int24::from_i16(75));
+    for _ in 0..40 {
+        iir.update(Int24::from_i16(100));
+    }
+    test_assert!(t, iir.update(Int24::from_i16(100)) == Int24::from_i16(100));
+    iir.reset();
+    test_assert!(t, iir.update(Int24::zero()) == Int24::zero());
+
+    // Second order low pass with a DC gain of 1:
+    // b = [0.25, 0.5, 0.25], a = [1, -0.5, 0.25]
+    let q = |v: i32| Fix24::<22>::from_bits(Int24::from_i32(v));
+    let mut iir = Biquad24::new(
+        q(1 << 20),
+        q(1 << 21),
+        q(1 << 20),
+        q(-(1 << 21)),
+        q(1 << 20),
+    );
+    let x = Int24::from_i32(1_000_000);
+    test_assert!(t, iir.update(x) == Int24::from_i32(250_000));
+    test_assert!(t, iir.update(x) == Int24::from_i32(875_000));
+    let mut y = Int24::zero();
+    for _ in 0..60 {
+        y = iir.update(x);
+    }
+    test_assert!(t, y == Int24::from_i32(1_333_334));
+
+    // Only the output saturates.
+    let two = Fix24::<22>::MAX;
+    let mut iir = Biquad24::new(two, -two, Fix24::ZERO, Fix24::ZERO, Fix24::ZERO);
+    test_assert!(t, iir.update(Int24::MAX) == Int24::MAX); // sat
+    test_assert!(t, iir.update(Int24::MAX) == Int24::zero());
+    test_assert!(t, iir.update(Int24::MIN) == Int24::MIN); // sat
+}
+
+fn test_slew(t: &impl TestOps) {
+    t.begin("slew");
+
+    let mut slew = SlewLimiter::new(Int24::zero(), Int24::from_i16(-10));
+    test_assert!(t, slew.update(Int24::from_i16(25)) == Int24::from_i16(10));
+    test_assert!(t, slew.update(Int24::from_i16(25)) == Int24::from_i16(20));
+    test_assert!(t, slew.update(Int24::from_i16(25)) == Int24::from_i16(25));
+    test_assert!(t, slew.update(Int24::from_i16(25)) == Int24::from_i16(25));
+    test_assert!(t, slew.update(Int24::from_i16(-100)) == Int24::from_i16(15));
+    test_assert!(t, slew.update(Int24::from_i16(10)) == Int24::from_i16(10));
+    slew.set_max_step(Int24::from_i16(1000));
+    test_assert!(
+        t,
+        slew.update(Int24::from_i16(-100)) == Int24::from_i16(-100)
+    );
+    test_assert!(t, slew.value() == Int24::from_i16(-100));
+
+    // Near the numeric limits.
+    let mut slew = SlewLimiter::new(Int24::MIN, Int24::from_i32(0x60_0000));
+    test_assert!(t, slew.update(Int24::MAX) == Int24::from_i32(-0x20_0000));
+    test_assert!(t, slew.update(Int24::MAX) == Int24::from_i32(0x40_0000));
+    test_assert!(t, slew.update(Int24::MAX) == Int24::MAX);
+    test_assert!(t, slew.update(Int24::MIN) == Int24::from_i32(0x1F_FFFF));
+    slew.reset(Int24::MIN);
+    test_assert!(t, slew.update(Int24::MIN) == Int24::MIN);
+    let mut slew = SlewLimiter::new(Int24::MAX, Int24::MIN);
+    test_assert!(t, slew.update(Int24::MIN) == Int24::zero());
+    test_assert!(t, slew.update(Int24::MIN) == Int24::from_i32(-0x7F_FFFF));
+    test_assert!(t, slew.update(Int24::MIN) == Int24::MIN);
+    test_assert!(t, slew.update(Int24::MAX) == Int24::from_i32(-1));
+}
+
+fn test_hysteresis(t: &impl TestOps) {
+    t.begin("hysteresis");
+
+    let mut hyst = Hysteresis::new(Int24::from_i16(20), Int24::from_i16(-10));
+    test_assert!(t, !hyst.update(Int24::from_i16(19)));
+    test_assert!(t, hyst.update(Int24::from_i16(20)));
+    test_assert!(t, hyst.update(Int24::from_i16(-9)));
+    test_assert!(t, !hyst.update(Int24::from_i16(-10)));
+    test_assert!(t, !hyst.update(Int24::from_i16(19)));
+    test_assert!(t, hyst.update(Int24::MAX));
+    test_assert!(t, !hyst.update(Int24::MIN));
+    hyst.reset(true);
+    test_assert!(t, hyst.state());
+
+    let mut hyst = Hysteresis::new(Int24::MIN, Int24::MAX);
+    test_assert!(t, !hyst.update(Int24::zero()));
+    test_assert!(t, hyst.update(Int24::MAX));
+    test_assert!(t, hyst.update(Int24::from_i32(-0x7F_FFFF)));
+
+    let w = Int24::from_i16(5);
+    test_assert!(t, Int24::from_i16(5).deadband(w) == Int24::zero());
+    test_assert!(t, Int24::from_i16(-5).deadband(w) == Int24::zero());
+    test_assert!(t, Int24::from_i16(6).deadband(w) == Int24::from_i16(1));
+    test_assert!(t, Int24::from_i16(-7).deadband(-w) == Int24::from_i16(-2));
+    test_assert!(t, Int24::MAX.deadband(w) == Int24::from_i32(0x7F_FFFA));
+    test_assert!(t, Int24::MIN.deadband(w) == Int24::from_i32(-0x7F_FFFB));
+    test_assert!(t, Int24::MIN.deadband(Int24::MIN) == Int24::from_i32(-1));
+    test_assert!(t, Int24::MAX.deadband(Int24::MIN) == Int24::zero());
+}
+
+fn test_lerp(t: &impl TestOps) {
+    t.begin("lerp");
+
+    let a = Int24::from_i16(100);
+    let b = Int24::from_i16(200);
+    test_assert!(t, a.lerp_q8(b, 0) == a);
+    test_assert!(t, a.lerp_q8(b, 64) == Int24::from_i16(125));
+    test_assert!(t, a.lerp_q8(b, 255) == Int24::from_i16(200));
+    test_assert!(t, b.lerp_q8(a, 128) == Int24::from_i16(150));
+    test_assert!(t, a.lerp_q8(-a, 128) == Int24::zero());
+    test_assert!(t, a.lerp_q16(b, 0) == a);
+    test_assert!(t, a.lerp_q16(b, 0x4000) == Int24::from_i16(125));
+    test_assert!(t, a.lerp_q16(b, 0xFFFF) == Int24::from_i16(200));
+
+    // Full range.
+    test_assert!(t, Int24::MIN.lerp_q8(Int24::MAX, 0) == Int24::MIN);
+    test_assert!(t, Int24::MIN.lerp_q8(Int24::MAX, 128) == Int24::zero());
+    test_assert!(
+        t,
+        Int24::MIN.lerp_q8(Int24::MAX, 255) == Int24::from_i32(0x7E_FFFF)
+    );
+    test_assert!(t, Int24::MAX.lerp_q16(Int24::MIN, 0x8000) == Int24::zero());
+    test_assert!(
+        t,
+        Int24::MAX.lerp_q16(Int24::MIN, 0xFFFF) == Int24::from_i32(-0x7F_FF00)
+    );
+    test_assert!(t, Int24::MAX.lerp_q16(Int24::MAX, 0x1234) == Int24::MAX);
+}
+
+fn test_map_range(t: &impl TestOps) {
+    t.begin("map_range");
+
+    let i = |v: i32| Int24::from_i32(v);
+    let z = Int24::zero();
+    test_assert!(t, i(512).map_range(z, i(1023), z, i(255)) == i(127));
+    test_assert!(t, i(1023).map_range(z, i(1023), z, i(255)) == i(255));
+    test_assert!(t, i(0).map_range(z, i(1023), i(255), z) == i(255));
+    test_assert!(
+        t,
+        i(-50).map_range(i(-100), i(100), i(0), i(1000)) == i(250)
+    );
+    test_assert!(
+        t,
+        i(-50).map_range(i(-100), i(100), i(1000), i(0)) == i(750)
+    );
+    test_assert!(t, i(-1).map_range(z, i(3), z, i(-10)) == i(3)); // extrapolate
+    test_assert!(t, i(5).map_range(i(5), i(5), i(7), i(9)) == i(7));
+
+    // Full range without overflow.
+    let min = Int24::MIN;
+    let max = Int24::MAX;
+    test_assert!(t, max.map_range(min, max, max, min) == min);
+    test_assert!(t, min.map_range(min, max, max, min) == max);
+    test_assert!(t, z.map_range(min, max, z, i(1000)) == i(500));
+    test_assert!(t, max.map_range(z, i(1), z, i(2)) == max); // sat
+    test_assert!(t, min.map_range(z, i(1), z, max) == min); // sat
+}
+
+fn test_trig(t: &impl TestOps) {
+    t.begin("trig");
+
+    let near = |a: Frac24, b: i32| (a.to_bits().to_i32() - b).abs() <= 2;
+    let deg = |d: i32| Int24::from_i32((d as i64 * 0x100_0000 / 360) as i32);
+
+    let (s, c) = Frac24::sin_cos(Int24::zero());
+    test_assert!(t, near(s, 0) && c == Frac24::MAX);
+    let (s, c) = Frac24::sin_cos(deg(90));
+    test_assert!(t, s == Frac24::MAX && near(c, 0));
+    let (s, c) = Frac24::sin_cos(deg(-90));
+    test_assert!(t, s == Frac24::MIN && near(c, 0));
+    let (s, c) = Frac24::sin_cos(Int24::MIN); // -180 degrees
+    test_assert!(t, near(s, 0) && c == Frac24::MIN);
+    let (s, c) = Frac24::sin_cos(deg(45));
+    test_assert!(t, near(s, 5931642) && near(c, 5931642));
+    let (s, c) = Frac24::sin_cos(deg(150));
+    test_assert!(t, near(s, 4194304) && near(c, -7264748));
+    let (s, c) = Frac24::sin_cos(deg(-120));
+    test_assert!(t, near(s, -7264748) && near(c, -4194304));
+    test_assert!(t, near(Frac24::sin(deg(30)), 4194304));
+    test_assert!(t, near(Frac24::cos(deg(60)), 4194304));
+    test_assert!(t, near(Frac24::sin(Int24::MAX), 3));
+
+    let i = |v: i32| Int24::from_i32(v);
+    let near = |a: Int24, b: i32| (a.to_i32() - b).abs() <= 1;
+    test_assert!(t, i(0).atan2(i(0)) == i(0));
+    test_assert!(t, i(0).atan2(i(5)) == i(0));
+    test_assert!(t, i(5).atan2(i(0)) == deg(90));
+    test_assert!(t, i(-5).atan2(i(0)) == deg(-90));
+    test_assert!(t, i(0).atan2(i(-5)) == Int24::MIN);
+    test_assert!(t, near(i(1).atan2(i(1)), 0x20_0000));
+    test_assert!(t, near(i(-1000).atan2(i(-1000)), -0x60_0000));
+    test_assert!(t, near(Int24::MAX.atan2(Int24::MIN), 0x60_0000));
+    test_assert!(t, near(Int24::MIN.atan2(Int24::MIN), -0x60_0000));
+    test_assert!(t, near(i(-1).atan2(Int24::MAX), 0));
+    test_assert!(t, near(i(4194304).atan2(i(-7264748)), 0x6A_AAAB)); // 150 degrees
+    test_assert!(t, near(i(-9).atan2(i(100)), -239670));
+}
+
+fn test_isqrt(t: &impl TestOps) {
+    t.begin("isqrt");
+
+    let i = |v: i32| Int24::from_i32(v);
+    test_assert!(t, i(0).isqrt() == i(0));
+    test_assert!(t, i(1).isqrt() == i(1));
+    test_assert!(t, i(3).isqrt() == i(1));
+    test_assert!(t, i(4).isqrt() == i(2));
+    test_assert!(t, i(99).isqrt() == i(9));
+    test_assert!(t, i(100).isqrt() == i(10));
+    test_assert!(t, i(2896 * 2896 - 1).isqrt() == i(2895));
+    test_assert!(t, i(2896 * 2896).isqrt() == i(2896));
+    test_assert!(t, Int24::MAX.isqrt() == i(2896));
+    test_assert!(t, i(-1).isqrt() == i(0));
+    test_assert!(t, Int24::MIN.isqrt() == i(0));
+    test_assert!(t, Int24::MAX.const_isqrt() == i(2896));
+    test_assert!(t, i(-5).const_isqrt() == i(0));
+
+    let mut v = 0;
+    while v <= 0x7F_FFFF {
+        let a = i(v);
+        test_assert!(t, a.isqrt() == a.const_isqrt());
+        let q = a.isqrt().to_i32();
+        test_assert!(t, q * q <= v && (q + 1) * (q + 1) > v);
+        v += 4093;
+    }
+}
+
+fn test_lut(t: &impl TestOps) {
+    t.begin("lut");
+
+    let i = |v: i32| Int24::from_i32(v);
+    let table = [
+        (i(-100), i(50)),
+        (i(0), i(0)),
+        (i(100), i(1000)),
+        (i(100), i(2000)),
+        (i(200), i(-1000)),
+    ];
+    let lut = Lut24::new(&table);
+    test_assert!(t, lut.points().len() == 5);
+    test_assert!(t, lut.lookup(Int24::MIN) == i(50));
+    test_assert!(t, lut.lookup(i(-100)) == i(50));
+    test_assert!(t, lut.lookup(i(-50)) == i(25));
+    test_assert!(t, lut.lookup(i(0)) == i(0));
+    test_assert!(t, lut.lookup(i(1)) == i(10));
+    test_assert!(t, lut.lookup(i(100)) == i(1000));
+    test_assert!(t, lut.lookup(i(101)) == i(1970));
+    test_assert!(t, lut.lookup(i(200)) == i(-1000));
+    test_assert!(t, lut.lookup(Int24::MAX) == i(-1000));
+
+    let table = [(Int24::MIN, Int24::MIN), (Int24::MAX, Int24::MAX)];
+    let lut = Lut24::new(&table);
+    test_assert!(t, lut.lookup(i(12345)) == i(12345));
+    let lut = Lut24::new(&table[..1]);
+    test_assert!(t, lut.lookup(i(12345)) == Int24::MIN);
+    let lut = Lut24::new(&[]);
+    test_assert!(t, lut.lookup(i(12345)) == i(0));
+}
+
+fn test_stats(t: &impl TestOps) {
+    t.begin("stats");
+
+    let i = |v: i32| Int24::from_i32(v);
+    let data = [i(1), i(2), i(4), i(-3)];
+    test_assert!(t, stats::mean(&data) == Some(i(1)));
+    test_assert!(t, stats::min(&data) == Some(i(-3)));
+    test_assert!(t, stats::max(&data) == Some(i(4)));
+    test_assert!(t, stats::variance(&data) == Some(Int48::from_i32(7))); // 6.5
+    let data = [i(-1), i(-2)];
+    test_assert!(t, stats::mean(&data) == Some(i(-2))); // -1.5
+    test_assert!(t, stats::variance(&data) == Some(Int48::zero())); // 0.25
+    let data = [i(7)];
+    test_assert!(t, stats::mean(&data) == Some(i(7)));
+    test_assert!(t, stats::variance(&data) == Some(Int48::zero()));
+    test_assert!(t, stats::mean(&[]).is_none());
+    test_assert!(t, stats::min(&[]).is_none());
+    test_assert!(t, stats::max(&[]).is_none());
+    test_assert!(t, stats::variance(&[]).is_none());
+
+    // No saturation of the sums.
+    let data = [Int24::MAX; 64];
+    test_assert!(t, stats::mean(&data) == Some(Int24::MAX));
+    test_assert!(t, stats::variance(&data) == Some(Int48::zero()));
+    let mut data = [Int24::MIN; 64];
+    for v in data.iter_mut().step_by(2) {
+        *v = Int24::MAX;
+    }
+    test_assert!(t, stats::mean(&data) == Some(i(-1))); // -0.5
+    test_assert!(
+        t,
+        stats::variance(&data) == Some(Int48::from_i64(0x3FFF_FF80_0000))
+    );
+    test_assert!(t, stats::min(&data) == Some(Int24::MIN));
+    test_assert!(t, stats::max(&data) == Some(Int24::MAX));
+    let mut data = [Int24::MAX; 64];
+    data[0] = Int24::MIN;
+    test_assert!(
+        t,
+        stats::variance(&data) == Some(Int48::from_i64(4329326518272))
+    );
+}
+
+fn test_sum(t: &impl TestOps) {
+    t.begin("sum");
+
+    let i = |v: i32| Int24::from_i32(v);
+    let data = [i(1), i(-2), i(4000), i(-0x10_0000)];
+    test_assert!(
+        t,
+        stats::sum_wide(&data) == Int48::from_i32(-0x10_0000 + 3999)
+    );
+    test_assert!(t, stats::sum_saturating(&data) == i(-0x10_0000 + 3999));
+    test_assert!(t, data.iter().sum::<Int24>() == i(-0x10_0000 + 3999));
+    test_assert!(t, stats::sum_wide(&[]) == Int48::zero());
+    test_assert!(t, stats::sum_saturating(&[]) == Int24::zero());
+
+    let data = [Int24::MAX; 100];
+    test_assert!(
+        t,
+        stats::sum_wide(&data) == Int48::from_i32(0x7F_FFFF * 100)
+    );
+    test_assert!(t, stats::sum_saturating(&data) == Int24::MAX); // sat
+    test_assert!(t, data.iter().sum::<Int24>() == Int24::MAX); // sat
+    let data = [Int24::MIN; 100];
+    test_assert!(
+        t,
+        stats::sum_wide(&data) == Int48::from_i32(-0x80_0000 * 100)
+    );
+    test_assert!(t, stats::sum_saturating(&data) == Int24::MIN); // sat
+
+    // Order independent saturation.
+    let data = [Int24::MAX, Int24::MAX, Int24::MIN, Int24::MIN, i(5)];
+    test_assert!(t, stats::sum_saturating(&data) == i(3));
+    test_assert!(t, data.into_iter().sum::<Int24>() == i(3));
+    test_assert!(
+        t,
+        data.into_iter().map(Int48::from).sum::<Int48>() == Int48::from_i32(3)
+    );
+}
+
+fn test_dot(t: &impl TestOps) {
+    t.begin("dot");
+    let a = [Int24::from_i16(1), Int24::from_i16(-2), Int24::from_i16(3)];
+    let b = [Int24::from_i16(4), Int24::from_i16(5), Int24::from_i16(-6)];
+    test_assert!(t, stats::dot(&a, &b) == Int48::from_i32(-24));
+    test_assert!(t, stats::dot(&a[..2], &b) == Int48::from_i32(-6));
+    test_assert!(t, stats::dot(&a, &b[..1]) == Int48::from_i32(4));
+    test_assert!(t, stats::dot(&[], &b) == Int48::zero());
+
+    // Products with MIN.
+    let a = [Int24::MIN, Int24::MIN, Int24::MAX];
+    let b = [Int24::MIN, Int24::MAX, Int24::MIN];
+    let p = (-0x80_0000_i64) * (-0x80_0000);
+    let q = (-0x80_0000_i64) * 0x7F_FFFF;
+    test_assert!(t, stats::dot(&a[..1], &b[..1]) == Int48::from_i64(p));
+    test_assert!(t, stats::dot(&a, &b) == Int48::from_i64(p + 2 * q));
+    test_assert!(t, stats::dot(&b, &a) == Int48::from_i64(p + 2 * q));
+
+    // No intermediate saturation.
+    let a = [Int24::MAX, Int24::MAX, Int24::MIN, Int24::MIN];
+    let b = [Int24::MAX; 4];
+    test_assert!(t, stats::dot(&a, &b) == Int48::from_i32(-0xFF_FFFE));
+    let a = [Int24::MIN; 8];
+    test_assert!(t, stats::dot(&a, &a) == Int48::MAX); // sat
+}
+
+fn test_packed(t: &impl TestOps) {
+    t.begin("packed");
+    test_assert!(t, Int24Slice::new(&[0; 4]).is_none());
+    test_assert!(t, Int24Slice::new(&[]).unwrap().is_empty());
+
+    let buf = [0x56, 0x34, 0x12, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0x7F];
+    let s = Int24Slice::new(&buf).unwrap();
+    test_assert!(t, s.len() == 3);
+    test_assert!(t, !s.is_empty());
+    test_assert!(t, s.get(0) == Some(Int24::from_i32(0x12_3456)));
+    test_assert!(t, s.get(1) == Some(Int24::MIN));
+    test_assert!(t, s.get(2) == Some(Int24::MAX));
+    test_assert!(t, s.get(3).is_none());
+    test_assert!(t, s.get(usize::MAX).is_none());
+    let mut it = s.iter();
+    test_assert!(t, it.len() == 3);
+    test_assert!(t, it.next() == Some(Int24::from_i32(0x12_3456)));
+    test_assert!(t, it.next_back() == Some(Int24::MAX));
+    test_assert!(t, it.next() == Some(Int24::MIN));
+    test_assert!(t, it.next().is_none());
+    test_assert!(
+        t,
+        s.into_iter().sum::<Int24>() == Int24::from_i32(0x12_3455)
+    );
+
+    let mut buf = [0xAA; 7];
+    test_assert!(t, Int24SliceMut::new(&mut buf).is_none());
+    let mut s = Int24SliceMut::new(&mut buf[..6]).unwrap();
+    test_assert!(t, s.len() == 2);
+    s.fill(Int24::zero());
+    test_assert!(t, s.iter().all(|v| v == Int24::zero()));
+    test_assert!(t, s.set(0, Int24::from_i16(-2)));
+    test_assert!(t, s.set(1, Int24::from_i32(0x12_3456)));
+    test_assert!(t, !s.set(2, Int24::MAX));
+    test_assert!(t, !s.set(usize::MAX, Int24::MAX));
+    test_assert!(t, s.get(0) == Some(Int24::from_i16(-2)));
+    test_assert!(t, s.as_slice().get(1) == Some(Int24::from_i32(0x12_3456)));
+    test_assert!(t, s.get(2).is_none());
+    test_assert!(t, buf == [0xFE, 0xFF, 0xFF, 0x56, 0x34, 0x12, 0xAA]);
+}
+
+fn test_packed_stream(t: &impl TestOps) {
+    t.begin("packed_stream");
+    let buf = [0x12, 0x34, 0x56, 0x80, 0x00, 0x00, 0x7F];
+    let mut it = Int24Iter::new(&buf, ByteOrder::BigEndian);
+    test_assert!(t, it.len() == 2);
+    test_assert!(t, it.remainder() == [0x7F]);
+    test_assert!(t, it.next() == Some(Int24::from_i32(0x12_3456)));
+    test_assert!(t, it.next() == Some(Int24::MIN));
+    test_assert!(t, it.next().is_none());
+    let mut it = Int24Iter::new(&buf, ByteOrder::LittleEndian);
+    test_assert!(t, it.next_back() == Some(Int24::from_i32(0x80)));
+    test_assert!(t, it.next_back() == Some(Int24::from_i32(0x56_3412)));
+    test_assert!(t, it.next_back().is_none());
+    test_assert!(
+        t,
+        Int24Iter::new(&buf[..2], ByteOrder::default())
+            .next()
+            .is_none()
+    );
+
+    let mut buf = [0xAA; 8];
+    let mut w = Int24Writer::new(&mut buf, ByteOrder::BigEndian);
+    test_assert!(t, w.remaining() == 2);
+    test_assert!(t, w.push(Int24::from_i32(0x12_3456)));
+    test_assert!(t, w.remaining() == 1);
+    test_assert!(t, w.write_all([Int24::MIN, Int24::MAX]) == 1);
+    test_assert!(t, w.remaining() == 0);
+    test_assert!(t, !w.push(Int24::MAX));
+    test_assert!(t, w.written() == [0x12, 0x34, 0x56, 0x80, 0x00, 0x00]);
+    test_assert!(t, buf == [0x12, 0x34, 0x56, 0x80, 0x00, 0x00, 0xAA, 0xAA]);
+
+    // Round trip.
+    let data = [Int24::from_i16(-300), Int24::MAX, Int24::from_i16(7)];
+    for order in [ByteOrder::LittleEndian, ByteOrder::BigEndian] {
+        let mut buf = [0; 9];
+        let mut w = Int24Writer::new(&mut buf, order);
+        test_assert!(t, w.write_all(data) == 3);
+        test_assert!(t, Int24Iter::new(&buf, order).eq(data));
+    }
+}
+
+fn test_packed_scale(t: &impl TestOps) {
+    t.begin("packed_scale");
+    let mut buf = [0; 12];
+    let mut s = Int24SliceMut::new(&mut buf).unwrap();
+    s.set(0, Int24::from_i16(1000));
+    s.set(1, Int24::from_i16(-1000));
+    s.set(2, Int24::MAX);
+    s.set(3, Int24::MIN);
+
+    // Gain 0.25 and offset -10.
+    let gain = Fix24::<12>::from_bits(Int24::from_i16(0x400));
+    s.scale_offset(gain, Int24::from_i16(-10));
+    test_assert!(t, s.get(0) == Some(Int24::from_i16(240)));
+    test_assert!(t, s.get(1) == Some(Int24::from_i16(-260)));
+    test_assert!(t, s.get(2) == Some(Int24::from_i32(0x1F_FFFF - 10)));
+    test_assert!(t, s.get(3) == Some(Int24::from_i32(-0x20_0000 - 10)));
+
+    // No intermediate saturation.
+    s.fill(Int24::MAX);
+    s.set(3, Int24::from_i16(3));
+    let gain = Fix24::<0>::from_bits(Int24::from_i16(2));
+    s.scale_offset(gain, Int24::MIN);
+    test_assert!(t, s.get(0) == Some(Int24::from_i32(0x7F_FFFE)));
+    test_assert!(t, s.get(3) == Some(Int24::from_i32(-0x7F_FFFA)));
+    s.scale_offset(gain, Int24::MAX);
+    test_assert!(t, s.get(0) == Some(Int24::MAX)); // sat
+    test_assert!(t, s.get(3) == Some(Int24::from_i32(-0x7F_FFF5)));
+    s.fill(Int24::MIN);
+    s.scale_offset(Fix24::<0>::from_bits(Int24::from_i16(-1)), Int24::zero());
+    test_assert!(t, s.iter().all(|v| v == Int24::MAX)); // sat
+
+    // Generic function.
+    let mut n = 0;
+    s.apply(|_| {
+        n += 1;
+        Int24::from_i16(n)
+    });
+    test_assert!(t, s.iter().eq((1..=4).map(Int24::from_i16)));
+    s.apply(|v| -v);
+    test_assert!(t, s.get(3) == Some(Int24::from_i16(-4)));
+}
+
+fn test_packed_select(t: &impl TestOps) {
+    t.begin("packed_select");
+    let mut buf = [0; 3 * 9];
+    let mut s = Int24SliceMut::new(&mut buf).unwrap();
+    test_assert!(t, s.select_nth(9).is_none());
+    test_assert!(t, Int24SliceMut::new(&mut []).unwrap().median().is_none());
+
+    // Compare against a sorted copy with pseudo random data.
+    let mut seed = 0x1234_5678_u32;
+    for round in 0..50 {
+        let mut sorted = [0_i32; 9];
+        for (i, v) in sorted.iter_mut().enumerate() {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            // Use a small range in some rounds to get duplicates.
+            *v = if round % 2 == 0 {
+                (seed as i32) >> 8
+            } else {
+                ((seed >> 16) % 4) as i32 - 2
+            };
+            s.set(i, Int24::from_i32(*v));
+        }
+        sorted.sort_unstable();
+        let n = round % 9;
+        test_assert!(t, s.select_nth(n) == Some(Int24::from_i32(sorted[n])));
+        test_assert!(t, s.iter().take(n).all(|v| v.to_i32() <= sorted[n]));
+        test_assert!(t, s.iter().skip(n).all(|v| v.to_i32() >= sorted[n]));
+        test_assert!(t, s.median() == Some(Int24::from_i32(sorted[4])));
+    }
+
+    // Even number of elements.
+    let mut buf = [0; 3 * 4];
+    let mut s = Int24SliceMut::new(&mut buf).unwrap();
+    for (i, v) in [7, -3, 10, 4].into_iter().enumerate() {
+        s.set(i, Int24::from_i16(v));
+    }
+    test_assert!(t, s.median() == Some(Int24::from_i16(5)));
+    for (i, v) in [-4, 7, -3, 0].into_iter().enumerate() {
+        s.set(i, Int24::from_i16(v));
+    }
+    test_assert!(t, s.median() == Some(Int24::from_i16(-2)));
+    s.fill(Int24::MAX);
+    test_assert!(t, s.median() == Some(Int24::MAX));
+    s.set(0, Int24::MIN);
+    s.set(1, Int24::MIN);
+    test_assert!(t, s.median() == Some(Int24::from_i16(-1)));
+    test_assert!(t, s.swap(0, 3));
+    test_assert!(t, !s.swap(0, 4));
+    test_assert!(t, !s.swap(usize::MAX, 0));
+    test_assert!(
+        t,
+        s.get(0) == Some(Int24::MAX) && s.get(3) == Some(Int24::MIN)
+    );
+}
+
+fn test_adc_bits(t: &impl TestOps) {
+    t.begin("adc_bits");
+    test_assert!(t, Int24::from_hx711_bits(0) == Int24::zero());
+    test_assert!(
+        t,
+        Int24::from_hx711_bits(0x12_3456) == Int24::from_i32(0x12_3456)
+    );
+    test_assert!(t, Int24::from_hx711_bits(0x7F_FFFF) == Int24::MAX);
+    test_assert!(t, Int24::from_hx711_bits(0x80_0000) == Int24::MIN);
+    test_assert!(t, Int24::from_hx711_bits(0xFF_FFFF) == Int24::from_i16(-1));
+    test_assert!(t, Int24::from_hx711_bits(0xFF00_0001) == Int24::from_i16(1));
+    test_assert!(
+        t,
+        Int24::from_hx711_bits(0x01FF_FFFE) == Int24::from_i16(-2)
+    );
+    test_assert!(
+        t,
+        Int24::from_ads1232_bits(0xFE_DCBA) == Int24::from_i32(-0x01_2346)
+    );
+
+    // Shift in MSB first.
+    let sample = Int24::from_i32(-0x12_3456).to_i32() as u32;
+    let mut bits = 0_u32;
+    for i in (0..adc::DATA_BITS).rev() {
+        bits = (bits << 1) | ((sample >> i) & 1);
+    }
+    test_assert!(
+        t,
+        Int24::from_hx711_bits(bits) == Int24::from_i32(-0x12_3456)
+    );
+
+    for gain in [Hx711Gain::A128, Hx711Gain::B32, Hx711Gain::A64] {
+        test_assert!(t, Hx711Gain::from_pulses(gain.pulses()) == Some(gain));
+        test_assert!(t, gain.pulses() == adc::DATA_BITS + gain.extra_pulses());
+    }
+    test_assert!(t, Hx711Gain::default().pulses() == 25);
+    test_assert!(t, Hx711Gain::B32.pulses() == 26);
+    test_assert!(t, Hx711Gain::A64.pulses() == 27);
+    test_assert!(t, Hx711Gain::from_pulses(24).is_none());
+    test_assert!(t, Hx711Gain::from_pulses(28).is_none());
+    test_assert!(t, adc::ADS1232_PULSES == 25);
+    test_assert!(t, adc::ADS1232_CALIBRATION_PULSES == 26);
+}
+
+fn test_adc_frames(t: &impl TestOps) {
+    t.begin("adc_frames");
+    let spi = [0x12, 0x34, 0x56, 0xA5];
+    let f = FrameFormat::Data24;
+    test_assert!(t, f.frame_len() == 3);
+    test_assert!(
+        t,
+        adc::decode_frame(&spi, f) == Ok((Int24::from_i32(0x12_3456), 0))
+    );
+    let f = FrameFormat::Data24Pad8;
+    test_assert!(t, f.frame_len() == 4);
+    test_assert!(
+        t,
+        adc::decode_frame(&spi, f) == Ok((Int24::from_i32(0x12_3456), 0xA5))
+    );
+    test_assert!(
+        t,
+        adc::decode_frame(&spi[..3], f) == Err(Error::SliceTooShort { needed: 4, len: 3 })
+    );
+    let f = FrameFormat::Status8Data24;
+    test_assert!(t, f.frame_len() == 4);
+    test_assert!(
+        t,
+        adc::decode_frame(&spi, f) == Ok((Int24::from_i32(0x34_56A5), 0x12))
+    );
+    test_assert!(t, adc::decode_frame(&[], FrameFormat::default()).is_err());
+
+    let spi = [0xFF, 0xFF, 0xFF, 0x80, 0x00, 0x00, 0x7F, 0xFF, 0xFF, 0x00];
+    let mut it = adc::decode_frames(&spi, FrameFormat::Data24);
+    test_assert!(t, it.len() == 3);
+    test_assert!(t, it.next() == Some((Int24::from_i16(-1), 0)));
+    test_assert!(t, it.next_back() == Some((Int24::MAX, 0)));
+    test_assert!(t, it.next() == Some((Int24::MIN, 0)));
+    test_assert!(t, it.next().is_none());
+    test_assert!(
+        t,
+        adc::decode_frames(&spi, FrameFormat::Status8Data24).len() == 2
+    );
+
+    let mut buf = [0xAA; 9];
+    let f = FrameFormat::Status8Data24;
+    test_assert!(
+        t,
+        adc::encode_frame(&mut buf[..3], f, Int24::MAX, 0x11)
+            == Err(Error::SliceTooShort { needed: 4, len: 3 })
+    );
+    test_assert!(t, buf == [0xAA; 9]);
+    test_assert!(
+        t,
+        adc::encode_frame(&mut buf, f, Int24::from_i16(-2), 0x11).is_ok()
+    );
+    test_assert!(t, buf[..5] == [0x11, 0xFF, 0xFF, 0xFE, 0xAA]);
+    test_assert!(
+        t,
+        adc::encode_frame(&mut buf, FrameFormat::Data24Pad8, Int24::MIN, 0).is_ok()
+    );
+    test_assert!(t, buf[..5] == [0x80, 0x00, 0x00, 0x00, 0xAA]);
+    test_assert!(
+        t,
+        adc::encode_frame(&mut buf[6..], FrameFormat::Data24, Int24::MAX, 0x11).is_ok()
+    );
+    test_assert!(t, buf[5..] == [0xAA, 0x7F, 0xFF, 0xFF]);
+
+    // Round trip.
+    for f in [
+        FrameFormat::Data24,
+        FrameFormat::Data24Pad8,
+        FrameFormat::Status8Data24,
+    ] {
+        let mut buf = [0; 4];
+        let status = if f == FrameFormat::Data24 { 0 } else { 0x5A };
+        test_assert!(
+            t,
+            adc::encode_frame(&mut buf, f, Int24::from_i32(-0x12_3456), status).is_ok()
+        );
+        test_assert!(
+            t,
+            adc::decode_frame(&buf, f) == Ok((Int24::from_i32(-0x12_3456), status))
+        );
+    }
+}
+
+fn test_i2s(t: &impl TestOps) {
+    t.begin("i2s");
+    test_assert!(
+        t,
+        Int24::from_i2s_left_justified(0x1234_5678) == Int24::from_i32(0x12_3456)
+    );
+    test_assert!(t, Int24::from_i2s_left_justified(0x8000_00FF) == Int24::MIN);
+    test_assert!(t, Int24::from_i2s_left_justified(0x7FFF_FF00) == Int24::MAX);
+    test_assert!(
+        t,
+        Int24::from_i2s_left_justified(0xFFFF_FFFF) == Int24::from_i16(-1)
+    );
+    test_assert!(
+        t,
+        Int24::from_i2s_right_justified(0x0012_3456) == Int24::from_i32(0x12_3456)
+    );
+    test_assert!(
+        t,
+        Int24::from_i2s_right_justified(0xFF80_0000) == Int24::MIN
+    );
+    test_assert!(
+        t,
+        Int24::from_i2s_right_justified(0x0080_0000) == Int24::MIN
+    );
+    test_assert!(
+        t,
+        Int24::from_i2s_right_justified(0xAA7F_FFFF) == Int24::MAX
+    );
+
+    test_assert!(
+        t,
+        Int24::from_i32(0x12_3456).to_i2s_left_justified() == 0x1234_5600
+    );
+    test_assert!(
+        t,
+        Int24::from_i16(-2).to_i2s_left_justified() == 0xFFFF_FE00
+    );
+    test_assert!(t, Int24::MIN.to_i2s_left_justified() == 0x8000_0000);
+    test_assert!(
+        t,
+        Int24::from_i32(0x12_3456).to_i2s_right_justified() == 0x0012_3456
+    );
+    test_assert!(
+        t,
+        Int24::from_i16(-2).to_i2s_right_justified() == 0xFFFF_FFFE
+    );
+    test_assert!(t, Int24::MIN.to_i2s_right_justified() == 0xFF80_0000);
+
+    for v in [Int24::MIN, Int24::from_i16(-1), Int24::zero(), Int24::MAX] {
+        test_assert!(
+            t,
+            Int24::from_i2s_left_justified(v.to_i2s_left_justified()) == v
+        );
+        test_assert!(
+            t,
+            Int24::from_i2s_right_justified(v.to_i2s_right_justified()) == v
+        );
+    }
+}
+
+fn test_apply_gain(t: &impl TestOps) {
+    t.begin("apply_gain");
+    let a = Int24::from_i32(0x12_3456);
+    test_assert!(t, a.apply_gain(0x100) == a);
+    test_assert!(t, a.apply_gain(0) == Int24::zero());
+    test_assert!(t, a.apply_gain(0x80) == Int24::from_i32(0x09_1A2B));
+    test_assert!(t, a.apply_gain(0x40) == Int24::from_i32(0x04_8D15));
+    test_assert!(t, a.apply_gain(-0x100) == Int24::from_i32(-0x12_3456));
+    test_assert!(t, a.apply_gain(0x180) == Int24::from_i32(0x1B_4E81));
+    test_assert!(t, a.apply_gain(0x800) == Int24::MAX); // sat
+    test_assert!(t, a.apply_gain(-0x800) == Int24::MIN); // sat
+    test_assert!(
+        t,
+        Int24::from_i16(-3).apply_gain(0x80) == Int24::from_i16(-2)
+    );
+    test_assert!(t, Int24::from_i16(3).apply_gain(0x80) == Int24::from_i16(1));
+    test_assert!(t, Int24::from_i16(1).apply_gain(0x7F) == Int24::zero());
+    test_assert!(t, Int24::from_i16(-1).apply_gain(1) == Int24::from_i16(-1));
+
+    // Extreme operands.
+    test_assert!(t, Int24::MIN.apply_gain(0x100) == Int24::MIN);
+    test_assert!(
+        t,
+        Int24::MIN.apply_gain(0x80) == Int24::from_i32(-0x40_0000)
+    );
+    test_assert!(t, Int24::MIN.apply_gain(-0x100) == Int24::MAX); // sat
+    test_assert!(t, Int24::MIN.apply_gain(i16::MIN) == Int24::MAX); // sat
+    test_assert!(t, Int24::MAX.apply_gain(i16::MIN) == Int24::MIN); // sat
+    test_assert!(t, Int24::MAX.apply_gain(0xFF) == Int24::from_i32(0x7F_7FFF));
+    test_assert!(
+        t,
+        Int24::MAX.apply_gain(-0xFF) == Int24::from_i32(-0x7F_8000)
+    );
+    test_assert!(
+        t,
+        Int24::from_i16(0x100).apply_gain(i16::MIN) == Int24::from_i32(-0x8000)
+    );
+    test_assert!(
+        t,
+        Int24::from_i16(0x100).apply_gain(i16::MAX) == Int24::from_i32(0x7FFF)
+    );
+
+    // Packed buffer.
+    let mut buf = [0; 9];
+    let mut s = Int24SliceMut::new(&mut buf).unwrap();
+    s.set(0, Int24::from_i16(100));
+    s.set(1, Int24::from_i16(-100));
+    s.set(2, Int24::MAX);
+    s.apply_gain(0x300);
+    test_assert!(t, s.get(0) == Some(Int24::from_i16(300)));
+    test_assert!(t, s.get(1) == Some(Int24::from_i16(-300)));
+    test_assert!(t, s.get(2) == Some(Int24::MAX)); // sat
+}
+
+fn test_db(t: &impl TestOps) {
+    t.begin("db");
+    test_assert!(t, Int24::zero().to_db_q8_8() == i16::MIN);
+    test_assert!(t, Int24::from_i16(3).to_db_q8_8() == i16::MIN); // sat
+    test_assert!(t, Int24::from_i16(4).to_db_q8_8() == -32367);
+    test_assert!(t, Int24::MIN.to_db_q8_8() == 0);
+    test_assert!(t, Int24::MAX.to_db_q8_8() == 0);
+    test_assert!(t, Int24::from_i32(0x40_0000).to_db_q8_8() == -1541);
+    test_assert!(t, Int24::from_i32(-0x40_0000).to_db_q8_8() == -1541);
+    test_assert!(t, Int24::from_i32(0x20_0000).to_db_q8_8() == -3083);
+    // -20 dB and -40 dB within 0.01 dB
+    let db = Int24::from_i32(838_861).to_db_q8_8();
+    test_assert!(t, (db as i32 + 20 * 256).abs() <= 2);
+    let db = Int24::from_i32(-83_886).to_db_q8_8();
+    test_assert!(t, (db as i32 + 40 * 256).abs() <= 2);
+
+    test_assert!(t, Int24::from_db_q8_8(0) == Int24::MAX); // sat
+    test_assert!(t, Int24::from_db_q8_8(i16::MAX) == Int24::MAX); // sat
+    test_assert!(t, Int24::from_db_q8_8(i16::MIN) == Int24::from_i16(3));
+    test_assert!(
+        t,
+        Int24::from_db_q8_8(-1541).to_i32().abs_diff(0x40_0000) < 0x800
+    );
+    test_assert!(
+        t,
+        Int24::from_db_q8_8(-20 * 256).to_i32().abs_diff(838_861) < 0x200
+    );
+
+    // Round trip.
+    for db in [-15_000, -10_000, -5_000, -2_560, -100, -1] {
+        let x = Int24::from_db_q8_8(db);
+        test_assert!(t, (x.to_db_q8_8() as i32 - db as i32).abs() <= 2);
+    }
+}
+
+fn test_dither(t: &impl TestOps) {
+    t.begin("dither");
+    let mut rng = 0;
+    test_assert!(t, Int24::MAX.to_i16_dithered(&mut rng) == i16::MAX); // sat
+    test_assert!(t, rng != 0);
+    test_assert!(t, Int24::MIN.to_i16_dithered(&mut rng) == i16::MIN);
+
+    // The dither never exceeds one LSB and the mean is rounded correctly.
+    for (x, lo, mean_x16) in [
+        (0x12_3400, 0x1233, 0x1_2340),
+        (0x12_3440, 0x1233, 0x1_2344),
+        (0x12_3480, 0x1234, 0x1_2348),
+        (-0x12_3440, -0x1235, -0x1_2344),
+        (0x7F_FFC0, 0x7FFF, 0x7_FFF0), // sat
+    ] {
+        let mut sum = 0_i32;
+        let mut ok = true;
+        for _ in 0..256 {
+            let v = Int24::from_i32(x).to_i16_dithered(&mut rng) as i32;
+            ok &= v >= lo && v <= lo + 2;
+            sum += v;
+        }
+        test_assert!(t, ok);
+        // Mean with 4 fractional bits.
+        test_assert!(t, (sum / 16 - mean_x16).abs() <= 2);
+    }
+}
+
+fn test_from_bits_signed(t: &impl TestOps) {
+    t.begin("from_bits_signed");
+    test_assert!(t, Int24::from_bits_signed(0xFFFF_FFFF, 0) == Int24::zero());
+    test_assert!(t, Int24::from_bits_signed(0, 1) == Int24::zero());
+    test_assert!(t, Int24::from_bits_signed(1, 1) == Int24::from_i16(-1));
+    test_assert!(t, Int24::from_bits_signed(0xFFFF_FFFE, 1) == Int24::zero());
+    test_assert!(t, Int24::from_bits_signed(0x7F, 8) == Int24::from_i16(127));
+    test_assert!(
+        t,
+        Int24::from_bits_signed(0x180, 8) == Int24::from_i16(-128)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits_signed(0x1_FFFF, 18) == Int24::from_i32(0x1_FFFF)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits_signed(0xFFFE_0000, 18) == Int24::from_i32(-0x2_0000)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits_signed(0x8_0001, 20) == Int24::from_i32(-0x7_FFFF)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits_signed(0x20_0000, 22) == Int24::from_i32(-0x20_0000)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits_signed(0x1F_FFFF, 22) == Int24::from_i32(0x1F_FFFF)
+    );
+    test_assert!(t, Int24::from_bits_signed(0xAA80_0000, 24) == Int24::MIN);
+    test_assert!(t, Int24::from_bits_signed(0x7F_FFFF, 24) == Int24::MAX);
+    test_assert!(t, Int24::from_bits_signed(0x00FF_FFFF, 25) == Int24::MAX); // sat
+    test_assert!(t, Int24::from_bits_signed(0x0100_0000, 25) == Int24::MIN); // sat
+    test_assert!(
+        t,
+        Int24::from_bits_signed(0x01FF_FFFF, 25) == Int24::from_i16(-1)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits_signed(0xFFFF_FFFE, 32) == Int24::from_i16(-2)
+    );
+    test_assert!(t, Int24::from_bits_signed(0x8000_0000, 40) == Int24::MIN); // sat
+}
+
+fn test_bits(t: &impl TestOps) {
+    t.begin("bits");
+    test_assert!(t, Int24::from_bits(0) == Int24::zero());
+    test_assert!(t, Int24::from_bits(0x12_3456) == Int24::from_i32(0x12_3456));
+    test_assert!(t, Int24::from_bits(0x80_0000) == Int24::MIN);
+    test_assert!(t, Int24::from_bits(0x7F_FFFF) == Int24::MAX);
+    test_assert!(t, Int24::from_bits(0xFF_FFFF) == Int24::from_i16(-1));
+    test_assert!(t, Int24::from_bits(0xFFFF_FFFF) == Int24::from_i16(-1));
+    test_assert!(t, Int24::from_bits(0xAB00_0001) == Int24::from_i16(1));
+    test_assert!(t, Int24::zero().to_bits() == 0);
+    test_assert!(t, Int24::from_i16(-1).to_bits() == 0xFF_FFFF);
+    test_assert!(t, Int24::MIN.to_bits() == 0x80_0000);
+    test_assert!(t, Int24::MAX.to_bits() == 0x7F_FFFF);
+    test_assert!(t, Int24::from_i32(-0x12_3456).to_bits() == 0xED_CBAA);
+    for v in [Int24::MIN, Int24::from_i16(-300), Int24::zero(), Int24::MAX] {
+        test_assert!(t, Int24::from_bits(v.to_bits()) == v);
+        test_assert!(t, Int24::from_bits_signed(v.to_bits(), 24) == v);
+    }
+}
+
+fn test_gray(t: &impl TestOps) {
+    t.begin("gray");
+    test_assert!(t, Int24::zero().to_gray() == Int24::zero());
+    test_assert!(t, Int24::from_bits(1).to_gray() == Int24::from_bits(1));
+    test_assert!(t, Int24::from_bits(2).to_gray() == Int24::from_bits(3));
+    test_assert!(t, Int24::from_bits(3).to_gray() == Int24::from_bits(2));
+    test_assert!(
+        t,
+        Int24::from_bits(0x80).to_gray() == Int24::from_bits(0xC0)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits(0x100).to_gray() == Int24::from_bits(0x180)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits(0x1_0000).to_gray() == Int24::from_bits(0x1_8000)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits(0xFF_FFFF).to_gray() == Int24::from_bits(0x80_0000)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits(0x80_0000).from_gray() == Int24::from_bits(0xFF_FFFF)
+    );
+    test_assert!(
+        t,
+        Int24::from_bits(0x00_8000).from_gray() == Int24::from_bits(0x00_FFFF)
+    );
+
+    // Compare against a 32 bit reference and check that
+    // consecutive codes only differ in one bit.
+    let mut v = 0x12_3456_u32;
+    for i in 0..500_u32 {
+        v = v.wrapping_mul(2_654_435_761).wrapping_add(i) & 0xFF_FFFF;
+        let g = Int24::from_bits(v).to_gray();
+        test_assert!(t, g.to_bits() == v ^ (v >> 1));
+        test_assert!(t, g.from_gray().to_bits() == v);
+        let next = Int24::from_bits(v + 1).to_gray();
+        test_assert!(t, (g.to_bits() ^ next.to_bits()).count_ones() == 1);
+    }
+}
+
+fn test_bcd(t: &impl TestOps) {
+    t.begin("bcd");
+    test_assert!(t, Int24::zero().to_bcd() == (false, 0));
+    test_assert!(t, Int24::from_i16(9).to_bcd() == (false, 0x9));
+    test_assert!(t, Int24::from_i16(10).to_bcd() == (false, 0x10));
+    test_assert!(t, Int24::from_i16(-1).to_bcd() == (true, 0x1));
+    test_assert!(t, Int24::from_i16(12345).to_bcd() == (false, 0x1_2345));
+    test_assert!(
+        t,
+        Int24::from_i32(7_999_999).to_bcd() == (false, 0x799_9999)
+    );
+    test_assert!(t, Int24::MAX.to_bcd() == (false, 0x838_8607));
+    test_assert!(t, Int24::MIN.to_bcd() == (true, 0x838_8608));
+
+    test_assert!(t, Int24::from_bcd(false, 0) == Some(Int24::zero()));
+    test_assert!(t, Int24::from_bcd(true, 0) == Some(Int24::zero()));
+    test_assert!(t, Int24::from_bcd(false, 0x838_8607) == Some(Int24::MAX));
+    test_assert!(t, Int24::from_bcd(true, 0x838_8608) == Some(Int24::MIN));
+    test_assert!(t, Int24::from_bcd(false, 0x838_8608) == Some(Int24::MAX)); // sat
+    test_assert!(t, Int24::from_bcd(true, 0x9999_9999) == Some(Int24::MIN)); // sat
+    test_assert!(t, Int24::from_bcd(false, 0x0000_000A).is_none());
+    test_assert!(t, Int24::from_bcd(false, 0xF000_0000).is_none());
+    test_assert!(t, Int24::from_bcd(false, 0x0001_B000).is_none());
+
+    // Round trip.
+    let mut v = 1_i32;
+    for i in 0..300 {
+        v = v.wrapping_mul(1_103_515_245).wrapping_add(12345 + i);
+        let x = Int24::from_i32(v >> 8);
+        let (neg, bcd) = x.to_bcd();
+        test_assert!(t, Int24::from_bcd(neg, bcd) == Some(x));
+    }
+}
+
+fn test_wrapping(t: &impl TestOps) {
+    t.begin("wrapping");
+    let one = Int24::from_i16(1);
+    test_assert!(t, Int24::MAX.wrapping_add(one) == Int24::MIN);
+    test_assert!(t, Int24::MIN.wrapping_sub(one) == Int24::MAX);
+    test_assert!(
+        t,
+        Int24::from_i16(-5).wrapping_add(Int24::from_i16(3)) == Int24::from_i16(-2)
+    );
+    test_assert!(t, Int24::MIN.wrapping_neg() == Int24::MIN);
+    test_assert!(t, Int24::MAX.wrapping_neg() == Int24::from_i32(-0x7F_FFFF));
+    test_assert!(t, Int24::from_i16(7).wrapping_neg() == Int24::from_i16(-7));
+    test_assert!(
+        t,
+        Int24::MIN.wrapping_mul(Int24::from_i16(-1)) == Int24::MIN
+    );
+    test_assert!(t, Int24::MAX.wrapping_mul(Int24::MAX) == one);
+    test_assert!(
+        t,
+        Int24::from_i16(-300).wrapping_mul(Int24::from_i16(7)) == Int24::from_i16(-2100)
+    );
+    test_assert!(
+        t,
+        Int24::from_i32(0x1000).wrapping_mul(Int24::from_i32(0x1001)) == Int24::from_i32(0x1000)
+    );
+
+    // Counter differences across the wrap point.
+    let before = Int24::from_i32(0x7F_FFF0);
+    let after = Int24::from_i32(-0x7F_FFF0);
+    test_assert!(t, after.wrapping_sub(before) == Int24::from_i16(0x20));
+    test_assert!(t, before.wrapping_sub(after) == Int24::from_i16(-0x20));
+
+    let mut a = WrappingInt24(Int24::MAX);
+    a += WrappingInt24(one);
+    test_assert!(t, a == WrappingInt24(Int24::MIN));
+    a -= WrappingInt24(Int24::from_i16(2));
+    test_assert!(t, a.0 == Int24::from_i32(0x7F_FFFE));
+    a *= WrappingInt24(Int24::from_i16(2));
+    test_assert!(t, a.0 == Int24::from_i16(-4));
+    test_assert!(t, (-a).0 == Int24::from_i16(4));
+    test_assert!(t, -WrappingInt24(Int24::MIN) == WrappingInt24(Int24::MIN));
+    test_assert!(
+        t,
+        (a - WrappingInt24(Int24::MAX)).0 == Int24::from_i32(0x7F_FFFD)
+    );
+    test_assert!(t, (a + a).0 == Int24::from_i16(-8));
+    test_assert!(
+        t,
+        WrappingInt24::from(one).0 == Int24::from(WrappingInt24(one))
+    );
+    test_assert!(t, WrappingInt24::default().0 == Int24::zero());
+}
+
+fn test_saturating(t: &impl TestOps) {
+    t.begin("saturating");
+    let one = SaturatingInt24(Int24::from_i16(1));
+    let mut a = SaturatingInt24(Int24::MAX);
+    a += one;
+    test_assert!(t, a.0 == Int24::MAX); // sat
+    a = SaturatingInt24(Int24::MIN) - one;
+    test_assert!(t, a.0 == Int24::MIN); // sat
+    a -= one;
+    test_assert!(t, a.0 == Int24::MIN); // sat
+    test_assert!(t, (-a).0 == Int24::MAX); // sat
+    test_assert!(t, (a * a).0 == Int24::MAX); // sat
+    a = SaturatingInt24(Int24::from_i16(-300));
+    a *= SaturatingInt24(Int24::from_i16(7));
+    test_assert!(t, a.0 == Int24::from_i16(-2100));
+    a /= SaturatingInt24(Int24::from_i16(-100));
+    test_assert!(t, a.0 == Int24::from_i16(21));
+    test_assert!(t, (a / SaturatingInt24(Int24::zero())).0 == Int24::MAX); // sat
+    test_assert!(t, (a + one - one).0 == Int24::from_i16(21));
+    test_assert!(t, SaturatingInt24(Int24::MIN) < one);
+    test_assert!(
+        t,
+        SaturatingInt24::from(Int24::MAX).0 == Int24::from(SaturatingInt24(Int24::MAX))
+    );
+    test_assert!(t, SaturatingInt24::default().0 == Int24::zero());
+
+    // Same results as the operators on Int24.
+    let data = [Int24::MAX, Int24::MAX, Int24::MIN, Int24::from_i16(5)];
+    let sum: SaturatingInt24 = data.iter().map(|v| SaturatingInt24(*v)).sum();
+    test_assert!(t, sum.0 == data.iter().sum::<Int24>());
+}
+
+fn test_cell(t: &impl TestOps) {
+    t.begin("cell");
+    let c = Int24Cell::default();
+    test_assert!(t, c.load() == Int24::zero());
+    c.store(Int24::from_i16(100));
+    test_assert!(t, c.load() == Int24::from_i16(100));
+    test_assert!(t, c.fetch_add(Int24::from_i16(5)) == Int24::from_i16(100));
+    test_assert!(t, c.fetch_sub(Int24::from_i16(10)) == Int24::from_i16(105));
+    test_assert!(t, c.load() == Int24::from_i16(95));
+    test_assert!(t, c.swap(Int24::MAX) == Int24::from_i16(95));
+    test_assert!(t, c.fetch_add(Int24::from_i16(1)) == Int24::MAX);
+    test_assert!(t, c.load() == Int24::MAX); // sat
+    test_assert!(t, c.update(|v| v >> 8) == Int24::MAX);
+    test_assert!(t, c.load() == Int24::from_i32(0x7FFF));
+    let mut c = Int24Cell::new(Int24::MIN);
+    test_assert!(t, c.fetch_sub(Int24::from_i16(1)) == Int24::MIN);
+    test_assert!(t, c.load() == Int24::MIN); // sat
+    *c.get_mut() = Int24::from_i16(-7);
+    test_assert!(t, c.into_inner() == Int24::from_i16(-7));
+}
+
+fn test_volatile(t: &impl TestOps) {
+    t.begin("volatile");
+    let mut buf = [0_u8; 4];
+    let p = buf[1..].as_mut_ptr().cast::<Int24>();
+    unsafe { volatile::write_volatile(p, Int24::from_i32(0x12_3456)) };
+    test_assert!(t, buf == [0, 0x56, 0x34, 0x12]);
+    test_assert!(
+        t,
+        unsafe { volatile::read_volatile(p) } == Int24::from_i32(0x12_3456)
+    );
+    let r = VolatileInt24::default();
+    test_assert!(t, r.read() == Int24::zero());
+    r.write(Int24::MIN);
+    test_assert!(t, r.read() == Int24::MIN);
+    r.modify(|v| v + Int24::from_i16(1));
+    test_assert!(t, r.read() == Int24::from_i32(-0x7F_FFFF));
+    test_assert!(
+        t,
+        unsafe { volatile::read_volatile(r.as_ptr()) } == Int24::from_i32(-0x7F_FFFF)
+    );
+}
+
+int24_progmem! {
+    static TEST_PROGMEM: [Int24; 4] = [
+        Int24::from_i32(0x12_3456),
+        Int24::MIN,
+        Int24::MAX,
+        Int24::from_i16(-2),
+    ];
+}
+
+fn test_progmem(t: &impl TestOps) {
+    t.begin("progmem");
+    test_assert!(t, TEST_PROGMEM.len() == 4);
+    test_assert!(t, !TEST_PROGMEM.is_empty());
+    test_assert!(t, TEST_PROGMEM.get(0) == Some(Int24::from_i32(0x12_3456)));
+    test_assert!(t, TEST_PROGMEM.get(1) == Some(Int24::MIN));
+    test_assert!(t, TEST_PROGMEM.get(3) == Some(Int24::from_i16(-2)));
+    test_assert!(t, TEST_PROGMEM.get(4).is_none());
+    test_assert!(
+        t,
+        TEST_PROGMEM.iter().next_back() == Some(Int24::from_i16(-2))
+    );
+    test_assert!(t, TEST_PROGMEM.iter().len() == 4);
+    test_assert!(t, TEST_PROGMEM.iter().max() == Some(Int24::MAX));
+}
+
+fn test_persist(t: &impl TestOps) {
+    t.begin("persist");
+    let mut buf = [0_u8; PERSIST_LEN + 1];
+    test_assert!(t, Int24::from_i32(0x12_3456).write_to(&mut buf).is_ok());
+    test_assert!(t, buf == [0x56, 0x34, 0x12, 0xA1, 0x00]);
+    test_assert!(t, Int24::read_from(&buf) == Ok(Int24::from_i32(0x12_3456)));
+    let short_err = Error::SliceTooShort { needed: 4, len: 3 };
+    test_assert!(
+        t,
+        Int24::read_from(&buf[..PERSIST_LEN - 1]) == Err(short_err)
+    );
+    buf[1] ^= 0x01;
+    test_assert!(t, Int24::read_from(&buf) == Err(Error::Checksum));
+    test_assert!(
+        t,
+        Int24::read_from(&[0x00; PERSIST_LEN]) == Err(Error::Checksum)
+    );
+    test_assert!(
+        t,
+        Int24::read_from(&[0xFF; PERSIST_LEN]) == Err(Error::Checksum)
+    );
+    let mut short = [0xAA_u8; PERSIST_LEN - 1];
+    test_assert!(t, Int24::MAX.write_to(&mut short) == Err(short_err));
+    test_assert!(t, short == [0xAA; PERSIST_LEN - 1]);
+    for v in [Int24::MIN, Int24::MAX, Int24::zero(), Int24::from_i16(-1)] {
+        test_assert!(t, v.write_to(&mut buf).is_ok());
+        test_assert!(t, Int24::read_from(&buf) == Ok(v));
+    }
+}
+
+fn test_slice_codec(t: &impl TestOps) {
+    t.begin("slice_codec");
+    let data = [Int24::from_i32(0x12_3456), Int24::MIN, Int24::from_i16(-2)];
+    let mut buf = [0xAA_u8; 10];
+    test_assert!(t, encode_le_slice(&data, &mut buf) == Ok(3));
+    test_assert!(
+        t,
+        buf == [0x56, 0x34, 0x12, 0x00, 0x00, 0x80, 0xFE, 0xFF, 0xFF, 0xAA]
+    );
+    let mut out = [Int24::zero(); 3];
+    test_assert!(t, decode_le_slice(&buf, &mut out) == Ok(3));
+    test_assert!(t, out == data);
+    test_assert!(t, encode_be_slice(&data, &mut buf) == Ok(3));
+    test_assert!(
+        t,
+        buf == [0x12, 0x34, 0x56, 0x80, 0x00, 0x00, 0xFF, 0xFF, 0xFE, 0xAA]
+    );
+    let mut out = [Int24::zero(); 3];
+    test_assert!(t, decode_be_slice(&buf, &mut out) == Ok(3));
+    test_assert!(t, out == data);
+
+    // Length checking.
+    let mut short = [0xAA_u8; 8];
+    let short_err = Err(Error::SliceTooShort { needed: 9, len: 8 });
+    test_assert!(t, encode_le_slice(&data, &mut short) == short_err);
+    test_assert!(t, short == [0xAA; 8]);
+    let mut out = [Int24::zero(); 3];
+    test_assert!(t, decode_be_slice(&short, &mut out) == short_err);
+    test_assert!(t, out == [Int24::zero(); 3]);
+    test_assert!(t, encode_le_slice(&[], &mut []) == Ok(0));
+    test_assert!(t, decode_le_slice(&[], &mut []) == Ok(0));
+}
+
+fn test_modbus(t: &impl TestOps) {
+    t.begin("modbus");
+    let hf = WordOrder::HighFirst;
+    let lf = WordOrder::LowFirst;
+    test_assert!(t, WordOrder::default() == hf);
+    test_assert!(
+        t,
+        Int24::from_i32(0x12_3456).to_modbus_registers(hf) == [0x0012, 0x3456]
+    );
+    test_assert!(
+        t,
+        Int24::from_i32(0x12_3456).to_modbus_registers(lf) == [0x3456, 0x0012]
+    );
+    test_assert!(t, Int24::MIN.to_modbus_registers(hf) == [0xFF80, 0x0000]);
+    test_assert!(t, Int24::MAX.to_modbus_registers(lf) == [0xFFFF, 0x007F]);
+    test_assert!(
+        t,
+        Int24::from_modbus_registers([0x3456, 0x0012], lf) == Int24::from_i32(0x12_3456)
+    );
+    test_assert!(
+        t,
+        Int24::from_modbus_registers([0xFF80, 0x0000], hf) == Int24::MIN
+    );
+    test_assert!(
+        t,
+        Int24::from_modbus_registers([0x8000, 0x0000], hf) == Int24::MIN
+    ); // sat
+    test_assert!(
+        t,
+        Int24::from_modbus_registers([0xFFFF, 0x0080], lf) == Int24::MAX
+    ); // sat
+    for v in [Int24::MIN, Int24::MAX, Int24::zero(), Int24::from_i16(-1)] {
+        test_assert!(
+            t,
+            Int24::from_modbus_registers(v.to_modbus_registers(hf), hf) == v
+        );
+        test_assert!(
+            t,
+            Int24::from_modbus_registers(v.to_modbus_registers(lf), lf) == v
+        );
+    }
+}
+
+#[cfg(feature = "ffi")]
+fn test_ffi(t: &impl TestOps) {
+    use crate::ffi::*;
+    t.begin("ffi");
+    test_assert!(t, int24_sat(0x100_0000) == 0x7F_FFFF); // sat
+    test_assert!(t, int24_sat(-5) == -5);
+    test_assert!(t, int24_to_i16(-0x1_0000) == i16::MIN); // sat
+    test_assert!(t, int24_add(0x7F_FFFF, 1) == 0x7F_FFFF); // sat
+    test_assert!(t, int24_add(0x100_0000, -1) == 0x7F_FFFE); // sat
+    test_assert!(t, int24_sub(-0x80_0000, 1) == -0x80_0000); // sat
+    test_assert!(t, int24_mul(1000, -300) == -300_000);
+    test_assert!(t, int24_div(-300_000, 1000) == -300);
+    test_assert!(t, int24_div(5, 0) == 0x7F_FFFF); // sat
+    test_assert!(t, int24_neg(-0x80_0000) == 0x7F_FFFF); // sat
+    test_assert!(t, int24_abs(-7) == 7);
+    test_assert!(t, int24_shl(0x40_0000, 1) == -0x80_0000);
+    test_assert!(t, int24_shr(-8, 2) == -2);
+}
+
+fn check_saturating_int<T: SaturatingInt>(t: &impl TestOps) {
+    let v = |x: i32| T::from_i32(x);
+    test_assert!(t, T::zero() == v(0));
+    test_assert!(t, v(-5).to_i64() == -5);
+    test_assert!(t, v(100).saturating_add(v(-30)) == v(70));
+    test_assert!(t, v(100).saturating_sub(v(130)) == v(-30));
+    test_assert!(t, v(-300).saturating_mul(v(100)) == v(-30_000));
+    test_assert!(t, v(-30_000).saturating_div(v(7)) == v(-4285));
+    test_assert!(t, v(7).saturating_neg() == v(-7));
+    test_assert!(t, v(-7).saturating_abs() == v(7));
+    test_assert!(t, v(3).saturating_shl(4) == v(48));
+    test_assert!(t, v(-48).shr(4) == v(-3));
+    test_assert!(t, T::MAX.saturating_add(v(1)) == T::MAX); // sat
+    test_assert!(t, T::MIN.saturating_sub(v(1)) == T::MIN); // sat
+    test_assert!(t, T::MAX.saturating_mul(v(2)) == T::MAX); // sat
+    test_assert!(t, T::MIN.saturating_mul(v(2)) == T::MIN); // sat
+    test_assert!(t, v(1).saturating_div(T::zero()) == T::MAX); // sat
+    test_assert!(t, v(-1).saturating_div(T::zero()) == T::MIN); // sat
+    test_assert!(t, T::MIN.saturating_div(v(-1)) == T::MAX); // sat
+    test_assert!(t, T::MIN.saturating_neg() == T::MAX); // sat
+    test_assert!(t, T::MIN.saturating_abs() == T::MAX); // sat
+    test_assert!(t, v(1).saturating_shl(60) == T::MAX); // sat
+    test_assert!(t, v(-1).saturating_shl(60) == T::MIN); // sat
+    test_assert!(t, T::MIN.shr(60) == v(-1));
+}
+
+fn test_saturating_int(t: &impl TestOps) {
+    t.begin("saturating_int");
+    check_saturating_int::<Int24>(t);
+    check_saturating_int::<Int48>(t);
+    test_assert!(
+        t,
+        Int48::from_i32(-6) * Int48::from_i32(7) == Int48::from_i32(-42)
+    );
+    test_assert!(
+        t,
+        Int48::from_i32(-42) / Int48::from_i32(7) == Int48::from_i32(-6)
+    );
+}
+
+fn test_complex(t: &impl TestOps) {
+    t.begin("complex");
+    let a = Complex24::from_i16(3, 4);
+    let b = Complex24::from_i16(1, -2);
+    test_assert!(t, a + b == Complex24::from_i16(4, 2));
+    test_assert!(t, a - b == Complex24::from_i16(2, 6));
+    test_assert!(t, a * b == Complex24::from_i16(11, -2));
+    test_assert!(t, -a == Complex24::from_i16(-3, -4));
+    test_assert!(t, a.conj() == Complex24::from_i16(3, -4));
+    test_assert!(t, a.power() == Int48::from_i32(25));
+    test_assert!(t, Complex24::default() == Complex24::zero());
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= Complex24::from_i16(0, 1);
+    test_assert!(t, c == Complex24::from_i16(2, 1));
+
+    // Q0.23 fixed-point multiplication: 0.5i * 0.5i = -0.25
+    let h = Complex24::new(Int24::zero(), Int24::from_i32(0x40_0000));
+    test_assert!(
+        t,
+        h.mul_shr(h, 23) == Complex24::new(Int24::from_i32(-0x20_0000), Int24::zero())
+    );
+
+    // Saturation.
+    let m = Complex24::new(Int24::MAX, Int24::MIN);
+    test_assert!(t, m * m == Complex24::new(Int24::MIN, Int24::MIN)); // sat
+    test_assert!(t, m + m == m); // sat
+    test_assert!(t, -m == Complex24::new(-Int24::MAX, Int24::MAX)); // sat
+    test_assert!(
+        t,
+        m.power() == Int48::from_i64(0x7F_FFFF * 0x7F_FFFF + 0x80_0000 * 0x80_0000)
+    );
+
+    // Magnitude approximation.
+    test_assert!(
+        t,
+        Complex24::from_i16(-1000, 0).magnitude_approx() == Int24::from_i16(1000)
+    );
+    test_assert!(
+        t,
+        Complex24::from_i16(0, 1000).magnitude_approx() == Int24::from_i16(1000)
+    );
+    test_assert!(
+        t,
+        Complex24::from_i16(1000, 1000).magnitude_approx() == Int24::from_i16(1375)
+    );
+    test_assert!(t, m.magnitude_approx() == Int24::MAX); // sat
+}
+
+fn test_vector(t: &impl TestOps) {
+    t.begin("vector");
+    let v = |x: i16, y: i16, z: i16| {
+        Vec3::new(Int24::from_i16(x), Int24::from_i16(y), Int24::from_i16(z))
+    };
+    let a = v(1, -2, 3);
+    let b = v(4, 5, -6);
+    test_assert!(t, a + b == v(5, 3, -3));
+    test_assert!(t, a - b == v(-3, -7, 9));
+    test_assert!(t, -a == v(-1, 2, -3));
+    test_assert!(t, a.mul_elem(b) == v(4, -10, -18));
+    test_assert!(t, a * Int24::from_i16(-3) == v(-3, 6, -9));
+    test_assert!(t, a.dot(b) == Int48::from_i32(-24));
+    test_assert!(t, a.length_squared() == Int48::from_i32(14));
+    test_assert!(t, a.cross(b) == v(-3, 18, 13));
+    test_assert!(t, Vec3::default() == Vec3::zero());
+    test_assert!(t, Vec3::splat(Int24::from_i16(2)) == v(2, 2, 2));
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= Int24::from_i16(2);
+    test_assert!(t, c == v(8, 10, -12));
+
+    // Q0.23 fixed-point scaling by 0.5.
+    test_assert!(
+        t,
+        b.scale_shr(Int24::from_i32(0x40_0000), 23) == v(2, 2, -3)
+    );
+
+    // Saturation.
+    let m = Vec2::new(Int24::MAX, Int24::MIN);
+    test_assert!(t, m + m == m); // sat
+    test_assert!(t, -m == Vec2::new(-Int24::MAX, Int24::MAX)); // sat
+    test_assert!(t, m.scale(Int24::from_i16(2)) == m); // sat
+    test_assert!(
+        t,
+        m.length_squared() == Int48::from_i64(0x7F_FFFF * 0x7F_FFFF + 0x80_0000 * 0x80_0000)
+    );
+    let m3 = Vec3::splat(Int24::MAX);
+    test_assert!(t, m3.dot(m3) == Int48::from_i64(3 * 0x7F_FFFF * 0x7F_FFFF));
+    let p = Vec3::new(Int24::MAX, Int24::zero(), Int24::zero());
+    let q = Vec3::new(Int24::zero(), Int24::MAX, Int24::zero());
+    test_assert!(
+        t,
+        p.cross(q) == Vec3::new(Int24::zero(), Int24::zero(), Int24::MAX)
+    ); // sat
+}
+
+fn test_matrix(t: &impl TestOps) {
+    t.begin("matrix");
+    let f = |x: i32| Int24::from_i32(x);
+    let v = |x: i16, y: i16, z: i16| {
+        Vec3::new(Int24::from_i16(x), Int24::from_i16(y), Int24::from_i16(z))
+    };
+    let a: Mat3x3<8> = Mat3x3::from_bits([
+        [f(0x100), f(0x080), f(0)],
+        [f(0), f(-0x200), f(0x100)],
+        [f(0x040), f(0), f(0x300)],
+    ]);
+    test_assert!(t, a * v(100, 200, -4) == v(200, -404, 13));
+    test_assert!(t, Mat3x3::<8>::identity() * v(1, -2, 3) == v(1, -2, 3));
+    test_assert!(t, a * Mat3x3::identity() == a);
+    test_assert!(t, Mat3x3::identity() * a == a);
+    test_assert!(t, a.transpose().m[0][2] == Fix24::from_bits(f(0x040)));
+    test_assert!(t, a.transpose().transpose() == a);
+    let b = a * a;
+    test_assert!(
+        t,
+        b.m[0]
+            == [
+                Fix24::from_bits(f(0x100)),
+                Fix24::from_bits(f(-0x080)),
+                Fix24::from_bits(f(0x080))
+            ]
+    );
+    test_assert!(
+        t,
+        b.m[1]
+            == [
+                Fix24::from_bits(f(0x040)),
+                Fix24::from_bits(f(0x400)),
+                Fix24::from_bits(f(0x100))
+            ]
+    );
+    test_assert!(
+        t,
+        b.m[2]
+            == [
+                Fix24::from_bits(f(0x100)),
+                Fix24::from_bits(f(0x020)),
+                Fix24::from_bits(f(0x900))
+            ]
+    );
+
+    // Rounding towards negative infinity.
+    test_assert!(t, a * v(-1, 0, 0) == v(-1, 0, -1));
+
+    // Wide accumulation and saturation.
+    let m: Mat3x3<23> = Mat3x3::from_bits([[Int24::MAX; 3]; 3]);
+    let big = Vec3::splat(Int24::MAX);
+    test_assert!(t, m * big == big); // sat
+    test_assert!(t, m * -big == Vec3::splat(Int24::MIN)); // sat
+    let h = Vec3::new(Int24::MAX, Int24::MIN, Int24::zero());
+    test_assert!(t, m * h == Vec3::splat(f(-1)));
+    test_assert!(t, Mat3x3::<23>::identity().m[1][1] == Fix24::MAX); // sat
+}
+
+fn test_conv_checked(t: &impl TestOps) {
+    t.begin("conv_checked");
+    test_assert!(t, Int24::zero().to_u8_checked() == Some(0));
+    test_assert!(t, Int24::from_i16(255).to_u8_checked() == Some(255));
+    test_assert!(t, Int24::from_i16(256).to_u8_checked().is_none());
+    test_assert!(t, Int24::from_i16(-1).to_u8_checked().is_none());
+    test_assert!(t, Int24::MIN.to_u8_checked().is_none());
+    test_assert!(t, Int24::zero().to_usize_checked() == Some(0));
+    test_assert!(
+        t,
+        Int24::from_i16(0x7FFF).to_usize_checked() == Some(0x7FFF)
+    );
+    test_assert!(t, Int24::from_i16(-1).to_usize_checked().is_none());
+    test_assert!(t, Int24::MIN.to_usize_checked().is_none());
+    if usize::BITS >= 24 {
+        test_assert!(
+            t,
+            Int24::MAX.to_usize_checked().map(|v| v as u32) == Some(0x7F_FFFF)
+        );
+    } else {
+        test_assert!(
+            t,
+            Int24::from_i32(0xFFFF).to_usize_checked() == Some(0xFFFF)
+        );
+        test_assert!(t, Int24::from_i32(0x1_0000).to_usize_checked().is_none());
+    }
+}
+
+fn test_scalar_ops(t: &impl TestOps) {
+    t.begin("scalar_ops");
+    let a = Int24::from_i32(100_000);
+    test_assert!(t, a + 5_i16 == Int24::from_i32(100_005));
+    test_assert!(t, a - 5_i16 == Int24::from_i32(99_995));
+    test_assert!(t, a * -3_i16 == Int24::from_i32(-300_000));
+    test_assert!(t, a / 7_i16 == Int24::from_i32(14_285));
+    test_assert!(t, a + -5_i8 == Int24::from_i32(99_995));
+    test_assert!(t, a - -5_i8 == Int24::from_i32(100_005));
+    test_assert!(t, a * 2_i8 == Int24::from_i32(200_000));
+    test_assert!(t, a / -8_i8 == Int24::from_i32(-12_500));
+    test_assert!(t, 5_i16 + a == Int24::from_i32(100_005));
+    test_assert!(t, 5_i16 - a == Int24::from_i32(-99_995));
+    test_assert!(t, -3_i8 * a == Int24::from_i32(-300_000));
+    test_assert!(t, 100_i8 / Int24::from_i16(7) == Int24::from_i16(14));
+    let mut b = a;
+    b += 1_i16;
+    b -= 2_i8;
+    b *= 10_i16;
+    b /= 3_i8;
+    test_assert!(t, b == Int24::from_i32(333_330));
+    test_assert!(t, Int24::MAX + 1_i16 == Int24::MAX); // sat
+    test_assert!(t, Int24::MIN - 1_i8 == Int24::MIN); // sat
+    test_assert!(t, Int24::MAX * i16::MIN == Int24::MIN); // sat
+    test_assert!(t, Int24::MIN / -1_i8 == Int24::MAX); // sat
+    test_assert!(t, a / 0_i16 == Int24::MAX); // sat
+}
+
+fn test_mul_small(t: &impl TestOps) {
+    t.begin("mul_small");
+    let a = Int24::from_i32(0x12_3456);
+    test_assert!(t, a.mul_i16(3) == Int24::from_i32(0x36_9D02));
+    test_assert!(t, a.mul_i16(-3) == Int24::from_i32(-0x36_9D02));
+    test_assert!(
+        t,
+        Int24::from_i16(-1000).mul_i16(-1000) == Int24::from_i32(1_000_000)
+    );
+    test_assert!(t, Int24::MIN.mul_i16(1) == Int24::MIN);
+    test_assert!(t, Int24::MIN.mul_i16(0) == Int24::zero());
+    test_assert!(t, Int24::MIN.mul_i16(-1) == Int24::MAX); // sat
+    test_assert!(t, a.mul_i16(i16::MAX) == Int24::MAX); // sat
+    test_assert!(t, a.mul_i16(i16::MIN) == Int24::MIN); // sat
+    test_assert!(
+        t,
+        Int24::from_i16(-256).mul_i16(0x7FFF) == Int24::from_i32(-0x7F_FF00)
+    );
+    test_assert!(t, Int24::from_i16(-256).mul_i16(i16::MIN) == Int24::MAX); // sat
+
+    test_assert!(t, a.mul_u8(7) == Int24::from_i32(0x7F_6E5A));
+    test_assert!(t, a.mul_u8(8) == Int24::MAX); // sat
+    test_assert!(t, (-a).mul_u8(7) == Int24::from_i32(-0x7F_6E5A));
+    test_assert!(t, (-a).mul_u8(8) == Int24::MIN); // sat
+    test_assert!(t, Int24::MIN.mul_u8(1) == Int24::MIN);
+    test_assert!(t, Int24::MIN.mul_u8(0) == Int24::zero());
+    test_assert!(t, Int24::from_i16(-1).mul_u8(255) == Int24::from_i16(-255));
+    test_assert!(
+        t,
+        Int24::from_i32(0x8000).mul_u8(255) == Int24::from_i32(0x7F_8000)
+    );
+
+    test_assert!(t, a.div_u8(1) == a);
+    test_assert!(t, a.div_u8(255) == Int24::from_i32(0x12_3456 / 255));
+    test_assert!(t, (-a).div_u8(7) == Int24::from_i32(-0x12_3456 / 7));
+    test_assert!(t, Int24::from_i16(-7).div_u8(2) == Int24::from_i16(-3));
+    test_assert!(t, Int24::MIN.div_u8(1) == Int24::MIN);
+    test_assert!(t, Int24::MIN.div_u8(2) == Int24::from_i32(-0x40_0000));
+    test_assert!(t, Int24::MAX.div_u8(128) == Int24::from_i32(0xFFFF));
+    test_assert!(t, Int24::from_i16(100).div_u8(101) == Int24::zero());
+    test_assert!(t, a.div_u8(0) == Int24::MAX); // sat
+    test_assert!(t, (-a).div_u8(0) == Int24::MIN); // sat
+    test_assert!(t, Int24::zero().div_u8(0) == Int24::MAX); // sat
+}
+
+fn test_shift_types(t: &impl TestOps) {
+    t.begin("shift_types");
+    let a = Int24::from_i32(-0x12_3456);
+    test_assert!(t, a << 4_u32 == a << 4_u8);
+    test_assert!(t, a << 4_usize == a << 4_u8);
+    test_assert!(t, a << 4_i32 == a << 4_u8);
+    test_assert!(t, a >> 4_u32 == a >> 4_u8);
+    test_assert!(t, a >> 4_usize == a >> 4_u8);
+    test_assert!(t, a >> 4_i32 == a >> 4_u8);
+    test_assert!(t, Int24::from_i16(-1) << 23_u32 == Int24::MIN);
+    test_assert!(t, a << 24_u32 == Int24::zero());
+    test_assert!(t, a << usize::MAX == Int24::zero());
+    test_assert!(t, a << i32::MAX == Int24::zero());
+    test_assert!(t, a >> 23_u32 == Int24::from_i16(-1));
+    test_assert!(t, a >> 1000_usize == Int24::from_i16(-1));
+    test_assert!(t, -a >> u32::MAX == Int24::zero());
+    test_assert!(t, a >> i32::MAX == Int24::from_i16(-1));
+    test_assert!(t, a << -1_i32 == a);
+    test_assert!(t, a >> i32::MIN == a);
+    let c = Int24::from_i16(-0x1234);
+    let mut b = c;
+    b <<= 4_u32;
+    b >>= 8_usize;
+    b <<= 1_i32;
+    b >>= 1_i32;
+    test_assert!(t, b == c >> 4_u8);
+
+    fn halve<T: core::ops::Shr<u32, Output = T>>(v: T) -> T {
+        v >> 1
+    }
+    test_assert!(t, halve(a) == a >> 1_u8);
+    test_assert!(t, halve(-1_i32) == -1);
+}
+
+#[allow(clippy::op_ref)]
+fn test_ref_ops(t: &impl TestOps) {
+    t.begin("ref_ops");
+    let a = Int24::from_i32(100_000);
+    let b = Int24::from_i16(-7);
+    test_assert!(t, &a + b == a + b);
+    test_assert!(t, a + &b == a + b);
+    test_assert!(t, &a + &b == a + b);
+    test_assert!(t, &a - &b == a - b);
+    test_assert!(t, &a * &b == a * b);
+    test_assert!(t, &a / &b == a / b);
+    test_assert!(t, -&a == -a);
+    test_assert!(t, &Int24::MAX + &Int24::MAX == Int24::MAX); // sat
+    let mut c = a;
+    c += &b;
+    c -= &b;
+    c *= &b;
+    c /= &b;
+    test_assert!(t, c == a);
+
+    // Generic code over references.
+    let data = [a, b, Int24::from_i16(7)];
+    let sum = data.iter().fold(Int24::zero(), |acc, v| acc + v);
+    test_assert!(t, sum == Int24::from_i32(100_000));
+    let prod = data
+        .iter()
+        .skip(1)
+        .fold(Int24::from_i16(1), |acc, v| &acc * v);
+    test_assert!(t, prod == Int24::from_i16(-49));
+}
+
+/// Number of random operand sets per operation in the randomized tests.
+const RANDOM_ITERATIONS: u16 = 2000;
+
+/// xorshift32 pseudo random operand generator for the randomized tests.
+struct RandomOperands {
+    state: u32,
+}
+
+impl RandomOperands {
+    fn new(seed: u32) -> Self {
+        Self {
+            state: if seed == 0 { 0x2545_F491 } else { seed },
+        }
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Get a random operand.
+    ///
+    /// The operands are biased towards the values that exercise the carry chains
+    /// and the saturation limits.
+    fn int24(&mut self) -> Int24 {
+        const PATTERNS: [u8; 4] = [0x00, 0x7F, 0x80, 0xFF];
+        let r = self.next_u32().to_le_bytes();
+        match r[3] & 3 {
+            0 => Int24::from_le_bytes([r[0], r[1], r[2]]),
+            1 => {
+                let mut b = [0; 3];
+                for (i, b) in b.iter_mut().enumerate() {
+                    *b = PATTERNS[((r[3] >> (2 + i * 2)) & 3) as usize] ^ (r[i] & 1);
+                }
+                Int24::from_le_bytes(b)
+            }
+            2 => Int24::from_i8(r[0] as i8),
+            _ => {
+                if r[1] & 1 == 0 {
+                    Int24::from_i32(Int24::MAX.to_i32() - r[0] as i32)
+                } else {
+                    Int24::from_i32(Int24::MIN.to_i32() + r[0] as i32)
+                }
+            }
+        }
+    }
+}
+
+/// Print an operand of a failed randomized test.
+fn print_operand(t: &impl TestOps, name: &str, v: Int24) {
+    let b = v.to_le_bytes();
+    t.print(name);
+    t.print_num(u32::from_le_bytes([b[0], b[1], b[2], 0]));
+}
+
+/// Cross check an operation for random operands `a`, `b` and `count`.
+///
+/// `f` returns the result and the reference result,
+/// or `None`, if the operands are not valid for the operation.
+/// Returns `false` and prints the operands at the first mismatch.
+fn check_random(
+    t: &impl TestOps,
+    rng: &mut RandomOperands,
+    f: impl Fn(Int24, Int24, u8) -> Option<(Int24, Int24)>,
+) -> bool {
+    for _ in 0..RANDOM_ITERATIONS {
+        let a = rng.int24();
+        let b = rng.int24();
+        let count = rng.next_u32() as u8;
+        if let Some((res, reference)) = f(a, b, count)
+            && res != reference
+        {
+            print_operand(t, "mismatch: a=", a);
+            print_operand(t, " b=", b);
+            t.print(" count=");
+            t.print_num(count.into());
+            t.print("\n");
+            return false;
+        }
+    }
+    true
+}
+
+fn test_random(t: &impl TestOps) {
+    t.begin("random");
+    let seed = t.random_seed();
+    t.print("seed: ");
+    t.print_num(seed);
+    t.print("\n");
+    let rng = &mut RandomOperands::new(seed);
+
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, _| Some((a + b, a.const_add(b))))
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, _| Some((a - b, a.const_sub(b))))
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, _| Some((a * b, a.const_mul_shr(b, 0))))
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, c| {
+            let c = c % 48;
+            Some((a.mul_shr(b, c), a.const_mul_shr(b, c)))
+        })
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, _| {
+            (b != Int24::zero()).then(|| (a / b, a.const_div(b)))
+        })
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, _| {
+            (b != Int24::zero()).then(|| (a.shl8div(b), a.const_shl_div(b, 8)))
+        })
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, c| {
+            let c = c % 25;
+            (b != Int24::zero()).then(|| (a.shl_div(b, c), a.const_shl_div(b, c)))
+        })
+    );
+    test_assert!(t, check_random(t, rng, |a, _, _| Some((-a, a.const_neg()))));
+    test_assert!(
+        t,
+        check_random(t, rng, |a, _, _| Some((a.abs(), a.const_abs())))
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, _, _| Some((a.isqrt(), a.const_isqrt())))
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, _, c| {
+            // The left shift truncates to 24 bits.
+            let c = c % 24;
+            let b = (a.to_i32() << c).to_le_bytes();
+            Some((a.shl(c), Int24::from_le_bytes([b[0], b[1], b[2]])))
+        })
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, _, c| {
+            let c = c % 24;
+            Some((a.shr(c), a.const_shr(c)))
+        })
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, _| {
+            let ord = |o: core::cmp::Ordering| Int24::from_i8(o as i8);
+            Some((ord(a.cmp(b)), ord(a.const_cmp(b))))
+        })
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, b, _| {
+            let b = b.cast_to_i16();
+            Some((a.mul_i16(b), a.const_mul_shr(Int24::from_i16(b), 0)))
+        })
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, _, c| {
+            Some((a.mul_u8(c), a.const_mul_shr(Int24::from_i16(c.into()), 0)))
+        })
+    );
+    test_assert!(
+        t,
+        check_random(t, rng, |a, _, c| {
+            (c != 0).then(|| (a.div_u8(c), a.const_div(Int24::from_i16(c.into()))))
+        })
+    );
+}
+
+fn test_xorshift24(t: &impl TestOps) {
+    t.begin("xorshift24");
+
+    let mut rng = XorShift24::new(1);
+    test_assert!(t, rng.next_int24().to_bits() == 0x0C_0981);
+    test_assert!(t, rng.next_int24().to_bits() == 0x61_44C1);
+    test_assert!(t, rng.next() == Some(Int24::from_bits(0x0E_4F21)));
+    test_assert!(t, rng.state() == 0x0E_4F21);
+    test_assert!(
+        t,
+        XorShift24::new(rng.state()).next_int24().to_bits() == 0xA8_5131
+    );
+
+    // Zero seed and the upper 8 bits.
+    test_assert!(t, XorShift24::new(0) == XorShift24::default());
+    test_assert!(t, XorShift24::new(0x0100_0000) == XorShift24::default());
+    test_assert!(t, XorShift24::new(0xAB00_0001) == XorShift24::new(1));
+
+    let mut rng = XorShift24::new(0x12_3456);
+    let mut ok = true;
+    let (mut neg, mut pos) = (false, false);
+    for _ in 0..200 {
+        ok &= rng.next_int24() != Int24::zero();
+        let b = rng.next_bits(4).to_i32();
+        ok &= (-8..8).contains(&b);
+        neg |= b < 0;
+        pos |= b > 0;
+        ok &= (-1..1).contains(&rng.next_bits(0).to_i32());
+        ok &= (-512..=510).contains(&rng.next_tpdf(9).to_i32());
+    }
+    test_assert!(t, ok && neg && pos);
+}
+
+fn test_next_multiple_of(t: &impl TestOps) {
+    t.begin("next_multiple_of");
+
+    let m = |a: i32, b: i32| {
+        Int24::from_i32(a)
+            .next_multiple_of(Int24::from_i32(b))
+            .to_i32()
+    };
+    let c = |a: i32, b: i32| {
+        Int24::from_i32(a)
+            .checked_next_multiple_of(Int24::from_i32(b))
+            .map(|v| v.to_i32())
+    };
+
+    test_assert!(t, m(7, 4) == 8);
+    test_assert!(t, m(8, 4) == 8);
+    test_assert!(t, m(-7, 4) == -4);
+    test_assert!(t, m(7, -4) == 4);
+    test_assert!(t, m(-7, -4) == -8);
+    test_assert!(t, m(0, 5) == 0);
+    test_assert!(t, m(123, 1) == 123);
+    test_assert!(t, m(123, -1) == 123);
+    test_assert!(t, m(1000, 0x10_0000) == 0x10_0000);
+    test_assert!(t, m(-0x80_0000, 0x7F_FFFF) == -0x7F_FFFF);
+    test_assert!(t, m(-5, 0x7F_FFFF) == 0);
+    test_assert!(t, m(123, 0) == 123);
+    test_assert!(t, m(0x7F_FFF0, 32) == 0x7F_FFFF); // sat
+    test_assert!(t, m(-0x7F_FFF0, -32) == -0x80_0000);
+    test_assert!(t, m(-0x7F_FFFF, -3) == -0x80_0000); // sat
+    test_assert!(t, m(1, -0x80_0000) == 0);
+    test_assert!(t, m(-1, -0x80_0000) == -0x80_0000);
+
+    test_assert!(t, c(7, 4) == Some(8));
+    test_assert!(t, c(-7, -4) == Some(-8));
+    test_assert!(t, c(123, 0).is_none());
+    test_assert!(t, c(0x7F_FFE0, 32) == Some(0x7F_FFE0));
+    test_assert!(t, c(0x7F_FFE1, 32).is_none());
+    test_assert!(t, c(-0x7F_FFF0, -32) == Some(-0x80_0000));
+    test_assert!(t, c(-0x7F_FFFF, -3).is_none());
+    test_assert!(t, c(-0x80_0000, -32) == Some(-0x80_0000));
+}
+
+fn test_power_of_two(t: &impl TestOps) {
+    t.begin("power_of_two");
+
+    let p = |v: i32| Int24::from_i32(v).is_power_of_two();
+    test_assert!(t, p(1));
+    test_assert!(t, p(2));
+    test_assert!(t, p(0x40_0000));
+    test_assert!(t, !p(0));
+    test_assert!(t, !p(3));
+    test_assert!(t, !p(-1));
+    test_assert!(t, !p(-0x80_0000));
+    test_assert!(t, !p(0x7F_FFFF));
+
+    let n = |v: i32| Int24::from_i32(v).next_power_of_two().to_i32();
+    test_assert!(t, n(-0x80_0000) == 1);
+    test_assert!(t, n(0) == 1);
+    test_assert!(t, n(1) == 1);
+    test_assert!(t, n(2) == 2);
+    test_assert!(t, n(3) == 4);
+    test_assert!(t, n(0x40_0000) == 0x40_0000);
+    test_assert!(t, n(0x40_0001) == 0x7F_FFFF); // sat
+    test_assert!(t, n(0x7F_FFFF) == 0x7F_FFFF); // sat
+
+    let c = |v: i32| {
+        Int24::from_i32(v)
+            .checked_next_power_of_two()
+            .map(|v| v.to_i32())
+    };
+    test_assert!(t, c(-1) == Some(1));
+    test_assert!(t, c(0x20_0001) == Some(0x40_0000));
+    test_assert!(t, c(0x40_0001).is_none());
+
+    let l = |v: i32| Int24::from_i32(v).checked_ilog2();
+    test_assert!(t, l(1) == Some(0));
+    test_assert!(t, l(255) == Some(7));
+    test_assert!(t, l(256) == Some(8));
+    test_assert!(t, l(0x7F_FFFF) == Some(22));
+    test_assert!(t, l(0).is_none());
+    test_assert!(t, l(-4).is_none());
+}
+
+fn test_div_pow2(t: &impl TestOps) {
+    t.begin("div_pow2");
+
+    for k in 0..23 {
+        let b = Int24::from_i32(1 << k);
+        for a in [
+            0, 1, -1, 1000, -1000, 0x12_3456, -0x12_3456, 0x7F_FFFF, -0x80_0000,
+        ] {
+            let a = Int24::from_i32(a);
+            test_assert!(t, a / b == a.const_div(b));
+            test_assert!(t, a.shl8div(b) == a.const_shl8div(b));
+        }
+    }
+
+    // Rounding towards zero.
+    let a = Int24::from_i32(-7);
+    test_assert!(t, a / Int24::from_i32(2) == Int24::from_i32(-3));
+    test_assert!(t, a.shl8div(Int24::from_i32(0x400)) == Int24::from_i32(-1));
+    let a = Int24::from_i32(-0x7F_FFFF);
+    test_assert!(t, a / Int24::from_i32(0x40_0000) == Int24::from_i32(-1));
+
+    let a = Int24::from_i32(0x10_0000);
+    test_assert!(t, a.shl8div(Int24::from_i32(16)) == Int24::MAX); // sat
+    test_assert!(t, (-a).shl8div(Int24::from_i32(16)) == Int24::MIN); // sat
+
+    // Negative powers of two and zero take the division loop.
+    let a = Int24::from_i32(-1000);
+    test_assert!(t, a / Int24::from_i32(-8) == Int24::from_i32(125));
+    test_assert!(t, a / Int24::MIN == Int24::zero());
+    test_assert!(t, a / Int24::zero() == Int24::MIN); // sat
+}
+
+fn test_recip_q23(t: &impl TestOps) {
+    t.begin("recip_q23");
+
+    let r = |v: i32| Int24::from_i32(v).recip_q23().to_bits().to_i32();
+    test_assert!(t, r(2) == 0x40_0000);
+    test_assert!(t, r(-2) == -0x40_0000);
+    test_assert!(t, r(3) == 2796203);
+    test_assert!(t, r(-3) == -2796203);
+    test_assert!(t, r(1000) == 8389);
+    test_assert!(t, r(0x40_0000) == 2);
+    test_assert!(t, r(0x60_0000) == 1);
+    test_assert!(t, r(0x7F_FFFF) == 1);
+    test_assert!(t, r(-0x80_0000) == -1);
+    test_assert!(t, r(-1) == -0x80_0000);
+    test_assert!(t, r(1) == 0x7F_FFFF); // sat
+    test_assert!(t, r(0) == 0x7F_FFFF); // sat
+
+    let r7 = Int24::from_i16(-7).recip_q23();
+    test_assert!(
+        t,
+        r7 * Int24::from_i32(-700_000) == Int24::from_i32(100_000)
+    );
+    // Rounded towards negative infinity.
+    test_assert!(
+        t,
+        r7 * Int24::from_i32(700_000) == Int24::from_i32(-100_001)
+    );
+}
+
+fn test_angle(t: &impl TestOps) {
+    t.begin("angle");
+
+    let deg = Angle24::from_degrees;
+    test_assert!(t, deg(0) == Angle24::ZERO);
+    test_assert!(t, deg(90) == Angle24::QUARTER);
+    test_assert!(t, deg(180) == Angle24::HALF);
+    test_assert!(t, deg(-180) == Angle24::HALF);
+    test_assert!(t, deg(270) == -Angle24::QUARTER);
+    test_assert!(t, deg(360 + 45).to_bits() == Int24::from_i32(0x20_0000));
+    test_assert!(t, deg(1).to_bits() == Int24::from_i32(46603));
+    test_assert!(t, deg(-1).to_bits() == Int24::from_i32(-46603));
+
+    // Wrapping arithmetic.
+    test_assert!(t, deg(170) + deg(20) == deg(-170));
+    test_assert!(t, deg(-170) - deg(170) == deg(20));
+    test_assert!(t, -Angle24::HALF == Angle24::HALF);
+    test_assert!(t, deg(45) * 10 == Angle24::QUARTER);
+    test_assert!(t, Angle24::QUARTER * 3 == -Angle24::QUARTER);
+    test_assert!(t, deg(30) * -1 == deg(-30));
+    let mut a = deg(315);
+    a += deg(90);
+    test_assert!(t, a == deg(45));
+    a -= deg(135);
+    test_assert!(t, a == -Angle24::QUARTER);
+
+    test_assert!(t, deg(-20).to_degrees() == I16F8::from_int(-20));
+    test_assert!(t, Angle24::QUARTER.to_degrees() == I16F8::from_int(90));
+    test_assert!(t, Angle24::HALF.to_degrees() == I16F8::from_int(-180));
+    test_assert!(t, deg(1).to_degrees() == I16F8::from_int(1));
+    test_assert!(t, deg(-179).to_degrees() == I16F8::from_int(-179));
+    test_assert!(
+        t,
+        (-Angle24::from_bits(Int24::from_i16(100)))
+            .to_degrees()
+            .to_bits()
+            == Int24::from_i16(-1)
+    ); // -0.002
+
+    test_assert!(t, deg(10).abs_diff(deg(-10)) == deg(20).to_bits());
+    test_assert!(t, deg(170).abs_diff(deg(-170)) == deg(20).to_bits());
+    test_assert!(t, Angle24::HALF.abs_diff(Angle24::ZERO) == Int24::MAX); // sat
+
+    let near = |a: Frac24, b: i32| (a.to_bits().to_i32() - b).abs() <= 2;
+    let (s, c) = deg(30).sin_cos();
+    test_assert!(t, near(s, 4194304) && near(c, 7264748));
+    test_assert!(t, near(deg(-150).sin(), -4194304));
+    test_assert!(t, near(deg(120).cos(), -4194304));
+
+    let i = Int24::from_i16;
+    test_assert!(t, Angle24::atan2(i(5), i(0)) == Angle24::QUARTER);
+    test_assert!(t, Angle24::atan2(i(0), i(-5)) == Angle24::HALF);
+    test_assert!(
+        t,
+        Int24::from(Angle24::QUARTER) == Int24::from_i32(0x40_0000)
+    );
+    test_assert!(t, Angle24::from(Int24::MIN) == Angle24::HALF);
+}
+
+fn test_nco(t: &impl TestOps) {
+    t.begin("nco");
+
+    test_assert!(
+        t,
+        Nco24::increment_for(1000, 8000) == Int24::from_i32(0x20_0000)
+    );
+    test_assert!(t, Nco24::increment_for(1, 3) == Int24::from_i32(5592405));
+    test_assert!(t, Nco24::increment_for(2, 3) == Int24::MAX); // sat
+    test_assert!(t, Nco24::increment_for(u32::MAX, 1) == Int24::MAX); // sat
+    test_assert!(t, Nco24::increment_for(1, 0) == Int24::MAX); // sat
+    test_assert!(t, Nco24::increment_for(0, 8000) == Int24::zero());
+
+    let mut nco = Nco24::from_frequency(1000, 8000);
+    let mut pulses = 0;
+    for i in 1..=80 {
+        if nco.step() {
+            pulses += 1;
+            test_assert!(t, i % 8 == 0);
+        }
+    }
+    test_assert!(t, pulses == 10);
+    test_assert!(t, nco.phase() == Angle24::ZERO);
+
+    // Change the frequency without a phase jump.
+    nco.step();
+    nco.set_increment(Int24::from_i32(0x10_0000));
+    test_assert!(t, nco.increment() == Int24::from_i32(0x10_0000));
+    nco.step();
+    test_assert!(t, nco.phase().to_bits() == Int24::from_i32(0x30_0000));
+
+    // Backwards.
+    let mut nco = Nco24::new(Int24::from_i32(-0x40_0000));
+    nco.set_phase(Angle24::QUARTER);
+    test_assert!(t, !nco.step());
+    test_assert!(t, nco.step());
+    test_assert!(t, nco.phase() == -Angle24::QUARTER);
+    test_assert!(t, !nco.step());
+
+    let mut nco = Nco24::new(Int24::zero());
+    test_assert!(t, !nco.step());
+
+    let near = |a: Frac24, b: i32| (a.to_bits().to_i32() - b).abs() <= 2;
+    let mut nco = Nco24::from_frequency(1, 4);
+    test_assert!(t, near(nco.sample_sin(), 0));
+    test_assert!(t, nco.sample_sin() == Frac24::MAX);
+    test_assert!(t, near(nco.sample_sin(), 0));
+    test_assert!(t, nco.sample_sin() == Frac24::MIN);
+    test_assert!(t, nco.sample_saw() == Frac24::ZERO);
+    test_assert!(t, nco.sample_saw() == Frac24::HALF);
+    test_assert!(t, nco.sample_saw() == Frac24::MIN);
+    test_assert!(t, nco.sample_saw() == -Frac24::HALF);
+}
+
+fn test_calibration(t: &impl TestOps) {
+    t.begin("calibration");
+
+    let i = Int24::from_i32;
+    let cal = Calibration::new([(i(-1000), i(-1100)), (i(0), i(50)), (i(1000), i(1000))]);
+    test_assert!(t, cal.is_sorted());
+    test_assert!(t, cal.points()[1] == (i(0), i(50)));
+    test_assert!(t, cal.apply(i(-2000)) == i(-1100));
+    test_assert!(t, cal.apply(i(-1000)) == i(-1100));
+    test_assert!(t, cal.apply(i(-500)) == i(-525));
+    test_assert!(t, cal.apply(i(0)) == i(50));
+    test_assert!(t, cal.apply(i(500)) == i(525));
+    test_assert!(t, cal.apply(Int24::MAX) == i(1000));
+    test_assert!(t, cal.lut().lookup(i(500)) == i(525));
+
+    let mut buf = [0xFF; Calibration::<3>::PERSIST_LEN + 1];
+    test_assert!(t, Calibration::<3>::PERSIST_LEN == 24);
+    test_assert!(t, cal.write_to(&mut buf).is_ok());
+    test_assert!(t, buf[24] == 0xFF);
+    test_assert!(t, Calibration::<3>::read_from(&buf) == Ok(cal));
+    let short_err = Error::SliceTooShort {
+        needed: 24,
+        len: 23,
+    };
+    test_assert!(t, Calibration::<3>::read_from(&buf[..23]) == Err(short_err));
+    test_assert!(t, cal.write_to(&mut [0; 23]) == Err(short_err));
+    buf[13] ^= 1;
+    test_assert!(t, Calibration::<3>::read_from(&buf) == Err(Error::Checksum));
+    test_assert!(
+        t,
+        Calibration::<3>::read_from(&[0xFF; 24]) == Err(Error::Checksum)
+    );
+
+    let unsorted = Calibration::new([(i(10), i(0)), (i(5), i(0))]);
+    test_assert!(t, !unsorted.is_sorted());
+    let mut buf = [0; 16];
+    test_assert!(t, unsorted.write_to(&mut buf).is_ok());
+    test_assert!(t, Calibration::<2>::read_from(&buf) == Err(Error::Unsorted));
+
+    let empty = Calibration::<0>::new([]);
+    test_assert!(t, empty.apply(i(42)) == Int24::zero());
+    test_assert!(t, Calibration::<0>::read_from(&[]) == Ok(empty));
+}
+
+fn test_poly_eval(t: &impl TestOps) {
+    t.begin("poly_eval");
+
+    let int = |v: i32| Fix24::<0>::from_bits(Int24::from_i32(v));
+    test_assert!(t, poly_eval(int(3), &[int(1), int(2), int(3)]) == int(34));
+    test_assert!(t, poly_eval(int(-3), &[int(1), int(2), int(3)]) == int(22));
+    test_assert!(t, poly_eval(int(5), &[int(7)]) == int(7));
+    test_assert!(t, poly_eval(int(5), &[]) == int(0));
+    test_assert!(
+        t,
+        poly_eval(int(1000), &[int(0), int(0), int(0), int(1)]) == int(0x7F_FFFF)
+    ); // sat
+    test_assert!(
+        t,
+        poly_eval(int(-1000), &[int(0), int(0), int(0), int(1)]) == int(-0x80_0000)
+    ); // sat
+
+    // The intermediate x^2 doesn't saturate.
+    let c = [int(-0x80_0000), int(0), int(1)];
+    test_assert!(t, poly_eval(int(0xFFF), &c) == int(0x7F_E001));
+    test_assert!(t, poly_eval(int(-0xFFF), &c) == int(0x7F_E001));
+
+    // Q7.16 coefficients and a Q0.23 argument.
+    let q16 = |v: i32| Fix24::<16>::from_bits(Int24::from_i32(v));
+    let half: Fix24<23> = Frac24::HALF.into();
+    let c = [q16(1 << 16), q16(2 << 16), q16(4 << 16)];
+    test_assert!(t, poly_eval(half, &c) == q16(3 << 16));
+    test_assert!(t, poly_eval(-half, &c) == q16(1 << 16));
+    // Third order: 0.25 - 0.5 x + 0.75 x^2 - 1.0 x^3 at x = 0.5
+    let c = [q16(0x4000), q16(-0x8000), q16(0xC000), q16(-0x1_0000)];
+    test_assert!(t, poly_eval(half, &c) == q16(0x1000));
+    // Rounded towards negative infinity.
+    let x = Fix24::<23>::from_bits(Int24::from_i8(1));
+    test_assert!(t, poly_eval(x, &[q16(0), q16(-1)]) == q16(-1));
+    test_assert!(t, poly_eval(x, &[q16(0), q16(1)]) == q16(0));
+}
+
+fn test_slice_bytes(t: &impl TestOps) {
+    t.begin("slice_bytes");
+
+    let bytes = [0x01, 0x02, 0x83, 0x04];
+    test_assert!(
+        t,
+        Int24::try_from_le_slice(&bytes) == Ok(Int24::from_i32(-0x7C_FDFF))
+    );
+    test_assert!(
+        t,
+        Int24::try_from_be_slice(&bytes) == Ok(Int24::from_i32(0x01_0283))
+    );
+    test_assert!(
+        t,
+        Int24::try_from_le_slice(&bytes[1..]) == Ok(Int24::from_i32(0x04_8302))
+    );
+    let short = Error::SliceTooShort { needed: 3, len: 2 };
+    test_assert!(t, Int24::try_from_le_slice(&bytes[2..]) == Err(short));
+    test_assert!(t, Int24::try_from_be_slice(&bytes[2..]) == Err(short));
+    let empty = Err(Error::SliceTooShort { needed: 3, len: 0 });
+    test_assert!(t, Int24::try_from_le_slice(&[]) == empty);
+
+    let v = Int24::from_i32(-0x7C_FDFF);
+    let mut buf = [0xAA; 5];
+    test_assert!(t, v.write_le_to(&mut buf[1..]) == Ok(()));
+    test_assert!(t, buf == [0xAA, 0x01, 0x02, 0x83, 0xAA]);
+    test_assert!(t, v.write_be_to(&mut buf[2..]) == Ok(()));
+    test_assert!(t, buf == [0xAA, 0x01, 0x83, 0x02, 0x01]);
+    test_assert!(t, v.write_le_to(&mut buf[3..]) == Err(short));
+    test_assert!(t, v.write_be_to(&mut buf[3..]) == Err(short));
+    test_assert!(t, buf == [0xAA, 0x01, 0x83, 0x02, 0x01]);
+}
+
+fn test_error(t: &impl TestOps) {
+    t.begin("error");
+
+    test_assert!(t, Int24::try_from(0x7F_FFFF) == Ok(Int24::MAX));
+    test_assert!(t, Int24::try_from(-0x80_0000) == Ok(Int24::MIN));
+    test_assert!(t, Int24::try_from(0x80_0000) == Err(Error::OutOfRange));
+    test_assert!(t, Int24::try_from(-0x80_0001) == Err(Error::OutOfRange));
+
+    let i = Int24::from_i32;
+    test_assert!(t, i8::try_from(i(-128)) == Ok(-128));
+    test_assert!(t, i8::try_from(i(128)) == Err(Error::OutOfRange));
+    test_assert!(t, i16::try_from(i(-32768)) == Ok(-32768));
+    test_assert!(t, i16::try_from(i(-32769)) == Err(Error::OutOfRange));
+    test_assert!(t, u8::try_from(i(255)) == Ok(255));
+    test_assert!(t, u8::try_from(i(-1)) == Err(Error::OutOfRange));
+    test_assert!(t, u16::try_from(i(0x1_0000)) == Err(Error::OutOfRange));
+    test_assert!(t, u32::try_from(Int24::MAX) == Ok(0x7F_FFFF));
+    test_assert!(t, u32::try_from(Int24::MIN) == Err(Error::OutOfRange));
+    test_assert!(t, usize::try_from(i(42)) == Ok(42));
+
+    test_assert!(t, "-1234".parse::<Int24>() == Ok(i(-1234)));
+    test_assert!(t, "99999999".parse::<Int24>() == Ok(Int24::MAX)); // sat
+    test_assert!(t, "12a".parse::<Int24>() == Err(Error::Parse));
+    test_assert!(t, "".parse::<Int24>() == Err(Error::Parse));
+    test_assert!(
+        t,
+        "1.25".parse::<Fix24<8>>() == Ok(Fix24::from_bits(i(0x140)))
+    );
+    test_assert!(t, "1..25".parse::<Fix24<8>>() == Err(Error::Parse));
+
+    let f = |b: &[u8]| -> Result<u8, Error> { u8::try_from(Int24::try_from_le_slice(b)?) };
+    test_assert!(t, f(&[7, 0, 0]) == Ok(7));
+    test_assert!(t, f(&[0, 1, 0]) == Err(Error::OutOfRange));
+    test_assert!(
+        t,
+        f(&[0]) == Err(Error::SliceTooShort { needed: 3, len: 1 })
+    );
+}
+
+fn test_saturating_unsigned(t: &impl TestOps) {
+    t.begin("saturating_unsigned");
+
+    let i = Int24::from_i32;
+    test_assert!(
+        t,
+        i(-1).saturating_add_unsigned(u16::MAX.into()) == i(0xFFFE)
+    );
+    test_assert!(
+        t,
+        i(1).saturating_sub_unsigned(u16::MAX.into()) == i(-0xFFFE)
+    );
+    test_assert!(
+        t,
+        Int24::MIN.saturating_add_unsigned(0xFF_FFFF) == Int24::MAX
+    );
+    test_assert!(
+        t,
+        Int24::MIN.saturating_add_unsigned(0xFF_FFFE) == i(0x7F_FFFE)
+    );
+    test_assert!(
+        t,
+        Int24::MAX.saturating_sub_unsigned(0xFF_FFFF) == Int24::MIN
+    );
+    test_assert!(
+        t,
+        Int24::MAX.saturating_sub_unsigned(0xFF_FFFE) == i(-0x7F_FFFF)
+    );
+    test_assert!(
+        t,
+        Int24::MIN.saturating_add_unsigned(0x100_0000) == Int24::MAX
+    ); // sat
+    test_assert!(t, Int24::MAX.saturating_add_unsigned(1) == Int24::MAX); // sat
+    test_assert!(t, Int24::MIN.saturating_sub_unsigned(1) == Int24::MIN); // sat
+    test_assert!(t, i(0).saturating_add_unsigned(u32::MAX) == Int24::MAX); // sat
+    test_assert!(t, i(0).saturating_sub_unsigned(u32::MAX) == Int24::MIN); // sat
+    test_assert!(t, i(-5).saturating_add_unsigned(0) == i(-5));
+    test_assert!(t, i(-5).saturating_sub_unsigned(0) == i(-5));
+}
+
+#[cfg(feature = "strict-ops")]
+fn test_strict(t: &impl TestOps) {
+    t.begin("strict");
+
+    let i = Int24::from_i32;
+    test_assert!(
+        t,
+        Int24::MAX.strict_sub(i(1)).strict_add(i(1)) == Int24::MAX
+    );
+    test_assert!(
+        t,
+        Int24::MIN.strict_add(i(1)).strict_sub(i(1)) == Int24::MIN
+    );
+    test_assert!(t, i(-4096).strict_mul(i(2048)) == Int24::MIN);
+    test_assert!(
+        t,
+        i(0x10_0000).strict_mul_shr(i(0x10_0000), 20) == i(0x10_0000)
+    );
+    test_assert!(t, Int24::MIN.strict_div(i(1)) == Int24::MIN);
+    test_assert!(t, i(1000).strict_shl8div(i(256)) == i(1000));
+    test_assert!(t, Int24::MAX.strict_neg() == i(-0x7F_FFFF));
+    test_assert!(t, i(-0x7F_FFFF).strict_abs() == Int24::MAX);
+}
+
+fn test_unchecked(t: &impl TestOps) {
+    t.begin("unchecked");
+
+    let i = Int24::from_i32;
+    // SAFETY: None of the results overflow.
+    unsafe {
+        test_assert!(t, i(1000).unchecked_add(i(-3000)) == i(-2000));
+        test_assert!(t, i(0x7F_FFFE).unchecked_add(i(1)) == Int24::MAX);
+        test_assert!(t, i(-0x7F_FFFF).unchecked_add(i(-1)) == Int24::MIN);
+        test_assert!(t, Int24::MIN.unchecked_add(Int24::MAX) == i(-1));
+
+        test_assert!(t, i(0).unchecked_mul(Int24::MIN) == i(0));
+        test_assert!(t, i(-1).unchecked_mul(i(-1)) == i(1));
+        test_assert!(t, i(-1000).unchecked_mul(i(1000)) == i(-1_000_000));
+        test_assert!(t, i(-4096).unchecked_mul(i(2048)) == Int24::MIN);
+        test_assert!(t, Int24::MIN.unchecked_mul(i(1)) == Int24::MIN);
+        test_assert!(t, i(1).unchecked_mul(Int24::MIN) == Int24::MIN);
+        test_assert!(t, Int24::MAX.unchecked_mul(i(-1)) == i(-0x7F_FFFF));
+        test_assert!(t, i(0x1234).unchecked_mul(i(0x123)) == i(0x14_B11C));
+        test_assert!(t, i(-0x1234).unchecked_mul(i(-0x123)) == i(0x14_B11C));
+    }
+}
+
+fn test_ct(t: &impl TestOps) {
+    t.begin("ct");
+
+    let vals = [
+        Int24::MIN,
+        Int24::from_i32(-0x7F_FFFF),
+        Int24::from_i32(-0x12_3456),
+        Int24::from_i16(-4096),
+        Int24::from_i16(-1000),
+        Int24::from_i8(-1),
+        Int24::zero(),
+        Int24::from_i8(1),
+        Int24::from_i8(3),
+        Int24::from_i16(2048),
+        Int24::from_i32(0x40_0000),
+        Int24::from_i32(0x7F_FFFE),
+        Int24::MAX,
+    ];
+    for a in vals {
+        test_assert!(t, a.ct_neg() == -a);
+        test_assert!(t, a.ct_abs() == a.abs());
+        for b in vals {
+            test_assert!(t, a.ct_add(b) == a + b);
+            test_assert!(t, a.ct_sub(b) == a - b);
+            test_assert!(t, a.ct_mul(b) == a * b);
+            test_assert!(t, a.ct_div(b) == a / b);
+        }
+    }
+}
+
+fn test_in_place(t: &impl TestOps) {
+    t.begin("in_place");
+
+    let i = Int24::from_i32;
+    let mut a = i(1000);
+    a.add_in_place(i(-3000));
+    test_assert!(t, a == i(-2000));
+    a.sub_in_place(i(-0x12_3456));
+    test_assert!(t, a == i(0x12_2C86));
+    a.add_in_place(Int24::MAX); // sat
+    test_assert!(t, a == Int24::MAX);
+    a.sub_in_place(i(1));
+    test_assert!(t, a == i(0x7F_FFFE));
+    a.sub_in_place(Int24::MAX);
+    a.sub_in_place(Int24::MAX); // sat
+    test_assert!(t, a == Int24::MIN);
+    a.add_in_place(i(-1)); // sat
+    test_assert!(t, a == Int24::MIN);
+    a.sub_in_place(i(1)); // sat
+    test_assert!(t, a == Int24::MIN);
+    a.sub_in_place(Int24::MIN);
+    test_assert!(t, a == i(0));
+
+    let mut buf = [i(5), i(6), i(7)];
+    for v in buf.iter_mut() {
+        v.add_in_place(i(0x7F_FFFA)); // sat
+    }
+    test_assert!(t, buf == [i(0x7F_FFFF), Int24::MAX, Int24::MAX]);
+}
+
+fn test_buffers(t: &impl TestOps) {
+    t.begin("buffers");
+
+    let pack = |v: [i32; 4]| {
+        let mut b = [0xAA; 13];
+        for (c, v) in b.as_chunks_mut::<3>().0.iter_mut().zip(v) {
+            *c = Int24::from_i32(v).to_le_bytes();
+        }
+        b
+    };
+    let src = pack([1, -0x12_3456, Int24::MAX.to_i32(), Int24::MIN.to_i32()]);
+
+    let mut dst = pack([-3, 0x20_0000, 1, -1]);
+    add_buffers(&mut dst, &src);
+    test_assert!(
+        t,
+        dst == pack([-2, -0x12_3456 + 0x20_0000, 0x7F_FFFF, -0x80_0000])
+    ); // sat
+
+    let mut dst = pack([-3, 0x20_0000, -2, 1]);
+    sub_buffers(&mut dst, &src);
+    test_assert!(t, dst == pack([-4, 0x32_3456, -0x80_0000, 0x7F_FFFF])); // sat
+
+    let mut dst = pack([5, -5, Int24::MAX.to_i32(), Int24::MIN.to_i32()]);
+    neg_buffer(&mut dst);
+    test_assert!(t, dst == pack([-5, 5, -0x7F_FFFF, 0x7F_FFFF])); // sat
+
+    // The shorter buffer determines the number of elements.
+    let mut dst = pack([1, 2, 3, 4]);
+    add_buffers(&mut dst[..8], &src);
+    test_assert!(t, dst == pack([2, -0x12_3454, 3, 4]));
+    add_buffers(&mut dst, &src[..2]);
+    test_assert!(t, dst == pack([2, -0x12_3454, 3, 4]));
+    add_buffers(&mut [], &src);
+    neg_buffer(&mut []);
+}
+
+fn test_table(t: &impl TestOps) {
+    t.begin("table");
+
+    const RAMP: [Int24; 5] = int24_table!(len = 5, |i| i as f64 * -1.75);
+    test_assert!(t, RAMP.map(|v| v.to_i32()) == [0, -2, -4, -5, -7]);
+
+    const ROUND: [Int24; 6] = int24_table!(len = 6, |i| i as f64 * 0.5 - 1.5);
+    test_assert!(t, ROUND.map(|v| v.to_i32()) == [-2, -1, -1, 0, 1, 1]);
+
+    const SAT: [Int24; 4] = int24_table!(len = 4, |i| match i {
+        0 => 1e30,
+        1 => -1e30,
+        2 => f64::NAN,
+        _ => -8_388_608.9,
+    });
+    test_assert!(
+        t,
+        SAT == [Int24::MAX, Int24::MIN, Int24::zero(), Int24::MIN]
+    );
+
+    const CONST: [Int24; 3] = int24_table!(len = 3, |_| 42.0);
+    test_assert!(t, CONST == [Int24::from_i8(42); 3]);
+}
+
+fn test_q15(t: &impl TestOps) {
+    t.begin("q15");
+
+    let i = Int24::from_i32;
+    test_assert!(t, Int24::from_q15(0) == i(0));
+    test_assert!(t, Int24::from_q15(0x1234) == i(0x12_3400));
+    test_assert!(t, Int24::from_q15(-1) == i(-0x100));
+    test_assert!(t, Int24::from_q15(i16::MAX) == i(0x7F_FF00));
+    test_assert!(t, Int24::from_q15(i16::MIN) == Int24::MIN);
+
+    test_assert!(t, i(0x12_347F).to_q15_sat() == 0x1234);
+    test_assert!(t, i(0x12_3480).to_q15_sat() == 0x1235);
+    test_assert!(t, i(-0x12_3480).to_q15_sat() == -0x1234);
+    test_assert!(t, i(-0x12_3481).to_q15_sat() == -0x1235);
+    test_assert!(t, i(0x7F_FF7F).to_q15_sat() == i16::MAX);
+    test_assert!(t, Int24::MAX.to_q15_sat() == i16::MAX); // sat
+    test_assert!(t, Int24::MIN.to_q15_sat() == i16::MIN);
+    for v in [i16::MIN, -1234, -1, 0, 1, 0x4000, i16::MAX] {
+        test_assert!(t, Int24::from_q15(v).to_q15_sat() == v);
+    }
+
+    test_assert!(t, i(0x40_0000).mul_q15(0x4000) == i(0x20_0000));
+    test_assert!(t, i(1000).mul_q15(-0x4000) == i(-500));
+    test_assert!(t, i(1001).mul_q15(0x4000) == i(500));
+    test_assert!(t, i(-1001).mul_q15(0x4000) == i(-501));
+    test_assert!(t, i(-0x12_3456).mul_q15(i16::MIN) == i(0x12_3456));
+    test_assert!(t, Int24::MAX.mul_q15(i16::MAX) == i(0x7F_FEFF));
+    test_assert!(t, Int24::MAX.mul_q15(i16::MIN) == i(-0x7F_FFFF));
+    test_assert!(t, Int24::MIN.mul_q15(i16::MIN) == Int24::MAX); // sat
+    test_assert!(t, Int24::MIN.mul_q15(0) == i(0));
+}
+
+fn test_duty(t: &impl TestOps) {
+    t.begin("duty");
+
+    let i = Int24::from_i32;
+    test_assert!(t, i(0).to_duty(255) == 0);
+    test_assert!(t, i(0x4_0000).to_duty(255) == 8); // 7.97
+    test_assert!(t, i(0x40_0000).to_duty(255) == 128);
+    test_assert!(t, Int24::MAX.to_duty(255) == 255);
+    test_assert!(t, Int24::MAX.to_duty(u16::MAX) == u16::MAX);
+    test_assert!(t, Int24::MAX.to_duty(0) == 0);
+    test_assert!(t, i(-1).to_duty(255) == 0);
+    test_assert!(t, Int24::MIN.to_duty(u16::MAX) == 0);
+
+    test_assert!(t, Int24::MIN.to_duty_signed(255) == 0);
+    test_assert!(t, i(0).to_duty_signed(255) == 128);
+    test_assert!(t, i(0).to_duty_signed(1000) == 500);
+    test_assert!(t, i(-0x40_0000).to_duty_signed(1000) == 250);
+    test_assert!(t, Int24::MAX.to_duty_signed(255) == 255);
+    test_assert!(t, Int24::MAX.to_duty_signed(u16::MAX) == u16::MAX);
+    test_assert!(t, Int24::MIN.to_duty_signed(u16::MAX) == 0);
+
+    test_assert!(t, Int24::from_duty(0, 255) == i(0));
+    test_assert!(t, Int24::from_duty(255, 255) == Int24::MAX);
+    test_assert!(t, Int24::from_duty(300, 255) == Int24::MAX);
+    test_assert!(t, Int24::from_duty(1, 2) == i(0x40_0000));
+    test_assert!(t, Int24::from_duty(5, 0) == i(0));
+    test_assert!(t, Int24::from_duty_signed(0, 255) == Int24::MIN);
+    test_assert!(t, Int24::from_duty_signed(1, 2) == i(0));
+    test_assert!(t, Int24::from_duty_signed(999, 998) == Int24::MAX);
+    test_assert!(t, Int24::from_duty_signed(5, 0) == i(0));
+    for top in [1, 255, 1000, 1023, u16::MAX] {
+        for duty in [0, 1, top / 3, top / 2, top - 1, top] {
+            test_assert!(t, Int24::from_duty(duty, top).to_duty(top) == duty);
+            test_assert!(
+                t,
+                Int24::from_duty_signed(duty, top).to_duty_signed(top) == duty
+            );
+        }
+    }
+}
+
+fn test_timestamp(t: &impl TestOps) {
+    t.begin("timestamp");
+
+    let b = Int24::from_bits;
+    test_assert!(
+        t,
+        b(0x10).wrapping_delta(b(0xFF_FFF0)) == Int24::from_i16(0x20)
+    );
+    test_assert!(
+        t,
+        b(0xFF_FFF0).wrapping_delta(b(0x10)) == Int24::from_i16(-0x20)
+    );
+    test_assert!(t, b(0x7F_FFFF).wrapping_delta(b(0)) == Int24::MAX);
+    test_assert!(t, b(0x80_0000).wrapping_delta(b(0)) == Int24::MIN);
+
+    test_assert!(t, b(0x100).elapsed_since(b(0xFF_FF00)) == 0x200);
+    test_assert!(t, b(0xFF_FF00).elapsed_since(b(0x100)) == 0xFF_FE00);
+    test_assert!(t, b(0x1234).elapsed_since(b(0x1234)) == 0);
+    test_assert!(t, b(0xFF_FFFF).elapsed_since(b(0)) == 0xFF_FFFF);
+    test_assert!(t, b(0).elapsed_since(b(0xFF_FFFF)) == 1);
+    test_assert!(t, Int24::MIN.elapsed_since(Int24::MAX) == 1); // no sat
+
+    test_assert!(
+        t,
+        b(0x10).wrapping_cmp(b(0xFF_FFF0)) == core::cmp::Ordering::Greater
+    );
+    test_assert!(
+        t,
+        b(0xFF_FFF0).wrapping_cmp(b(0x10)) == core::cmp::Ordering::Less
+    );
+    test_assert!(
+        t,
+        b(0x1234).wrapping_cmp(b(0x1234)) == core::cmp::Ordering::Equal
+    );
+    test_assert!(
+        t,
+        b(0x7F_FFFF).wrapping_cmp(b(0)) == core::cmp::Ordering::Greater
+    );
+    test_assert!(
+        t,
+        b(0x80_0000).wrapping_cmp(b(0)) == core::cmp::Ordering::Less
+    );
+    test_assert!(
+        t,
+        Int24::MIN.wrapping_cmp(Int24::MAX) == core::cmp::Ordering::Greater
+    );
+}
+
+fn test_bisect(t: &impl TestOps) {
+    t.begin("bisect");
+
+    let i = Int24::from_i32;
+    let cmp = |target: i32| move |x: Int24| x.to_i32().cmp(&target);
+    test_assert!(t, bisect(Int24::MIN, Int24::MAX, cmp(0)) == Ok(i(0)));
+    test_assert!(
+        t,
+        bisect(Int24::MIN, Int24::MAX, cmp(-0x80_0000)) == Ok(Int24::MIN)
+    );
+    test_assert!(
+        t,
+        bisect(Int24::MIN, Int24::MAX, cmp(0x7F_FFFE)) == Ok(i(0x7F_FFFE))
+    );
+    test_assert!(
+        t,
+        bisect(Int24::MIN, Int24::MAX, cmp(0x7F_FFFF)) == Err(Int24::MAX)
+    );
+    test_assert!(t, bisect(i(10), i(20), cmp(5)) == Err(i(10)));
+    test_assert!(t, bisect(i(10), i(20), cmp(25)) == Err(i(20)));
+    test_assert!(t, bisect(i(10), i(10), cmp(10)) == Err(i(10)));
+    test_assert!(t, bisect(i(20), i(10), cmp(15)) == Err(i(20)));
+
+    // Monotone function with gaps: 3x.
+    let f = |target: i32| move |x: Int24| (x.to_i32() * 3).cmp(&target);
+    test_assert!(t, bisect(i(-1000), i(1000), f(300)) == Ok(i(100)));
+    test_assert!(t, bisect(i(-1000), i(1000), f(301)) == Err(i(101)));
+    test_assert!(t, bisect(i(-1000), i(1000), f(-301)) == Err(i(-100)));
+
+    let calls = Cell::new(0);
+    let r = bisect(Int24::MIN, Int24::MAX, |x| {
+        calls.set(calls.get() + 1);
+        x.cmp(Int24::MAX)
+    });
+    test_assert!(t, r == Err(Int24::MAX));
+    test_assert!(t, calls.get() <= 25);
+}
+
+fn test_ratio(t: &impl TestOps) {
+    t.begin("ratio");
+
+    let i = Int24::from_i32;
+    let r = |n, d| Ratio24::new(i(n), i(d)).unwrap();
+    test_assert!(t, Ratio24::new(i(1), i(0)).is_none());
+    test_assert!(t, r(6, 4).num() == i(3) && r(6, 4).den() == i(2));
+    test_assert!(t, r(6, -4).num() == i(-3) && r(6, -4).den() == i(2));
+    test_assert!(t, r(-6, -4) == r(3, 2));
+    test_assert!(t, r(0, -5) == Ratio24::ZERO);
+    test_assert!(t, r(7, 7) == Ratio24::ONE);
+    test_assert!(t, r(0x7F_FFFF, 0x7F_FFFF) == Ratio24::ONE);
+    test_assert!(t, r(-0x80_0000, -0x80_0000) == Ratio24::ONE);
+    test_assert!(t, r(-0x80_0000, 0x40_0000) == r(-2, 1));
+    test_assert!(t, r(1, -0x80_0000) == r(-1, 0x40_0000)); // approx
+    test_assert!(t, r(-0x80_0000, -1) == Ratio24::from_int(Int24::MAX)); // sat
+
+    test_assert!(t, r(1, 3) + r(1, 6) == r(1, 2));
+    test_assert!(t, r(1, 3) - r(1, 2) == r(-1, 6));
+    test_assert!(t, r(3, 4) * r(8, 9) == r(2, 3));
+    test_assert!(t, -r(3, 4) == r(-3, 4));
+    test_assert!(
+        t,
+        -Ratio24::from_int(Int24::MIN) == Ratio24::from_int(Int24::MAX)
+    ); // sat
+    test_assert!(t, r(-3, 4).recip() == Some(r(-4, 3)));
+    test_assert!(t, Ratio24::ZERO.recip().is_none());
+    let big = Ratio24::from_int(Int24::MAX);
+    test_assert!(t, big + big == big); // sat
+    test_assert!(t, big * big == big); // sat
+    test_assert!(t, -big - big == Ratio24::from_int(Int24::MIN)); // sat
+    let tiny = r(1, 0x7F_FFFF);
+    test_assert!(t, tiny * tiny == Ratio24::ZERO); // approx
+    let a = r(0x7F_FFFF, 0x7F_FFFE) * r(0x7F_FFFD, 0x7F_FFFF);
+    test_assert!(t, a.mul_int(i(0x7F_FFFE)) == i(0x7F_FFFD));
+    let mut b = r(1, 2);
+    b += r(1, 4);
+    b *= r(2, 1);
+    b -= r(1, 2);
+    test_assert!(t, b == Ratio24::ONE);
+
+    test_assert!(t, r(160, 147).mul_int(i(14700)) == i(16000));
+    test_assert!(t, r(1, 3).mul_int(i(2)) == i(1));
+    test_assert!(t, r(1, 3).mul_int(i(1)) == i(0));
+    test_assert!(t, r(1, 2).mul_int(i(1)) == i(1));
+    test_assert!(t, r(1, 2).mul_int(i(-1)) == i(0));
+    test_assert!(t, r(2, 1).mul_int(Int24::MAX) == Int24::MAX); // sat
+    test_assert!(t, r(-1, 1).mul_int(Int24::MIN) == Int24::MAX); // sat
+
+    test_assert!(t, r(1, 3).to_fix::<16>() == Fix24::from_bits(i(0x5555)));
+    test_assert!(t, r(2, 3).to_fix::<16>() == Fix24::from_bits(i(0xAAAB)));
+    test_assert!(
+        t,
+        r(-1, 2).to_fix::<23>() == Fix24::from_bits(i(-0x40_0000))
+    );
+    test_assert!(t, Ratio24::ONE.to_fix::<23>() == Fix24::MAX); // sat
+    test_assert!(t, r(3, 1).to_fix::<0>() == Fix24::from_bits(i(3)));
+}
+
+fn test_scale_ppm(t: &impl TestOps) {
+    t.begin("scale_ppm");
+
+    let i = Int24::from_i32;
+    test_assert!(t, i(2000).scale_percent(10) == i(2200));
+    test_assert!(t, i(2000).scale_percent(-100) == i(0));
+    test_assert!(t, i(2000).scale_percent(-200) == i(-2000));
+    test_assert!(t, i(5).scale_percent(10) == i(6)); // 5.5
+    test_assert!(t, i(-5).scale_percent(10) == i(-6)); // -5.5
+    test_assert!(t, i(4).scale_percent(10) == i(4)); // 4.4
+    test_assert!(t, i(0x40_0000).scale_percent(i16::MAX) == Int24::MAX); // sat
+    test_assert!(t, i(-0x40_0000).scale_percent(i16::MAX) == Int24::MIN); // sat
+
+    test_assert!(t, i(2000).scale_permille(15) == i(2030));
+    test_assert!(t, i(2000).scale_permille(-15) == i(1970));
+    test_assert!(t, i(100).scale_permille(5) == i(101)); // 100.5
+    test_assert!(t, i(-100).scale_permille(5) == i(-101)); // -100.5
+    test_assert!(t, i(0).scale_permille(i16::MIN) == i(0));
+    test_assert!(t, Int24::MAX.scale_permille(i16::MIN) == Int24::MIN); // sat
+
+    test_assert!(t, i(8_000_000).scale_ppm(-350) == i(7_997_200));
+    test_assert!(t, i(8_000_000).scale_ppm(0) == i(8_000_000));
+    test_assert!(t, i(1_000_000).scale_ppm(1) == i(1_000_001));
+    test_assert!(t, i(500_000).scale_ppm(1) == i(500_001)); // 500000.5
+    test_assert!(t, i(499_999).scale_ppm(1) == i(499_999));
+    test_assert!(t, Int24::MAX.scale_ppm(1) == Int24::MAX); // sat
+    test_assert!(t, Int24::MIN.scale_ppm(-1) == i(-0x80_0000 + 8));
+    test_assert!(t, Int24::MIN.scale_ppm(1) == Int24::MIN); // sat
+    test_assert!(t, Int24::MAX.scale_ppm(i32::MIN) == Int24::MIN); // sat
+    test_assert!(t, Int24::MAX.scale_ppm(i32::MAX) == Int24::MAX); // sat
+    test_assert!(t, Int24::MAX.scale_ppm(-1_000_000) == i(0));
+}
+
+fn test_self_test(t: &impl TestOps) {
+    t.begin("self_test");
+    test_assert!(t, Int24::self_test() == Ok(()));
+}
+
+#[cfg(feature = "float")]
+fn test_float(t: &impl TestOps) {
+    use crate::Rounding;
+    t.begin("float");
+
+    let i = Int24::from_i32;
+    let f = Int24::from_f64;
+    let e = Rounding::NearestEven;
+    test_assert!(t, f(0.5, e) == i(0));
+    test_assert!(t, f(1.5, e) == i(2));
+    test_assert!(t, f(2.5, e) == i(2));
+    test_assert!(t, f(2.5000001, e) == i(3));
+    test_assert!(t, f(-0.5, e) == i(0));
+    test_assert!(t, f(-1.5, e) == i(-2));
+    test_assert!(t, f(-2.5, e) == i(-2));
+    test_assert!(t, f(-2.4999999, e) == i(-2));
+    test_assert!(t, f(8_388_606.5, e) == i(0x7F_FFFE));
+    test_assert!(t, f(8_388_607.5, e) == Int24::MAX); // sat
+    test_assert!(t, f(-8_388_608.5, e) == Int24::MIN); // sat
+
+    let a = Rounding::NearestAway;
+    test_assert!(t, f(0.5, a) == i(1));
+    test_assert!(t, f(2.5, a) == i(3));
+    test_assert!(t, f(2.4999999, a) == i(2));
+    test_assert!(t, f(-0.5, a) == i(-1));
+    test_assert!(t, f(-2.5, a) == i(-3));
+    test_assert!(t, f(8_388_606.5, a) == Int24::MAX);
+    test_assert!(t, f(-8_388_608.5, a) == Int24::MIN); // sat
+    test_assert!(t, f(12.5, a) == Int24::__from_table_f64(12.5));
+
+    for (v, trunc, floor, ceil) in [
+        (0.0, 0, 0, 0),
+        (-0.0, 0, 0, 0),
+        (1.25, 1, 1, 2),
+        (-1.25, -1, -2, -1),
+        (7.0, 7, 7, 7),
+        (-7.0, -7, -7, -7),
+        (0.999, 0, 0, 1),
+        (-0.001, 0, -1, 0),
+        (8_388_606.9, 0x7F_FFFE, 0x7F_FFFE, 0x7F_FFFF),
+        (-8_388_607.1, -0x7F_FFFF, -0x80_0000, -0x7F_FFFF),
+    ] {
+        test_assert!(t, f(v, Rounding::Truncate) == i(trunc));
+        test_assert!(t, f(v, Rounding::Floor) == i(floor));
+        test_assert!(t, f(v, Rounding::Ceil) == i(ceil));
+    }
+
+    for mode in [e, Rounding::Truncate, Rounding::Floor, Rounding::Ceil] {
+        test_assert!(t, f(f64::NAN, mode) == i(0));
+        test_assert!(t, f(f64::INFINITY, mode) == Int24::MAX); // sat
+        test_assert!(t, f(f64::NEG_INFINITY, mode) == Int24::MIN); // sat
+        test_assert!(t, f(8_388_607.9, mode) == Int24::MAX);
+        test_assert!(t, f(1e30, mode) == Int24::MAX); // sat
+        test_assert!(t, f(-1e30, mode) == Int24::MIN); // sat
+        test_assert!(t, Int24::from_f32(f32::NAN, mode) == i(0));
+        test_assert!(t, Int24::from_f32(-3.0, mode) == i(-3));
+        test_assert!(t, Int24::from_f32(f32::MAX, mode) == Int24::MAX); // sat
+        for v in [Int24::MIN, i(-1), i(0), i(0x12_3456), Int24::MAX] {
+            test_assert!(t, f(v.to_f64(), mode) == v);
+            test_assert!(t, Int24::from_f32(v.to_f32(), mode) == v);
+        }
+    }
+    test_assert!(t, Rounding::default() == e);
+}
+
+fn test_neg(t: &impl TestOps) {
+    t.begin("neg");
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(-100000);
+    test_assert!(t, -a == b);
+    test_assert!(t, a.const_neg() == b);
+
+    let a = Int24::from_i32(-100000);
+    let b = Int24::from_i32(100000);
+    test_assert!(t, -a == b);
+    test_assert!(t, a.const_neg() == b);
+
+    let a = Int24::from_i32(0x7F_FFFF);
+    let b = Int24::from_i32(-0x7F_FFFF);
+    test_assert!(t, -a == b);
+    test_assert!(t, a.const_neg() == b);
+
+    let a = Int24::from_i32(-0x7F_FFFF);
+    let b = Int24::from_i32(0x7F_FFFF);
+    test_assert!(t, -a == b);
+    test_assert!(t, a.const_neg() == b);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = Int24::from_i32(0x7F_FFFF); // saturated
+    test_assert!(t, -a == b);
+    test_assert!(t, a.const_neg() == b);
+}
+
+fn test_abs(t: &impl TestOps) {
+    t.begin("abs");
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(100000);
+    test_assert!(t, a.abs() == b);
+    test_assert!(t, a.const_abs() == b);
+
+    let a = Int24::from_i32(-100000);
+    let b = Int24::from_i32(100000);
+    test_assert!(t, a.abs() == b);
+    test_assert!(t, a.const_abs() == b);
+
+    let a = Int24::from_i32(0x7F_FFFF);
+    let b = Int24::from_i32(0x7F_FFFF);
+    test_assert!(t, a.abs() == b);
+    test_assert!(t, a.const_abs() == b);
+
+    let a = Int24::from_i32(-0x7F_FFFF);
+    let b = Int24::from_i32(0x7F_FFFF);
+    test_assert!(t, a.abs() == b);
+    test_assert!(t, a.const_abs() == b);
+
+    let a = Int24::from_i32(-0x80_0000);
+    let b = Int24::from_i32(0x7F_FFFF); // saturated
+    test_assert!(t, a.abs() == b);
+    test_assert!(t, a.const_abs() == b);
+}
+
+fn test_flags(t: &impl TestOps) {
+    t.begin("flags");
+
+    let a = Int24::from_i32(0x7F_FFFF - 1);
+    test_assert!(t, a.add_flagged(Int24::from_i32(1)) == (Int24::MAX, false));
+    test_assert!(t, a.add_flagged(Int24::from_i32(2)) == (Int24::MAX, true));
+    test_assert!(t, a.sub_flagged(Int24::from_i32(-2)) == (Int24::MAX, true));
+
+    let a = Int24::from_i32(-0x80_0000 + 1);
+    test_assert!(t, a.sub_flagged(Int24::from_i32(1)) == (Int24::MIN, false));
+    test_assert!(t, a.sub_flagged(Int24::from_i32(2)) == (Int24::MIN, true));
+    test_assert!(t, a.add_flagged(Int24::from_i32(-2)) == (Int24::MIN, true));
+
+    let a = Int24::from_i32(0x40_0000);
+    test_assert!(t, a.mul_flagged(Int24::from_i32(-2)) == (Int24::MIN, false));
+    test_assert!(t, a.mul_flagged(Int24::from_i32(2)) == (Int24::MAX, true));
+    test_assert!(t, a.mul_flagged(Int24::from_i32(-3)) == (Int24::MIN, true));
+    test_assert!(t, Int24::MIN.mul_flagged(Int24::MIN) == (Int24::MAX, true));
+    test_assert!(
+        t,
+        Int24::MIN.mul_flagged(Int24::from_i32(1)) == (Int24::MIN, false)
+    );
+    let a = Int24::from_i32(-1000);
+    test_assert!(
+        t,
+        a.mul_flagged(Int24::from_i32(1010)) == (Int24::from_i32(-1010000), false)
+    );
+
+    let a = Int24::from_i32(1000);
+    test_assert!(
+        t,
+        a.div_flagged(Int24::from_i32(10)) == (Int24::from_i32(100), false)
+    );
+    test_assert!(t, a.div_flagged(Int24::zero()) == (Int24::MAX, true));
+    test_assert!(
+        t,
+        Int24::MIN.div_flagged(Int24::from_i32(-1)) == (Int24::MAX, true)
+    );
+
+    let a = Int24::from_i32(0x7F_FFFF);
+    test_assert!(t, !a.shl8div_flagged(Int24::from_i32(256)).1);
+    test_assert!(t, a.shl8div_flagged(Int24::from_i32(255)).1);
+    let a = Int24::from_i32(0x8_0000);
+    test_assert!(t, !a.shl8div_flagged(Int24::from_i32(-16)).1);
+    test_assert!(t, a.shl8div_flagged(Int24::from_i32(16)).1);
+    test_assert!(t, a.shl8div_flagged(Int24::zero()).1);
+
+    test_assert!(t, Int24::MIN.neg_flagged() == (Int24::MAX, true));
+    test_assert!(
+        t,
+        Int24::MAX.neg_flagged() == (Int24::from_i32(-0x7F_FFFF), false)
+    );
+    test_assert!(t, Int24::MIN.abs_flagged() == (Int24::MAX, true));
+    test_assert!(
+        t,
+        Int24::from_i32(-5).abs_flagged() == (Int24::from_i32(5), false)
+    );
+
+    let mut f = Int24Flags::new();
+    let a = f.add(Int24::from_i32(1000), Int24::from_i32(1000));
+    let a = f.mul(a, Int24::from_i32(1000));
+    test_assert!(t, a == Int24::from_i32(2000000) && !f.saturated());
+    let a = f.mul(a, Int24::from_i32(1000));
+    let a = f.div(a, Int24::from_i32(1000));
+    test_assert!(t, a == Int24::from_i32(8388) && f.saturated());
+    f.clear();
+    test_assert!(t, !f.saturated());
+    let _ = f.neg(Int24::MIN);
+    test_assert!(t, f.saturated());
+}
+
+fn test_shl(t: &impl TestOps) {
+    t.begin("shl");
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(400000);
+    test_assert!(t, a << 2 == b);
+    test_assert!(t, a.const_shl(2) == b);
+
+    let a = Int24::from_i32(1000);
+    let b = Int24::from_i32(256000);
+    test_assert!(t, a.shl8() == b);
+
+    let a = Int24::from_i32(10);
+    let b = Int24::from_i32(655360);
+    test_assert!(t, a.shl16() == b);
+
+    let a = Int24::from_i32(-1);
+    test_assert!(t, a.shl(23) == Int24::MIN);
+    test_assert!(t, a.shl(24) == Int24::zero());
+    test_assert!(t, a.shl(200) == Int24::zero());
+}
+
+fn test_shr(t: &impl TestOps) {
+    t.begin("shr");
+
+    let a = Int24::from_i32(400000);
+    let b = Int24::from_i32(100000);
+    test_assert!(t, a >> 2 == b);
+    test_assert!(t, a.const_shr(2) == b);
+
+    let a = Int24::from_i32(-400000);
+    let b = Int24::from_i32(-100000);
+    test_assert!(t, a >> 2 == b);
+    test_assert!(t, a.const_shr(2) == b);
+
+    let a = Int24::from_i32(256000);
+    let b = Int24::from_i32(1000);
+    test_assert!(t, a.shr8() == b);
+
+    let a = Int24::from_i32(-256000);
+    let b = Int24::from_i32(-1000);
+    test_assert!(t, a.shr8() == b);
+
+    let a = Int24::from_i32(655360);
+    let b = Int24::from_i32(10);
+    test_assert!(t, a.shr16() == b);
+
+    let a = Int24::from_i32(-655360);
+    let b = Int24::from_i32(-10);
+    test_assert!(t, a.shr16() == b);
+
+    let a = Int24::from_i32(-400000);
+    test_assert!(t, a.shr(24).to_i32() == -1);
+    test_assert!(t, a.shr(200).to_i32() == -1);
+    let a = Int24::from_i32(400000);
+    test_assert!(t, a.shr(24) == Int24::zero());
+    test_assert!(t, a.shr(200) == Int24::zero());
+}
+
+fn test_cmp(t: &impl TestOps) {
+    t.begin("cmp");
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(100000);
+    test_assert!(t, a == b);
+    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Equal);
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(100001);
+    test_assert!(t, a != b);
+    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Less);
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(100000);
+    test_assert!(t, a <= b);
+    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Equal);
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(100001);
+    test_assert!(t, a < b);
+    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Less);
+
+    let a = Int24::from_i32(100000);
+    let b = Int24::from_i32(100000);
+    test_assert!(t, a >= b);
+    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Equal);
+
+    let a = Int24::from_i32(100001);
+    let b = Int24::from_i32(100000);
+    test_assert!(t, a > b);
+    test_assert!(t, a.const_cmp(b) == core::cmp::Ordering::Greater);
+}
+
+/// Returns `true`, if `a` and `b` are equal.
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true`, if the test `name` is selected by `filter`.
+///
+/// An empty filter selects all tests.
+/// A filter ending with `*` selects all tests starting with the filter.
+/// Any other filter selects the test with exactly this name.
+const fn filter_match(name: &str, filter: &str) -> bool {
+    let (name, filter) = (name.as_bytes(), filter.as_bytes());
+    match filter.split_last() {
+        None => true,
+        Some((b'*', prefix)) => {
+            name.len() >= prefix.len() && bytes_eq(name.split_at(prefix.len()).0, prefix)
+        }
+        Some(_) => bytes_eq(name, filter),
+    }
+}
+
+/// Get the test name of the test function `func`.
+const fn test_name(func: &str) -> &str {
+    match func.split_at_checked(5) {
+        Some((_, name)) => name,
+        None => func,
+    }
+}
+
+/// Returns `true`, if the test function `func` is selected at compile time by `T`.
+const fn selected<T: TestOps>(func: &str) -> bool {
+    let mut index = 0;
+    while index < TEST_FUNCTIONS.len()
+        && !bytes_eq(TEST_FUNCTIONS[index].as_bytes(), func.as_bytes())
+    {
+        index += 1;
+    }
+    filter_match(test_name(func), T::FILTER) && index % T::PARTS as usize == T::PART as usize
+}
+
+/// Define the list of all test functions in the order of execution.
+macro_rules! test_list {
+    ($($(#[$attr:meta])* $test:ident,)*) => {
+        /// The names of all test functions.
+        const TEST_FUNCTIONS: &[&str] = &[$($(#[$attr])* core::stringify!($test),)*];
+
+        /// Run the test functions selected by `T` and by `filter`.
+        fn run_selected<T: TestOps>(t: &Counter<'_, T>, filter: &str) {
+            $(
+                $(#[$attr])*
+                if const { selected::<T>(core::stringify!($test)) }
+                    && filter_match(test_name(core::stringify!($test)), filter)
+                {
+                    t.time_start();
+                    $test(t);
+                    t.time_end();
+                }
+            )*
+        }
+    };
+}
+
+test_list! {
+    test_base,
+    test_conv_i8,
+    test_conv_i16,
+    test_conv_i32,
+    test_add,
+    test_sub,
+    test_mul,
+    test_mul_shr,
+    test_mul_const,
+    test_div,
+    test_checked_div,
+    test_shl_div,
+    test_divisor,
+    test_shl8div,
+    test_i16f8,
+    test_i8f16,
+    test_frac24,
+    test_fix24,
+    test_format,
+    test_parse,
+    test_int48,
+    test_pid,
+    test_ema,
+    test_fir,
+    test_biquad,
+    test_slew,
+    test_hysteresis,
+    test_lerp,
+    test_map_range,
+    test_trig,
+    test_isqrt,
+    test_lut,
+    test_stats,
+    test_sum,
+    test_dot,
+    test_packed,
+    test_packed_stream,
+    test_packed_scale,
+    test_packed_select,
+    test_adc_bits,
+    test_adc_frames,
+    test_i2s,
+    test_apply_gain,
+    test_db,
+    test_dither,
+    test_from_bits_signed,
+    test_bits,
+    test_gray,
+    test_bcd,
+    test_wrapping,
+    test_saturating,
+    test_cell,
+    test_volatile,
+    test_progmem,
+    test_persist,
+    test_slice_codec,
+    test_modbus,
+    test_saturating_int,
+    test_complex,
+    test_vector,
+    test_matrix,
+    test_conv_checked,
+    test_scalar_ops,
+    test_mul_small,
+    test_shift_types,
+    test_ref_ops,
+    test_random,
+    test_xorshift24,
+    test_next_multiple_of,
+    test_power_of_two,
+    test_div_pow2,
+    test_recip_q23,
+    test_angle,
+    test_nco,
+    test_calibration,
+    test_poly_eval,
+    test_slice_bytes,
+    test_error,
+    test_saturating_unsigned,
+    test_unchecked,
+    test_ct,
+    test_in_place,
+    test_buffers,
+    test_table,
+    test_q15,
+    test_duty,
+    test_timestamp,
+    test_bisect,
+    test_ratio,
+    test_scale_ppm,
+    test_self_test,
+    #[cfg(feature = "strict-ops")]
+    test_strict,
+    #[cfg(feature = "float")]
+    test_float,
+    #[cfg(feature = "ffi")]
+    test_ffi,
+    test_neg,
+    test_abs,
+    test_flags,
+    test_shl,
+    test_shr,
+    test_cmp,
+}
+
+/// Get the names of all unit tests, as accepted by [run_tests_filtered].
+#[cfg(all(test, feature = "strict-overflow", debug_assertions))]
+pub(crate) fn test_names() -> impl Iterator<Item = &'static str> {
+    TEST_FUNCTIONS.iter().map(|func| test_name(func))
+}
+
+/// Run all unit tests and return the summary of the run.
+///
+/// The behavior after a failed assertion is selected by [TestOps::fail_policy].
+pub fn run_tests(t: &impl TestOps) -> TestSummary {
+    run_tests_filtered(t, "")
+}
+
+/// Run the unit tests selected by `filter` and return the summary of the run.
+///
+/// The test names are the names printed at the beginning of each test.
+/// An empty filter selects all tests.
+/// A filter ending with `*` selects all tests with names starting with the filter,
+/// e.g. `"conv_*"`.
+/// Any other filter selects the test with exactly this name, e.g. `"mul_small"`.
+///
+/// Only the tests selected at compile time by [TestOps::FILTER] and [TestOps::PART] are run.
+///
+/// The duration of each test is measured with [TestOps::time_start] and [TestOps::time_end]
+/// and it is reported in a `Time:` line after the test.
+pub fn run_tests_filtered<T: TestOps>(t: &T, filter: &str) -> TestSummary {
+    let t = &Counter::new(t);
+    t.print("\n\nBegin tests\n");
+    if T::PARTS > 1 {
+        t.print("Part ");
+        t.print_num(T::PART.into());
+        t.print(" of ");
+        t.print_num(T::PARTS.into());
+        t.print("\n");
+    }
+    run_selected(t, filter);
+    let s = t.summary.get();
+    t.print("Done! Tests: ");
+    t.print_num(s.tests.into());
+    t.print(", passed: ");
+    t.print_num(s.passed);
+    t.print(", failed: ");
+    t.print_num(s.failed);
+    t.print("\n");
+    s
 }
 
 // vim: ts=4 sw=4 expandtab
@@ -0,0 +1,166 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::{Int24, Int48};
+
+/// Implement the element-wise operations for a vector type with the fields `$f`.
+macro_rules! impl_vec {
+    ($t:ident, $($f:ident),+) => {
+        impl $t {
+            /// Construct a new vector from its elements.
+            pub const fn new($($f: Int24),+) -> Self {
+                Self { $($f),+ }
+            }
+
+            /// Construct a new zero vector.
+            pub const fn zero() -> Self {
+                Self { $($f: Int24::zero()),+ }
+            }
+
+            /// Construct a new vector with all elements set to `v`.
+            pub const fn splat(v: Int24) -> Self {
+                Self { $($f: v),+ }
+            }
+
+            /// Multiply the vectors element-wise with saturation.
+            pub fn mul_elem(self, other: Self) -> Self {
+                Self { $($f: self.$f * other.$f),+ }
+            }
+
+            /// Multiply all elements by `k` with saturation.
+            pub fn scale(self, k: Int24) -> Self {
+                Self { $($f: self.$f * k),+ }
+            }
+
+            /// Multiply all elements by `k` and arithmetically right shift
+            /// the 48 bit intermediate products by `count` bits.
+            ///
+            /// This is the scaling by a fixed-point factor.
+            pub fn scale_shr(self, k: Int24, count: u8) -> Self {
+                Self { $($f: Int48::mul_int24(self.$f, k).to_int24_shr(count)),+ }
+            }
+
+            /// Get the dot product with a 48 bit accumulator.
+            pub fn dot(self, other: Self) -> Int48 {
+                Int48::zero() $(+ Int48::mul_int24(self.$f, other.$f))+
+            }
+
+            /// Get the squared length of the vector with a 48 bit accumulator.
+            pub fn length_squared(self) -> Int48 {
+                self.dot(self)
+            }
+        }
+
+        impl core::ops::Add for $t {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($f: self.$f + other.$f),+ }
+            }
+        }
+
+        impl core::ops::AddAssign for $t {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl core::ops::Sub for $t {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($f: self.$f - other.$f),+ }
+            }
+        }
+
+        impl core::ops::SubAssign for $t {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl core::ops::Mul<Int24> for $t {
+            type Output = Self;
+
+            fn mul(self, k: Int24) -> Self {
+                self.scale(k)
+            }
+        }
+
+        impl core::ops::MulAssign<Int24> for $t {
+            fn mul_assign(&mut self, k: Int24) {
+                *self = *self * k;
+            }
+        }
+
+        impl core::ops::Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($f: -self.$f),+ }
+            }
+        }
+    };
+}
+
+/// Two dimensional vector of [Int24].
+///
+/// All element-wise operations saturate.
+///
+/// ```
+/// use avr_int24::{Int24, Vec2};
+///
+/// let a = Vec2::new(Int24::from_i16(3), Int24::from_i16(4));
+/// assert_eq!(a.length_squared().to_i64(), 25);
+/// assert_eq!(a * Int24::from_i16(2), Vec2::new(Int24::from_i16(6), Int24::from_i16(8)));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Vec2 {
+    /// X element.
+    pub x: Int24,
+    /// Y element.
+    pub y: Int24,
+}
+
+impl_vec!(Vec2, x, y);
+
+/// Three dimensional vector of [Int24].
+///
+/// All element-wise operations saturate.
+///
+/// ```
+/// use avr_int24::{Int24, Vec3};
+///
+/// // Accelerometer reading and a reference axis.
+/// let acc = Vec3::new(Int24::from_i16(100), Int24::from_i16(-200), Int24::from_i16(1000));
+/// let z = Vec3::new(Int24::zero(), Int24::zero(), Int24::from_i16(1));
+/// assert_eq!(acc.dot(z).to_i64(), 1000);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Vec3 {
+    /// X element.
+    pub x: Int24,
+    /// Y element.
+    pub y: Int24,
+    /// Z element.
+    pub z: Int24,
+}
+
+impl_vec!(Vec3, x, y, z);
+
+impl Vec3 {
+    /// Get the cross product with saturation of the elements.
+    pub fn cross(self, other: Self) -> Self {
+        let c = |a: Int24, b: Int24, c: Int24, d: Int24| {
+            (Int48::mul_int24(a, b) - Int48::mul_int24(c, d)).to_int24()
+        };
+        Self::new(
+            c(self.y, other.z, self.z, other.y),
+            c(self.z, other.x, self.x, other.z),
+            c(self.x, other.y, self.y, other.x),
+        )
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
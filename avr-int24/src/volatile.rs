@@ -0,0 +1,137 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Volatile access to [Int24] values in memory mapped registers and DMA descriptors.
+//!
+//! The 3 bytes of a value are accessed one by one in a defined order:
+//!
+//! - Reads access the bytes from the lowest to the highest address.
+//! - Writes access the bytes from the highest to the lowest address.
+//!
+//! This is the order that latching multi byte registers usually require:
+//! Reading the low byte latches the upper bytes
+//! and writing the low byte commits the previously written upper bytes.
+
+use crate::Int24;
+use core::cell::UnsafeCell;
+
+/// Read the [Int24] at `ptr` with volatile byte accesses from the lowest to the highest address.
+///
+/// # Safety
+///
+/// `ptr` must be valid for reads of 3 bytes.
+/// It doesn't need to be aligned, because [Int24] has an alignment of 1.
+///
+/// ```
+/// use avr_int24::{Int24, volatile};
+///
+/// let reg = Int24::from_i16(-2);
+/// assert_eq!(unsafe { volatile::read_volatile(&reg) }, Int24::from_i16(-2));
+/// ```
+#[inline(always)]
+pub unsafe fn read_volatile(ptr: *const Int24) -> Int24 {
+    let p: *const u8 = ptr.cast();
+    // SAFETY: The caller guarantees that `ptr` is valid for reads of 3 bytes.
+    unsafe {
+        let b0 = p.read_volatile();
+        let b1 = p.add(1).read_volatile();
+        let b2 = p.add(2).read_volatile();
+        Int24::from_raw((b0, b1, b2))
+    }
+}
+
+/// Write `value` to `ptr` with volatile byte accesses from the highest to the lowest address.
+///
+/// # Safety
+///
+/// `ptr` must be valid for writes of 3 bytes.
+/// It doesn't need to be aligned, because [Int24] has an alignment of 1.
+///
+/// ```
+/// use avr_int24::{Int24, volatile};
+///
+/// let mut reg = Int24::zero();
+/// unsafe { volatile::write_volatile(&mut reg, Int24::from_i16(-2)) };
+/// assert_eq!(reg, Int24::from_i16(-2));
+/// ```
+#[inline(always)]
+pub unsafe fn write_volatile(ptr: *mut Int24, value: Int24) {
+    let p: *mut u8 = ptr.cast();
+    let (b0, b1, b2) = value.0;
+    // SAFETY: The caller guarantees that `ptr` is valid for writes of 3 bytes.
+    unsafe {
+        p.add(2).write_volatile(b2);
+        p.add(1).write_volatile(b1);
+        p.write_volatile(b0);
+    }
+}
+
+/// [Int24] that is only accessed with volatile reads and writes.
+///
+/// The byte access order is the order of [read_volatile] and [write_volatile].
+/// A reference to a memory mapped register can be created by casting its address.
+///
+/// ```
+/// use avr_int24::{Int24, VolatileInt24};
+///
+/// // For a memory mapped register this would be:
+/// // let reg = unsafe { &*(0x1234 as *const VolatileInt24) };
+/// let reg = VolatileInt24::new(Int24::zero());
+/// reg.write(Int24::from_i32(0x12_3456));
+/// assert_eq!(reg.read(), Int24::from_i32(0x12_3456));
+/// ```
+#[repr(transparent)]
+pub struct VolatileInt24 {
+    value: UnsafeCell<Int24>,
+}
+
+impl VolatileInt24 {
+    /// Construct a new volatile value containing `value`.
+    pub const fn new(value: Int24) -> Self {
+        Self {
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Read the value with volatile byte accesses.
+    #[inline(always)]
+    pub fn read(&self) -> Int24 {
+        // SAFETY: The pointer is derived from a reference and is valid.
+        unsafe { read_volatile(self.value.get()) }
+    }
+
+    /// Write `value` with volatile byte accesses.
+    #[inline(always)]
+    pub fn write(&self, value: Int24) {
+        // SAFETY: The pointer is derived from a reference and is valid.
+        unsafe { write_volatile(self.value.get(), value) }
+    }
+
+    /// Read the value, replace it by `f(value)` and write it back.
+    ///
+    /// This is not atomic.
+    #[inline(always)]
+    pub fn modify<F: FnOnce(Int24) -> Int24>(&self, f: F) {
+        self.write(f(self.read()));
+    }
+
+    /// Get a raw pointer to the value.
+    pub const fn as_ptr(&self) -> *mut Int24 {
+        self.value.get()
+    }
+}
+
+impl Default for VolatileInt24 {
+    fn default() -> Self {
+        Self::new(Int24::zero())
+    }
+}
+
+impl core::fmt::Debug for VolatileInt24 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("VolatileInt24").field(&self.read()).finish()
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,277 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+use crate::Int24;
+
+impl Int24 {
+    /// Add two [Int24] and wrap around modulo 2^24 on overflow.
+    pub const fn wrapping_add(self, other: Self) -> Self {
+        Self::from_bits(self.to_bits().wrapping_add(other.to_bits()))
+    }
+
+    /// Subtract two [Int24] and wrap around modulo 2^24 on overflow.
+    ///
+    /// For two readings of a wrapping 24 bit counter this is the signed distance
+    /// from `other` to `self`, as long as the counter advanced by less than 2^23.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let before = Int24::from_i32(0x7F_FFF0);
+    /// let after = Int24::from_i32(-0x80_0000 + 0x10); // wrapped around
+    /// assert_eq!(after.wrapping_sub(before), Int24::from_i16(0x20));
+    /// ```
+    pub const fn wrapping_sub(self, other: Self) -> Self {
+        Self::from_bits(self.to_bits().wrapping_sub(other.to_bits()))
+    }
+
+    /// Multiply two [Int24] and wrap around modulo 2^24 on overflow.
+    pub const fn wrapping_mul(self, other: Self) -> Self {
+        Self::from_bits(self.to_bits().wrapping_mul(other.to_bits()))
+    }
+
+    /// Negate `self` and wrap around on overflow.
+    ///
+    /// The negation of [Int24::MIN] is [Int24::MIN].
+    pub const fn wrapping_neg(self) -> Self {
+        Self::from_bits(self.to_bits().wrapping_neg())
+    }
+
+    /// Get the signed distance from the counter snapshot `earlier` to `self`.
+    ///
+    /// Both values are snapshots of a free-running 24 bit counter.
+    /// The result is correct, as long as the snapshots are less than 2^23 ticks apart.
+    /// A negative result means that `earlier` was actually taken after `self`.
+    ///
+    /// This is the same as [Int24::wrapping_sub].
+    pub const fn wrapping_delta(self, earlier: Self) -> Self {
+        self.wrapping_sub(earlier)
+    }
+
+    /// Get the number of ticks elapsed from the counter snapshot `earlier` to `self`.
+    ///
+    /// Both values are snapshots of a free-running 24 bit counter.
+    /// The result is the modular difference in the range `0..2^24`,
+    /// which is correct, as long as the counter wrapped around less than once in between.
+    /// Unlike a saturating subtraction, this is correct across the counter wrap around.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    ///
+    /// let start = Int24::from_bits(0xFF_FF00);
+    /// let now = Int24::from_bits(0x00_0100); // wrapped around
+    /// assert_eq!(now.elapsed_since(start), 0x200);
+    /// assert_eq!(start.elapsed_since(now), 0xFF_FE00);
+    /// ```
+    pub const fn elapsed_since(self, earlier: Self) -> u32 {
+        self.wrapping_sub(earlier).to_bits()
+    }
+
+    /// Compare the counter snapshots `self` and `other` in the order they have been taken.
+    ///
+    /// `self` is greater than `other`, if it has been taken after `other`.
+    /// The result is correct, as long as the snapshots are less than 2^23 ticks apart.
+    /// Note that this order is not transitive over the whole 24 bit range.
+    ///
+    /// ```
+    /// use avr_int24::Int24;
+    /// use core::cmp::Ordering;
+    ///
+    /// let deadline = Int24::from_bits(0x00_0010);
+    /// let now = Int24::from_bits(0xFF_FFF0);
+    /// assert_eq!(now.wrapping_cmp(deadline), Ordering::Less); // not yet expired
+    /// ```
+    pub const fn wrapping_cmp(self, other: Self) -> core::cmp::Ordering {
+        let d = self.wrapping_sub(other).to_i32();
+        if d < 0 {
+            core::cmp::Ordering::Less
+        } else if d > 0 {
+            core::cmp::Ordering::Greater
+        } else {
+            core::cmp::Ordering::Equal
+        }
+    }
+}
+
+/// [Int24] with wrapping arithmetic.
+///
+/// All arithmetic operations wrap around modulo 2^24 instead of saturating.
+/// This is useful for encoder position counters and 24 bit hardware timers.
+///
+/// ```
+/// use avr_int24::{Int24, WrappingInt24};
+///
+/// let a = WrappingInt24(Int24::MAX);
+/// let b = a + WrappingInt24(Int24::from_i16(1));
+/// assert_eq!(b, WrappingInt24(Int24::MIN));
+/// assert_eq!((b - a).0, Int24::from_i16(1));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+#[repr(transparent)]
+pub struct WrappingInt24(pub Int24);
+
+impl From<Int24> for WrappingInt24 {
+    fn from(v: Int24) -> Self {
+        Self(v)
+    }
+}
+
+impl From<WrappingInt24> for Int24 {
+    fn from(v: WrappingInt24) -> Self {
+        v.0
+    }
+}
+
+impl core::ops::Add for WrappingInt24 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0.wrapping_add(other.0))
+    }
+}
+
+impl core::ops::AddAssign for WrappingInt24 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub for WrappingInt24 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl core::ops::SubAssign for WrappingInt24 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Mul for WrappingInt24 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(self.0.wrapping_mul(other.0))
+    }
+}
+
+impl core::ops::MulAssign for WrappingInt24 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl core::ops::Neg for WrappingInt24 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+/// [Int24] with saturating arithmetic.
+///
+/// This mirrors `core::num::Saturating` and [WrappingInt24].
+/// The arithmetic operations are the saturating operations of [Int24].
+/// Therefore, saturation panics in debug builds with the `strict-overflow` feature.
+///
+#[cfg_attr(feature = "strict-overflow", doc = "```no_run")]
+#[cfg_attr(not(feature = "strict-overflow"), doc = "```")]
+/// use avr_int24::{Int24, SaturatingInt24};
+///
+/// let a = SaturatingInt24(Int24::MAX);
+/// let b = a + SaturatingInt24(Int24::from_i16(1));
+/// assert_eq!(b, SaturatingInt24(Int24::MAX));
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
+#[repr(transparent)]
+pub struct SaturatingInt24(pub Int24);
+
+impl From<Int24> for SaturatingInt24 {
+    fn from(v: Int24) -> Self {
+        Self(v)
+    }
+}
+
+impl From<SaturatingInt24> for Int24 {
+    fn from(v: SaturatingInt24) -> Self {
+        v.0
+    }
+}
+
+impl core::ops::Add for SaturatingInt24 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl core::ops::AddAssign for SaturatingInt24 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl core::ops::Sub for SaturatingInt24 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl core::ops::SubAssign for SaturatingInt24 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl core::ops::Mul for SaturatingInt24 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl core::ops::MulAssign for SaturatingInt24 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl core::ops::Div for SaturatingInt24 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self(self.0 / other.0)
+    }
+}
+
+impl core::ops::DivAssign for SaturatingInt24 {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl core::ops::Neg for SaturatingInt24 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl core::iter::Sum for SaturatingInt24 {
+    /// Sum up all elements with a wide accumulator and saturate the result.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|v| v.0).sum())
+    }
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,311 @@
+// -*- coding: utf-8 -*-
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+//! Link time proof that the operations never panic.
+//!
+//! Each operation is called with opaque operands while a guard value is alive.
+//! The guard calls an undefined function, if it is dropped during unwinding.
+//! If the optimizer can't remove all panic paths of an operation,
+//! then the unwinding path remains and linking fails with an undefined symbol
+//! `avr_int24_<operation>_may_panic`.
+//!
+//! The check needs optimizations and LTO, so it is only built in release mode.
+//! Run it with `make no-panic`, which enables LTO for the release profile.
+//!
+//! This only covers the generic backend of the host build.
+//! The AVR assembly routines are checked by `make no-panic` of avr-int24-test.
+
+#![cfg(not(debug_assertions))]
+
+use avr_int24::{
+    Angle24, ByteOrder, Calibration, Complex24, DivisorInt24, Ema24, Fir24, Fix24, Frac24,
+    Hysteresis, I8F16, I16F8, Int24, Int24Cell, Int24Flags, Int24Iter, Int24Slice, Int24SliceMut,
+    Int24Writer, Int48, Lut24, Mat3x3, Nco24, Pid24, Ratio24, SlewLimiter, Vec3, WrappingInt24,
+    XorShift24,
+    adc::{self, FrameFormat},
+    add_buffers, bisect, decode_le_slice, encode_be_slice, int24_progmem,
+    modbus::WordOrder,
+    neg_buffer, poly_eval, stats, sub_buffers,
+};
+use core::hint::black_box;
+
+/// Guard that fails linking, if it can be dropped.
+macro_rules! guard {
+    ($name:ident) => {{
+        struct Guard;
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                unsafe extern "C" {
+                    #[link_name = concat!("avr_int24_", stringify!($name), "_may_panic")]
+                    fn may_panic() -> !;
+                }
+                // SAFETY: This is never called, if the operation can't unwind.
+                unsafe { may_panic() }
+            }
+        }
+
+        Guard
+    }};
+}
+
+/// Generate a test for each operation that fails linking, if the operation may panic.
+///
+/// The operands are opaque to the optimizer,
+/// so the operation must be panic free for all operand values.
+macro_rules! no_panic {
+    ($($name:ident($($arg:ident: $ty:ty = $val:expr),* $(,)?) => $body:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                $(
+                    let $arg = $val;
+                    let $arg: $ty = black_box($arg);
+                )*
+                let guard = guard!($name);
+                let result = $body;
+                core::mem::forget(guard);
+                black_box(&result);
+            }
+        )*
+    };
+}
+
+const A: Int24 = Int24::from_i32(0x12_3456);
+const B: Int24 = Int24::from_i32(-0x65);
+
+no_panic! {
+    // Core operations
+    add(a: Int24 = A, b: Int24 = B) => a + b;
+    sub(a: Int24 = A, b: Int24 = B) => a - b;
+    mul(a: Int24 = A, b: Int24 = B) => a * b;
+    mul_shr(a: Int24 = A, b: Int24 = B, c: u8 = 8) => a.mul_shr(b, c);
+    mul_i16(a: Int24 = A, b: i16 = -3) => a.mul_i16(b);
+    mul_u8(a: Int24 = A, b: u8 = 3) => a.mul_u8(b);
+    div(a: Int24 = A, b: Int24 = B) => a / b;
+    div_u8(a: Int24 = A, b: u8 = 3) => a.div_u8(b);
+    checked_div(a: Int24 = A, b: Int24 = B) => a.checked_div(b);
+    shl8div(a: Int24 = A, b: Int24 = B) => a.shl8div(b);
+    shl_div(a: Int24 = A, b: Int24 = B, c: u8 = 16) => a.shl_div(b, c);
+    checked_shl8div(a: Int24 = A, b: Int24 = B) => a.checked_shl8div(b);
+    neg(a: Int24 = A) => -a;
+    abs(a: Int24 = A) => a.abs();
+    isqrt(a: Int24 = A) => a.isqrt();
+    shl(a: Int24 = A, c: u8 = 4) => a.shl(c);
+    shr(a: Int24 = A, c: u8 = 4) => a.shr(c);
+    shift_types(a: Int24 = A, c: u32 = 30, d: usize = 5, e: i32 = -3) => {
+        (a << c, a >> c, a << d, a >> d, a << e, a >> e)
+    };
+    cmp(a: Int24 = A, b: Int24 = B) => a.cmp(b);
+    wrapping(a: Int24 = A, b: Int24 = B) => (WrappingInt24(a) * WrappingInt24(b)).0;
+    saturating_add_unsigned(a: Int24 = A, b: u32 = 7) => a.saturating_add_unsigned(b);
+    saturating_sub_unsigned(a: Int24 = A, b: u32 = 7) => a.saturating_sub_unsigned(b);
+    add_in_place(a: Int24 = A, b: Int24 = B) => {
+        let mut a = a;
+        a.add_in_place(b);
+        a
+    };
+    sub_in_place(a: Int24 = A, b: Int24 = B) => {
+        let mut a = a;
+        a.sub_in_place(b);
+        a
+    };
+    flagged(a: Int24 = A, b: Int24 = B, c: u8 = 8) => {
+        let mut f = Int24Flags::new();
+        let x = f.mul_shr(a, b, c);
+        let x = f.shl_div(x, b, c);
+        (f.div(x, b), f.saturated())
+    };
+
+    // Constant time operations
+    ct_add(a: Int24 = A, b: Int24 = B) => a.ct_add(b);
+    ct_sub(a: Int24 = A, b: Int24 = B) => a.ct_sub(b);
+    ct_mul(a: Int24 = A, b: Int24 = B) => a.ct_mul(b);
+    ct_div(a: Int24 = A, b: Int24 = B) => a.ct_div(b);
+    ct_neg(a: Int24 = A) => a.ct_neg();
+    ct_abs(a: Int24 = A) => a.ct_abs();
+    self_test() => Int24::self_test();
+
+    // Conversions
+    conv(a: Int24 = A, v: i32 = -5) => (Int24::from_i32(v), a.to_i16(), a.to_i8(), a.to_u8_checked());
+    bytes(buf: &mut [u8] = &mut [0; 4]) => {
+        let v = Int24::try_from_le_slice(buf);
+        (A.write_be_to(buf), v)
+    };
+    bits(a: Int24 = A, v: u32 = 0x12_3456, n: u8 = 12) => {
+        (a.to_gray().from_gray(), a.to_bcd(), Int24::from_bcd(false, v), Int24::from_bits_signed(v, n))
+    };
+    power_of_two(a: Int24 = A) => (a.next_power_of_two(), a.checked_ilog2());
+    modbus(a: Int24 = A) => Int24::from_modbus_registers(a.to_modbus_registers(WordOrder::LowFirst), WordOrder::LowFirst);
+    q15(a: Int24 = A, c: i16 = 0x4000) => (a.mul_q15(c), a.to_q15_sat(), Int24::from_q15(c));
+    audio(a: Int24 = A, g: i16 = 0x180, rng: u32 = 1) => {
+        let mut rng = rng;
+        (a.apply_gain(g), a.to_i16_dithered(&mut rng), a.to_db_q8_8(), Int24::from_db_q8_8(g))
+    };
+    i2s(w: u32 = 0x1234_5600) => Int24::from_i2s_left_justified(w).to_i2s_right_justified();
+    persist(a: Int24 = A, buf: &mut [u8] = &mut [0; 4]) => (a.write_to(buf), Int24::read_from(buf));
+
+    // Scaling
+    lerp(a: Int24 = A, b: Int24 = B, t: u16 = 0x4000) => (a.lerp_q8(b, t as u8), a.lerp_q16(b, t));
+    map_range(a: Int24 = A, b: Int24 = B, c: Int24 = A) => a.map_range(b, c, B, A);
+    duty(a: Int24 = A, top: u16 = 255, d: u16 = 9) => {
+        (a.to_duty(top), a.to_duty_signed(top), Int24::from_duty(d, top), Int24::from_duty_signed(d, top))
+    };
+    scale_ppm(a: Int24 = A, p: i32 = -350, q: i16 = 15) => (a.scale_ppm(p), a.scale_permille(q), a.scale_percent(q));
+    next_multiple_of(a: Int24 = A, b: Int24 = B) => (a.next_multiple_of(b), a.checked_next_multiple_of(b));
+    deadband(a: Int24 = A, b: Int24 = B) => a.deadband(b);
+
+    // Timestamps
+    timestamps(a: Int24 = A, b: Int24 = B) => (a.elapsed_since(b), a.wrapping_delta(b), a.wrapping_cmp(b));
+
+    // Search
+    bisect_range(a: Int24 = A, b: Int24 = B, t: i32 = 3) => bisect(b, a, |x| x.to_i32().cmp(&t));
+
+    // Wide integers
+    int48(a: Int24 = A, b: Int24 = B, c: u8 = 13) => {
+        let w = Int48::mul_int24(a, b);
+        ((w * w / Int48::from_int24(b)).shl(c).shr(c) - w).abs().to_int24_shr(c)
+    };
+    ratio(a: Int24 = A, b: Int24 = B) => {
+        let r = Ratio24::new(a, b).unwrap_or(Ratio24::ONE);
+        let r = (r + r) * r - r;
+        (r.mul_int(a), r.to_fix::<16>(), r.recip())
+    };
+
+    // Fixed-point
+    i16f8(a: Int24 = A, b: Int24 = B) => {
+        let (a, b) = (I16F8::from_bits(a), I16F8::from_bits(b));
+        ((a * b / a + b - a).abs(), -a)
+    };
+    i8f16(a: Int24 = A, b: Int24 = B) => {
+        let (a, b) = (I8F16::from_bits(a), I8F16::from_bits(b));
+        ((a * b / a + b - a).abs(), -a)
+    };
+    frac24(a: Int24 = A, b: Int24 = B) => {
+        let (fa, fb) = (Frac24::from_bits(a), Frac24::from_bits(b));
+        ((fa * fb / fa + fb - fa).abs(), fa.mul_int(b))
+    };
+    fix24(a: Int24 = A, b: Int24 = B) => {
+        let (fa, fb) = (Fix24::<12>::from_bits(a), Fix24::<12>::from_bits(b));
+        ((fa * fb / fb + fb - fa).abs().rescale::<20>(), Fix24::<4>::from_int(a).to_int())
+    };
+    poly(x: Int24 = A, c: &[Fix24<16>] = &[Fix24::ZERO; 3]) => poly_eval(Fix24::<23>::from_bits(x), c);
+    divisor(a: Int24 = A, b: Int24 = B) => DivisorInt24::new(b).divide(a);
+    recip_q23(a: Int24 = A) => a.recip_q23();
+
+    // Trigonometry and geometry
+    sin_cos(a: Int24 = A) => Frac24::sin_cos(a);
+    atan2(a: Int24 = A, b: Int24 = B) => a.atan2(b);
+    angle(a: Int24 = A, b: Int24 = B, d: i16 = 1000) => {
+        let x = Angle24::from_bits(a) * d - Angle24::from_degrees(d);
+        (x.to_degrees(), x.abs_diff(Angle24::from_bits(b)), Angle24::atan2(a, b))
+    };
+    nco(f: u32 = 1000, r: u32 = 8000) => {
+        let mut nco = Nco24::from_frequency(f, r);
+        (nco.step(), nco.sample_sin(), nco.sample_saw())
+    };
+    complex(a: Int24 = A, b: Int24 = B, c: u8 = 23) => {
+        let x = Complex24::new(a, b);
+        (x.mul_shr(x.conj(), c), x.power(), x.magnitude_approx())
+    };
+    vector(a: Int24 = A, b: Int24 = B, c: u8 = 12) => {
+        let v = Vec3::new(a, b, a);
+        (v.cross(v.scale_shr(b, c)).mul_elem(v), v.dot(v), v.length_squared())
+    };
+    matrix(a: Int24 = A, b: Int24 = B) => {
+        let m = Mat3x3::<16>::from_bits([[a, b, a], [b, a, b], [a, a, b]]);
+        m.mul_mat(&m.transpose()).mul_vec(Vec3::splat(b))
+    };
+
+    // Filters and control
+    ema(s: u8 = 4, x: Int24 = A) => {
+        let mut f = Ema24::new(s);
+        f.update(x)
+    };
+    fir(c: Int24 = A, x: Int24 = B) => {
+        let mut f = Fir24::new([Frac24::from_bits(c); 5]);
+        f.update(x)
+    };
+    pid(k: Int24 = A, s: u8 = 8, x: Int24 = B) => {
+        let mut pid = Pid24::new(k, k, k, s).with_output_limits(B, A).with_derivative_filter(s);
+        pid.update(x, k)
+    };
+    slew(a: Int24 = A, b: Int24 = B) => {
+        let mut sl = SlewLimiter::new(a, b);
+        sl.update(b)
+    };
+    hysteresis(a: Int24 = A, b: Int24 = B) => {
+        let mut h = Hysteresis::new(b, a);
+        h.update(a)
+    };
+    random(seed: u32 = 1, bits: u8 = 12) => {
+        let mut rng = XorShift24::new(seed);
+        (rng.next_int24(), rng.next_bits(bits), rng.next_tpdf(bits))
+    };
+
+    // Tables
+    lut(x: Int24 = A, p: &[(Int24, Int24)] = &[(A, B), (B, A)]) => Lut24::new(p).lookup(x);
+    progmem(i: usize = 2) => {
+        int24_progmem! {
+            static TABLE: [Int24; 2] = [A, B];
+        }
+        TABLE.get(i)
+    };
+    calibration(x: Int24 = A, buf: &mut [u8] = &mut [0; 16]) => {
+        let cal = Calibration::new([(A, B), (B, A)]);
+        (cal.apply(x), cal.write_to(buf), Calibration::<2>::read_from(buf))
+    };
+
+    // Slices and buffers
+    slice(bytes: &[u8] = &[1; 10], i: usize = 2) => {
+        let s = Int24Slice::new(bytes);
+        (s.and_then(|s| s.get(i)), Int24Iter::new(bytes, ByteOrder::BigEndian).sum::<Int24>())
+    };
+    slice_mut(bytes: &mut [u8] = &mut [1; 12], i: usize = 2, v: Int24 = A) => {
+        let mut s = Int24SliceMut::new(bytes).unwrap_or_else(|| Int24SliceMut::new(&mut []).unwrap());
+        let changed = (s.set(i, v), s.swap(i, 0));
+        s.apply_gain(0x180);
+        s.scale_offset(Fix24::<16>::from_bits(v), v);
+        (changed, s.median(), s.select_nth(i))
+    };
+    writer(bytes: &mut [u8] = &mut [0; 7], v: Int24 = A) => {
+        let mut w = Int24Writer::new(bytes, ByteOrder::LittleEndian);
+        (w.push(v), w.push(v), w.push(v), w.remaining())
+    };
+    encode(data: &mut [Int24] = &mut [A; 3], bytes: &mut [u8] = &mut [0; 9]) => {
+        (encode_be_slice(data, bytes), decode_le_slice(bytes, data))
+    };
+    buffers(dst: &mut [u8] = &mut [0; 9], src: &[u8] = &[1; 8]) => {
+        add_buffers(dst, src);
+        sub_buffers(dst, src);
+        neg_buffer(dst);
+    };
+    statistics(data: &[Int24] = &[A, B, A]) => {
+        (stats::sum_wide(data), stats::mean(data), stats::variance(data), stats::dot(data, data))
+    };
+    adc_frames(frame: &[u8] = &[1, 2, 3, 4], v: Int24 = A, buf: &mut [u8] = &mut [0; 4]) => {
+        (adc::decode_frame(frame, FrameFormat::Status8Data24), adc::encode_frame(buf, FrameFormat::Data24Pad8, v, 1))
+    };
+    cell(a: Int24 = A, b: Int24 = B) => {
+        let c = Int24Cell::new(a);
+        c.fetch_add(b);
+        c.fetch_sub(b);
+        c.update(|x| x * b)
+    };
+
+    // Formatting
+    format(a: Int24 = A, d: u8 = 3, buf: &mut [u8] = &mut [0; 16]) => a.format_decimal(d, buf).map(|s| s.len());
+    format_fixed(a: Int24 = A, f: u8 = 12, d: u8 = 3, buf: &mut [u8] = &mut [0; 16]) => {
+        a.format_fixed(f, d, buf).map(|s| s.len())
+    };
+    parse(s: &str = "-123.456", d: u8 = 3) => (Int24::parse_decimal(s, d), Int24::parse_fixed(s, d));
+}
+
+#[cfg(feature = "float")]
+no_panic! {
+    from_f64(v: f64 = 1.5, m: avr_int24::Rounding = avr_int24::Rounding::Floor) => Int24::from_f64(v, m);
+    from_f32(v: f32 = 1.5, m: avr_int24::Rounding = avr_int24::Rounding::NearestEven) => Int24::from_f32(v, m);
+}
+
+// vim: ts=4 sw=4 expandtab
@@ -0,0 +1,132 @@
+#!/usr/bin/env python3
+# -*- coding: utf-8 -*-
+# SPDX-License-Identifier: Apache-2.0 OR MIT
+# Copyright (C) 2025 Michael Büsch <m@bues.ch>
+
+"""
+Print the flash footprint of each operation of the avr-int24-test codesize build.
+
+The operations are the functions codesize_<name> of the firmware.
+The own size is the size of the instantiated function.
+The total size additionally includes all functions that are called from it,
+directly or indirectly, e.g. the assembly routines of the crate and the libgcc helpers.
+Functions that are shared by several operations are included in each of their totals.
+Constant tables in the program memory are not included.
+
+With --check-panic the sizes aren't printed.
+Instead the script fails, if any operation can reach a panic function.
+This is the no-panic check of the AVR assembly routines,
+because tests/no_panic.rs of the crate only covers the generic backend.
+Calls through function pointers are not followed.
+"""
+
+import argparse, bisect, re, subprocess, sys
+
+PREFIX = "codesize_"
+PANIC_RE = re.compile(r"^(core::panicking::|rust_begin_unwind$)")
+
+def run(objdump, *args):
+    return subprocess.run([objdump, *args], check=True,
+                          capture_output=True, text=True).stdout
+
+def read_functions(objdump, elf):
+    """Get the function symbols as a sorted list of (address, size, name).
+    """
+    funcs = {}
+    sym_re = re.compile(r"^([0-9a-f]+)\s.*\sF\s+\.text\S*\s+([0-9a-f]+)\s+(.+)$")
+    for line in run(objdump, "-t", "-C", elf).splitlines():
+        m = sym_re.match(line)
+        if m:
+            addr, size, name = int(m.group(1), 16), int(m.group(2), 16), m.group(3).strip()
+            if size:
+                funcs[addr] = (addr, size, name)
+    return sorted(funcs.values())
+
+def read_calls(objdump, elf):
+    """Get the (source, target) addresses of all calls and jumps.
+    """
+    insn_re = re.compile(r"^\s*([0-9a-f]+):\s+(?:[0-9a-f]{2} )+\s*(r?call|r?jmp)\s+([^\s;]+)")
+    for line in run(objdump, "-d", elf).splitlines():
+        m = insn_re.match(line)
+        if not m:
+            continue
+        addr, insn, op = int(m.group(1), 16), m.group(2), m.group(3)
+        if insn.startswith("r"):
+            # Relative to the next instruction: .+N or .-N
+            target = addr + 2 + int(op[1:], 0)
+        else:
+            target = int(op, 0)
+        yield addr, target
+
+def main():
+    p = argparse.ArgumentParser(description=__doc__.strip().splitlines()[0])
+    p.add_argument("--objdump", default="avr-objdump", help="objdump program")
+    p.add_argument("--sort", choices=("name", "own", "total"), default="name",
+                   help="sort order of the operations")
+    p.add_argument("--check-panic", action="store_true",
+                   help="fail, if an operation may panic")
+    p.add_argument("elf", help="ELF file of the codesize build")
+    args = p.parse_args()
+
+    funcs = read_functions(args.objdump, args.elf)
+    starts = [f[0] for f in funcs]
+
+    def containing(addr):
+        i = bisect.bisect_right(starts, addr) - 1
+        if i >= 0 and addr < funcs[i][0] + funcs[i][1]:
+            return i
+        return None
+
+    callees = {}
+    for source, target in read_calls(args.objdump, args.elf):
+        s, t = containing(source), containing(target)
+        if s is not None and t is not None and s != t:
+            callees.setdefault(s, set()).add(t)
+
+    def closure(i):
+        seen, todo = {i}, [i]
+        while todo:
+            for c in callees.get(todo.pop(), ()):
+                if c not in seen:
+                    seen.add(c)
+                    todo.append(c)
+        return seen
+
+    rows = []
+    used = set()
+    for i, (_addr, size, name) in enumerate(funcs):
+        if name.startswith(PREFIX):
+            reach = closure(i)
+            used |= reach
+            rows.append((name[len(PREFIX):], size, sum(funcs[c][1] for c in reach)))
+    if not rows:
+        print(f"No {PREFIX}* functions found. "
+              "Build the firmware with the codesize feature.", file=sys.stderr)
+        return 1
+
+    if args.check_panic:
+        bad = sorted((name[len(PREFIX):], funcs[c][2])
+                     for i, (_addr, _size, name) in enumerate(funcs)
+                     if name.startswith(PREFIX)
+                     for c in closure(i) if PANIC_RE.match(funcs[c][2]))
+        for op, panic in bad:
+            print(f"{op} may panic: calls {panic}", file=sys.stderr)
+        if bad:
+            return 1
+        print(f"None of the {len(rows)} operations can panic.")
+        return 0
+
+    key = {"name": lambda r: r[0], "own": lambda r: r[1], "total": lambda r: r[2]}[args.sort]
+    rows.sort(key=key)
+    width = max(len(r[0]) for r in rows)
+    print(f"{'operation':<{width}} {'own':>6} {'total':>6}")
+    for name, own, total in rows:
+        print(f"{name:<{width}} {own:>6} {total:>6}")
+    print(f"{'all operations':<{width}} {sum(r[1] for r in rows):>6} "
+          f"{sum(funcs[c][1] for c in used):>6}")
+    return 0
+
+if __name__ == "__main__":
+    sys.exit(main())
+
+# vim: ts=4 sw=4 expandtab